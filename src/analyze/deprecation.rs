@@ -0,0 +1,199 @@
+use crate::analyze::{Analyzer, Code, Diagnostic};
+use crate::ast;
+use crate::compile::{Declaration, Symbols};
+use crate::core::Descriptor;
+use crate::visit::{Visitor, walk};
+
+/* -------------------------------------------------------------------------- */
+/*                          Analyzer: DeprecatedUsage                         */
+/* -------------------------------------------------------------------------- */
+
+/// `DeprecatedUsage` warns about references to deprecated types which are
+/// declared in a different schema than the one being analyzed.
+pub struct DeprecatedUsage<'a> {
+    declarations: &'a Symbols<Declaration>,
+    diagnostics: Vec<Diagnostic>,
+    scope: Descriptor,
+}
+
+/* -------------------------- Impl: DeprecatedUsage ------------------------- */
+
+impl<'a> DeprecatedUsage<'a> {
+    /// `new` creates a new [`DeprecatedUsage`] analyzer for the given package
+    /// scope.
+    pub fn new(declarations: &'a Symbols<Declaration>, scope: Descriptor) -> Self {
+        Self {
+            declarations,
+            diagnostics: Vec::new(),
+            scope,
+        }
+    }
+}
+
+/* ----------------------------- Impl: Analyzer ----------------------------- */
+
+impl Analyzer for DeprecatedUsage<'_> {
+    fn drain_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+/* ------------------------------ Impl: Visitor ----------------------------- */
+
+impl<'ast> Visitor<'ast> for DeprecatedUsage<'_> {
+    fn visit_message(&mut self, msg: &'ast ast::Message) {
+        self.scope.push(msg.name.name.clone());
+        walk::walk_message(self, msg);
+        self.scope.pop();
+    }
+
+    fn visit_enum(&mut self, enum_: &'ast ast::Enum) {
+        self.scope.push(enum_.name.name.clone());
+        walk::walk_enum(self, enum_);
+        self.scope.pop();
+    }
+
+    fn visit_reference(&mut self, reference: &'ast ast::Reference) {
+        let Some((descriptor, declaration)) = self.declarations.resolve(&self.scope, reference)
+        else {
+            return; // Unresolved references are reported elsewhere.
+        };
+
+        // Deprecated types may still be used within their defining schema.
+        if declaration.span.context == reference.span.context {
+            return;
+        }
+
+        if let Some(annotation) = declaration.deprecated() {
+            let message = match annotation.note() {
+                Some(note) => format!("type '{}' is deprecated: {}", descriptor, note),
+                None => format!("type '{}' is deprecated", descriptor),
+            };
+
+            self.diagnostics.push(
+                Diagnostic::warning(reference.span.clone(), message)
+                    .with_code(Code::DeprecatedUsage)
+                    .with_secondary(annotation.span.clone(), "deprecated here"),
+            );
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyze::Severity;
+    use crate::core::{DescriptorBuilder, PackageName, SchemaImport};
+    use crate::lex::Span;
+    use chumsky::span::Span as SpanTrait;
+
+    /* ------------------------ Tests: visit_reference ---------------------- */
+
+    #[test]
+    fn test_reference_to_deprecated_type_in_other_file_warns() {
+        // Given: A deprecated type declared in another file.
+        let declarations = make_declarations(vec![make_deprecated(Some("use Vec3"))]);
+
+        // When: Analyzing a reference to it from a different file.
+        let diagnostics = analyze(&declarations, SchemaImport::anonymous());
+
+        // Then: A warning including the note is reported.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].message,
+            "type 'com.example.Point' is deprecated: use Vec3"
+        );
+    }
+
+    #[test]
+    fn test_reference_to_deprecated_type_in_same_file_is_allowed() {
+        // Given: A deprecated type declared in the same file.
+        let declarations = make_declarations(vec![make_deprecated(Some("use Vec3"))]);
+
+        // When: Analyzing a reference to it from the declaring file.
+        let diagnostics = analyze(&declarations, SchemaImport::default());
+
+        // Then: No warnings are reported.
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_reference_to_non_deprecated_type_is_allowed() {
+        // Given: A type which is not deprecated.
+        let declarations = make_declarations(vec![]);
+
+        // When: Analyzing a reference to it from a different file.
+        let diagnostics = analyze(&declarations, SchemaImport::anonymous());
+
+        // Then: No warnings are reported.
+        assert!(diagnostics.is_empty());
+    }
+
+    /* ---------------------------- Fn: analyze ----------------------------- */
+
+    fn analyze(declarations: &Symbols<Declaration>, file: SchemaImport) -> Vec<Diagnostic> {
+        let scope = DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .build()
+            .unwrap();
+
+        let span: Span = SpanTrait::new(file, 0..0);
+        let reference = ast::Reference {
+            components: vec![ast::Ident {
+                name: "Point".to_string(),
+                span: span.clone(),
+            }],
+            is_absolute: false,
+            span,
+        };
+
+        let mut analyzer = DeprecatedUsage::new(declarations, scope);
+        analyzer.visit_reference(&reference);
+        analyzer.drain_diagnostics()
+    }
+
+    /* ------------------------ Fn: make_declarations ----------------------- */
+
+    fn make_declarations(annotations: Vec<ast::Annotation>) -> Symbols<Declaration> {
+        let mut declarations = Symbols::default();
+
+        declarations.insert(
+            make_descriptor(),
+            Declaration {
+                annotations,
+                span: Span::default(),
+            },
+        );
+
+        declarations
+    }
+
+    /* ------------------------- Fn: make_deprecated ------------------------ */
+
+    fn make_deprecated(note: Option<&str>) -> ast::Annotation {
+        let note = note.map(|n| ast::Text {
+            content: n.to_string(),
+            span: Span::default(),
+        });
+
+        ast::Annotation {
+            kind: ast::AnnotationKind::Deprecated(note),
+            span: Span::default(),
+        }
+    }
+
+    /* ------------------------- Fn: make_descriptor ------------------------ */
+
+    fn make_descriptor() -> Descriptor {
+        DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .path(vec!["Point".to_string()])
+            .build()
+            .unwrap()
+    }
+}
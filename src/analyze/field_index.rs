@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::analyze::{Analyzer, Diagnostic};
+use crate::analyze::{Analyzer, Code, Diagnostic};
 use crate::ast;
 use crate::lex::Span;
 use crate::visit::{Visitor, walk};
@@ -20,13 +20,11 @@ pub struct FieldIndexUniqueness {
 
 impl FieldIndexUniqueness {
     fn diagnostic(value: u64, first: &Span, second: &Span) -> Diagnostic {
-        Diagnostic::error(
-            second.clone(),
-            format!(
-                "duplicate index {} (previously defined at {:?})",
-                value, first
-            ),
-        )
+        Diagnostic::error(second.clone(), format!("duplicate index {}", value))
+            .with_code(Code::DuplicateIndex)
+            .with_label("reused here")
+            .with_secondary(first.clone(), format!("index {} first used here", value))
+            .with_note("indices identify fields on the wire and must be unique")
     }
 }
 
@@ -48,7 +46,7 @@ impl<'ast> Visitor<'ast> for FieldIndexUniqueness {
             let index_opt = match item {
                 ast::EnumItem::UnitVariant(variant) => variant.index.as_ref(),
                 ast::EnumItem::FieldVariant(field) => field.index.as_ref(),
-                ast::EnumItem::CommentBlock(_) => None,
+                ast::EnumItem::CommentBlock(_) | ast::EnumItem::Reserved(_) => None,
             };
 
             if let Some(index) = index_opt {
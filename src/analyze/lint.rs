@@ -0,0 +1,301 @@
+use derive_more::Display;
+
+use crate::analyze::{Code, Diagnostic, Severity};
+
+/* -------------------------------------------------------------------------- */
+/*                                 Enum: Level                                */
+/* -------------------------------------------------------------------------- */
+
+/// `Level` determines how a warning is reported.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Level {
+    /// Discard the warning.
+    #[display("allow")]
+    Allow,
+    /// Report the warning as an error.
+    #[display("deny")]
+    Deny,
+    /// Report the warning as-is.
+    #[display("warn")]
+    Warn,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Enum: Lint                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `Lint` selects a set of warnings whose [`Level`] can be configured.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum Lint {
+    /// Select warnings of the category (e.g. `unused`).
+    #[display("{_0}")]
+    Category(&'static str),
+    /// Select warnings with the [`Code`].
+    #[display("{}", _0.name())]
+    Code(Code),
+    /// Select all warnings.
+    #[display("warnings")]
+    Warnings,
+}
+
+/* ------------------------------- Impl: Lint ------------------------------- */
+
+impl Lint {
+    /// `matches` returns whether a warning with the provided `code` is
+    /// selected by the [`Lint`].
+    pub fn matches(&self, code: Option<Code>) -> bool {
+        match self {
+            Lint::Category(category) => code.and_then(|c| c.category()) == Some(category),
+            Lint::Code(c) => code == Some(*c),
+            Lint::Warnings => true,
+        }
+    }
+
+    /// `specificity` ranks how narrowly the [`Lint`] selects warnings; more
+    /// specific lints take precedence over broader ones.
+    fn specificity(&self) -> usize {
+        match self {
+            Lint::Warnings => 0,
+            Lint::Category(_) => 1,
+            Lint::Code(_) => 2,
+        }
+    }
+}
+
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for Lint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "warnings" {
+            return Ok(Lint::Warnings);
+        }
+
+        if let Ok(code) = s.parse::<Code>() {
+            return Ok(Lint::Code(code));
+        }
+
+        Code::ALL
+            .iter()
+            .filter_map(Code::category)
+            .find(|category| *category == s)
+            .map(Lint::Category)
+            .ok_or_else(|| format!("unknown lint: {}", s))
+    }
+}
+
+/* ---------------------------- Impl: Deserialize --------------------------- */
+
+impl<'de> serde::Deserialize<'de> for Lint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: LintLevels                             */
+/* -------------------------------------------------------------------------- */
+
+/// `LintLevels` maps [`Lint`]s to the [`Level`] at which matching warnings are
+/// reported. Warnings not selected by any lint are reported as-is.
+///
+/// NOTE: When several lints select the same warning, the most specific one
+/// (code, then category, then `warnings`) wins; ties are resolved in favor of
+/// the lint configured last. Errors are never affected.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LintLevels {
+    levels: Vec<(Lint, Level)>,
+}
+
+/* ---------------------------- Impl: LintLevels ---------------------------- */
+
+impl LintLevels {
+    /// `apply` applies the configured levels to `diagnostics`, discarding
+    /// allowed warnings and promoting denied warnings to errors.
+    pub fn apply(&self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        diagnostics
+            .into_iter()
+            .filter_map(|diagnostic| {
+                if diagnostic.severity == Severity::Error {
+                    return Some(diagnostic);
+                }
+
+                match self.lookup(diagnostic.code) {
+                    None | Some((_, Level::Warn)) => Some(diagnostic),
+                    Some((_, Level::Allow)) => None,
+                    Some((lint, Level::Deny)) => {
+                        let mut diagnostic = diagnostic.with_note(format!("'{}' is denied", lint));
+                        diagnostic.severity = Severity::Error;
+                        Some(diagnostic)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    #[allow(unused)]
+    /// `level` returns the [`Level`] at which a warning with the provided
+    /// `code` is reported.
+    pub fn level(&self, code: Option<Code>) -> Level {
+        self.lookup(code)
+            .map(|(_, level)| *level)
+            .unwrap_or(Level::Warn)
+    }
+
+    /// `set` configures warnings selected by `lint` to be reported at `level`.
+    pub fn set(&mut self, lint: Lint, level: Level) -> &mut Self {
+        self.levels.push((lint, level));
+        self
+    }
+
+    /// `lookup` returns the configured [`Lint`] which determines the level of
+    /// a warning with the provided `code`.
+    fn lookup(&self, code: Option<Code>) -> Option<&(Lint, Level)> {
+        self.levels
+            .iter()
+            .filter(|(lint, _)| lint.matches(code))
+            .max_by_key(|(lint, _)| lint.specificity())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use chumsky::span::Span as SpanTrait;
+
+    use crate::lex::Span;
+
+    use super::*;
+
+    /* ---------------------------- Tests: Lint ----------------------------- */
+
+    #[test]
+    fn test_lint_from_str_accepts_codes_names_and_categories() {
+        // Given: Lints written as a code, a name, a category and `warnings`.
+        let inputs = ["W0004", "unused-include", "unused", "warnings"];
+
+        // When: Parsing each lint.
+        let got = inputs
+            .iter()
+            .map(|s| s.parse::<Lint>())
+            .collect::<Result<Vec<_>, _>>();
+
+        // Then: Each lint selects the expected warnings.
+        assert_eq!(
+            got,
+            Ok(vec![
+                Lint::Code(Code::UnusedInclude),
+                Lint::Code(Code::UnusedInclude),
+                Lint::Category("unused"),
+                Lint::Warnings,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_lint_from_str_rejects_unknown() {
+        // Given: An unknown lint.
+        // When: Parsing the lint.
+        let parsed = "unused-imports".parse::<Lint>();
+
+        // Then: Parsing fails.
+        assert_eq!(parsed, Err("unknown lint: unused-imports".to_owned()));
+    }
+
+    /* ------------------------- Tests: LintLevels -------------------------- */
+
+    #[test]
+    fn test_lint_levels_default_to_warn() {
+        // Given: No configured levels.
+        let levels = LintLevels::default();
+
+        // When: Looking up the level of a warning.
+        let level = levels.level(Some(Code::UnusedType));
+
+        // Then: The warning is reported as-is.
+        assert_eq!(level, Level::Warn);
+    }
+
+    #[test]
+    fn test_lint_levels_prefer_specific_lints() {
+        // Given: Warnings are denied, but one category and code are adjusted.
+        let mut levels = LintLevels::default();
+        levels
+            .set(Lint::Code(Code::UnusedType), Level::Warn)
+            .set(Lint::Category("unused"), Level::Allow)
+            .set(Lint::Warnings, Level::Deny);
+
+        // When: Looking up the level of several warnings.
+        let got = [
+            levels.level(Some(Code::UnusedType)),
+            levels.level(Some(Code::UnusedInclude)),
+            levels.level(Some(Code::NamingConvention)),
+            levels.level(None),
+        ];
+
+        // Then: The most specific lint determines each level.
+        assert_eq!(got, [Level::Warn, Level::Allow, Level::Deny, Level::Deny]);
+    }
+
+    #[test]
+    fn test_lint_levels_prefer_last_of_equal_specificity() {
+        // Given: A code which is first allowed and then denied.
+        let mut levels = LintLevels::default();
+        levels
+            .set(Lint::Code(Code::UnusedType), Level::Allow)
+            .set(Lint::Code(Code::UnusedType), Level::Deny);
+
+        // When: Looking up the level of the warning.
+        let level = levels.level(Some(Code::UnusedType));
+
+        // Then: The last configured level wins.
+        assert_eq!(level, Level::Deny);
+    }
+
+    #[test]
+    fn test_lint_levels_apply_maps_severities() {
+        // Given: Levels which allow unused types and deny all other warnings.
+        let mut levels = LintLevels::default();
+        levels
+            .set(Lint::Warnings, Level::Deny)
+            .set(Lint::Code(Code::UnusedType), Level::Allow);
+
+        let diagnostics = vec![
+            Diagnostic::error(make_span(), "error").with_code(Code::DuplicateIndex),
+            Diagnostic::warning(make_span(), "unused").with_code(Code::UnusedType),
+            Diagnostic::warning(make_span(), "naming").with_code(Code::NamingConvention),
+        ];
+
+        // When: Applying the levels.
+        let got = levels.apply(diagnostics);
+
+        // Then: The allowed warning is dropped and the other is promoted.
+        let got = got
+            .iter()
+            .map(|d| (d.message.as_str(), d.severity, d.notes.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            got,
+            vec![
+                ("error", Severity::Error, vec![]),
+                (
+                    "naming",
+                    Severity::Error,
+                    vec!["'warnings' is denied".to_owned()]
+                ),
+            ]
+        );
+    }
+
+    /* --------------------------- Fn: make_span ---------------------------- */
+
+    fn make_span() -> Span {
+        SpanTrait::new(Default::default(), 0..1)
+    }
+}
@@ -5,14 +5,39 @@ use crate::visit::Visitor;
 mod report;
 pub use report::*;
 
+/* ------------------------------- Mod: Lint ------------------------------- */
+
+mod lint;
+pub use lint::*;
+
+/* ------------------------------ Mod: Suggest ----------------------------- */
+
+mod suggest;
+pub use suggest::*;
+
 /* ----------------------------- Mod: Analyzers ----------------------------- */
 
+mod deprecation;
+pub use deprecation::DeprecatedUsage;
+
 mod field_index;
 pub use field_index::FieldIndexUniqueness;
 
+mod naming;
+pub use naming::*;
+
+mod reserved;
+pub use reserved::ReservedUsage;
+
+mod suppress;
+pub use suppress::*;
+
 mod type_reference;
 pub use type_reference::*;
 
+mod unused;
+pub use unused::*;
+
 /* -------------------------------------------------------------------------- */
 /*                              Trait: Analyzer                               */
 /* -------------------------------------------------------------------------- */
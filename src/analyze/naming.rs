@@ -0,0 +1,447 @@
+use std::collections::HashSet;
+
+use derive_builder::Builder;
+use derive_more::Display;
+
+use crate::analyze::{Analyzer, Code, Diagnostic};
+use crate::ast;
+use crate::visit::{Visitor, walk};
+
+/* -------------------------------------------------------------------------- */
+/*                                 Enum: Case                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `Case` enumerates the supported identifier naming conventions.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Case {
+    /// Allow any naming convention (i.e. disable the check).
+    #[display("any case")]
+    Any,
+    /// Require `camelCase` names.
+    #[display("camelCase")]
+    Camel,
+    /// Require `PascalCase` names.
+    #[display("PascalCase")]
+    Pascal,
+    /// Require `snake_case` names.
+    #[display("snake_case")]
+    Snake,
+    /// Require `SCREAMING_SNAKE_CASE` names.
+    #[display("SCREAMING_SNAKE_CASE")]
+    ScreamingSnake,
+}
+
+/* ------------------------------- Impl: Case ------------------------------- */
+
+impl Case {
+    /// `matches` returns whether `name` conforms to this naming convention.
+    pub fn matches(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let Some(first) = chars.next() else {
+            return true;
+        };
+
+        match self {
+            Case::Any => true,
+            Case::Camel => first.is_ascii_lowercase() && !name.contains('_'),
+            Case::Pascal => first.is_ascii_uppercase() && !name.contains('_'),
+            Case::Snake => name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+            Case::ScreamingSnake => name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'),
+        }
+    }
+
+    /// `convert` converts `name` into this naming convention.
+    pub fn convert(&self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            Case::Any => name.to_owned(),
+            Case::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| match i {
+                    0 => w.to_ascii_lowercase(),
+                    _ => capitalize(w),
+                })
+                .collect(),
+            Case::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Case::Snake => words
+                .iter()
+                .map(|w| w.to_ascii_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            Case::ScreamingSnake => words
+                .iter()
+                .map(|w| w.to_ascii_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+        }
+    }
+}
+
+/* ---------------------------- Fn: split_words ----------------------------- */
+
+/// `split_words` splits an identifier into its component words, breaking on
+/// underscores and changes in letter case (e.g. `HTTPServer` yields `HTTP` and
+/// `Server`).
+fn split_words(name: &str) -> Vec<String> {
+    let chars = name.chars().collect::<Vec<_>>();
+
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1);
+
+            let is_word_start = prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()));
+
+            if is_word_start {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/* ----------------------------- Fn: capitalize ----------------------------- */
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => {
+            first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+        }
+        None => String::new(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: NamingRules                            */
+/* -------------------------------------------------------------------------- */
+
+/// `NamingRules` configures the naming conventions enforced for each category
+/// of identifier.
+#[derive(Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(default)]
+pub struct NamingRules {
+    /// `fields` is the convention for message field names.
+    pub fields: Case,
+    /// `types` is the convention for message and enum names.
+    pub types: Case,
+    /// `variants` is the convention for enum variant names.
+    pub variants: Case,
+}
+
+/* ---------------------------- Impl: Default ------------------------------- */
+
+impl Default for NamingRules {
+    fn default() -> Self {
+        Self {
+            fields: Case::Snake,
+            types: Case::Pascal,
+            variants: Case::Pascal,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Keywords                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Keywords` is the set of reserved words for a single target language.
+#[derive(Clone, Debug, Default)]
+pub struct Keywords {
+    /// `language` is the name of the target language (e.g. "rust").
+    pub language: String,
+    /// `words` contains the language's reserved words.
+    pub words: HashSet<String>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                        Analyzer: NamingConvention                          */
+/* -------------------------------------------------------------------------- */
+
+/// `NamingConvention` warns about declarations whose names don't follow the
+/// configured [`NamingRules`] or which collide with a reserved keyword in any
+/// enabled target language.
+pub struct NamingConvention<'a> {
+    diagnostics: Vec<Diagnostic>,
+    keywords: &'a [Keywords],
+    rules: &'a NamingRules,
+}
+
+/* ------------------------- Impl: NamingConvention ------------------------- */
+
+impl<'a> NamingConvention<'a> {
+    /// `new` creates a new [`NamingConvention`] analyzer.
+    pub fn new(rules: &'a NamingRules, keywords: &'a [Keywords]) -> Self {
+        Self {
+            diagnostics: Vec::new(),
+            keywords,
+            rules,
+        }
+    }
+
+    /// `check` validates a single declaration name of the given `category`.
+    fn check(&mut self, category: &str, case: Case, name: &ast::Ident) {
+        if !case.matches(&name.name) {
+            let suggestion = case.convert(&name.name);
+
+            let mut diagnostic = Diagnostic::warning(
+                name.span.clone(),
+                format!("{} name '{}' should be {}", category, name.name, case),
+            )
+            .with_code(Code::NamingConvention);
+
+            if suggestion != name.name && !suggestion.is_empty() {
+                diagnostic = diagnostic.with_help(format!("consider renaming to '{}'", suggestion));
+            }
+
+            self.diagnostics.push(diagnostic);
+        }
+
+        for keywords in self.keywords {
+            if keywords.words.contains(&name.name) {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        name.span.clone(),
+                        format!(
+                            "{} name '{}' is a reserved keyword in {}",
+                            category, name.name, keywords.language
+                        ),
+                    )
+                    .with_code(Code::KeywordCollision)
+                    .with_help(format!("consider renaming to '{}_'", name.name)),
+                );
+            }
+        }
+    }
+}
+
+/* ----------------------------- Impl: Analyzer ----------------------------- */
+
+impl Analyzer for NamingConvention<'_> {
+    fn drain_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+/* ------------------------------ Impl: Visitor ----------------------------- */
+
+impl<'ast> Visitor<'ast> for NamingConvention<'_> {
+    fn visit_enum(&mut self, enum_: &'ast ast::Enum) {
+        self.check("enum", self.rules.types, &enum_.name);
+
+        // NOTE: Enums contain no nested declarations, so items are checked here
+        // directly rather than walked (which would treat field variants as
+        // message fields).
+        for item in &enum_.items {
+            match item {
+                ast::EnumItem::UnitVariant(variant) => {
+                    self.check("variant", self.rules.variants, &variant.name)
+                }
+                ast::EnumItem::FieldVariant(field) => {
+                    self.check("variant", self.rules.variants, &field.name)
+                }
+                ast::EnumItem::CommentBlock(_) | ast::EnumItem::Reserved(_) => {}
+            }
+        }
+    }
+
+    fn visit_message(&mut self, msg: &'ast ast::Message) {
+        self.check("message", self.rules.types, &msg.name);
+
+        for item in &msg.items {
+            if let ast::MessageItem::Field(field) = item {
+                self.check("field", self.rules.fields, &field.name);
+            }
+        }
+
+        walk::walk_message(self, msg);
+    }
+
+    fn visit_field(&mut self, _: &'ast ast::Field) {
+        // Fields are checked by their enclosing declaration.
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyze::Severity;
+    use crate::lex::Span;
+
+    /* ---------------------------- Tests: Case ----------------------------- */
+
+    #[test]
+    fn test_case_matches() {
+        // Given/When/Then: Names are validated against each convention.
+        assert!(Case::Snake.matches("player_id"));
+        assert!(!Case::Snake.matches("playerId"));
+        assert!(Case::Pascal.matches("PlayerState"));
+        assert!(!Case::Pascal.matches("player_state"));
+        assert!(Case::Camel.matches("playerId"));
+        assert!(!Case::Camel.matches("PlayerId"));
+        assert!(Case::ScreamingSnake.matches("MAX_PLAYERS"));
+        assert!(!Case::ScreamingSnake.matches("MaxPlayers"));
+        assert!(Case::Any.matches("whatever_Case"));
+    }
+
+    #[test]
+    fn test_case_convert() {
+        // Given/When/Then: Names are converted between conventions.
+        assert_eq!(Case::Snake.convert("playerId"), "player_id");
+        assert_eq!(Case::Snake.convert("HTTPServer"), "http_server");
+        assert_eq!(Case::Pascal.convert("player_state"), "PlayerState");
+        assert_eq!(Case::Camel.convert("player_state"), "playerState");
+        assert_eq!(Case::ScreamingSnake.convert("maxPlayers"), "MAX_PLAYERS");
+    }
+
+    /* ------------------------ Tests: NamingConvention --------------------- */
+
+    #[test]
+    fn test_field_violating_convention_warns() {
+        // Given: A message with a camelCase field.
+        let msg = make_message("Player", vec!["playerId"]);
+
+        // When: Analyzing the message with the default rules.
+        let diagnostics = analyze(&msg, &[]);
+
+        // Then: A warning with a suggested fix is reported.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].message,
+            "field name 'playerId' should be snake_case"
+        );
+        assert_eq!(
+            diagnostics[0].help,
+            vec!["consider renaming to 'player_id'"]
+        );
+    }
+
+    #[test]
+    fn test_type_violating_convention_warns() {
+        // Given: A message with a snake_case name.
+        let msg = make_message("player_state", vec![]);
+
+        // When: Analyzing the message with the default rules.
+        let diagnostics = analyze(&msg, &[]);
+
+        // Then: A warning with a suggested fix is reported.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].help,
+            vec!["consider renaming to 'PlayerState'"]
+        );
+    }
+
+    #[test]
+    fn test_field_colliding_with_keyword_warns() {
+        // Given: A message with a field named after a target language keyword.
+        let msg = make_message("Item", vec!["type"]);
+        let keywords = vec![Keywords {
+            language: "rust".to_owned(),
+            words: HashSet::from(["type".to_owned()]),
+        }];
+
+        // When: Analyzing the message.
+        let diagnostics = analyze(&msg, &keywords);
+
+        // Then: A keyword collision warning is reported.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "field name 'type' is a reserved keyword in rust"
+        );
+        assert_eq!(diagnostics[0].help, vec!["consider renaming to 'type_'"]);
+    }
+
+    #[test]
+    fn test_conforming_names_are_allowed() {
+        // Given: A message whose names follow the default conventions.
+        let msg = make_message("Player", vec!["player_id", "pos_v2"]);
+
+        // When: Analyzing the message.
+        let diagnostics = analyze(&msg, &[]);
+
+        // Then: No warnings are reported.
+        assert!(diagnostics.is_empty());
+    }
+
+    /* ---------------------------- Fn: analyze ----------------------------- */
+
+    fn analyze(msg: &ast::Message, keywords: &[Keywords]) -> Vec<Diagnostic> {
+        let rules = NamingRules::default();
+        let mut analyzer = NamingConvention::new(&rules, keywords);
+        analyzer.visit_message(msg);
+        analyzer.drain_diagnostics()
+    }
+
+    /* -------------------------- Fn: make_message -------------------------- */
+
+    fn make_message(name: &str, fields: Vec<&str>) -> ast::Message {
+        ast::Message {
+            annotations: vec![],
+            comment: None,
+            items: fields
+                .into_iter()
+                .map(|f| {
+                    ast::MessageItem::Field(ast::Field {
+                        annotations: vec![],
+                        comment: None,
+                        inline_comment: None,
+                        encoding: None,
+                        index: None,
+                        kind: ast::Type::Scalar(ast::Scalar {
+                            kind: ast::ScalarType::Bool,
+                            span: Span::default(),
+                        }),
+                        name: make_ident(f),
+                        span: Span::default(),
+                    })
+                })
+                .collect(),
+            name: make_ident(name),
+            span: Span::default(),
+        }
+    }
+
+    /* --------------------------- Fn: make_ident --------------------------- */
+
+    fn make_ident(name: &str) -> ast::Ident {
+        ast::Ident {
+            name: name.to_owned(),
+            span: Span::default(),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use derive_more::Display;
+
+/* -------------------------------------------------------------------------- */
+/*                                 Enum: Code                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `Code` is a stable identifier for a category of [`super::Diagnostic`].
+///
+/// NOTE: Codes must never be renumbered or reused; retire a code by leaving it
+/// in place instead.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Code {
+    /* ------------------------------- Errors ------------------------------- */
+    #[display("E0001")]
+    InvalidToken,
+
+    #[display("E0002")]
+    InvalidSyntax,
+
+    #[display("E0003")]
+    DuplicateIndex,
+
+    #[display("E0004")]
+    UnresolvedReference,
+
+    #[display("E0005")]
+    InvalidReferenceKind,
+
+    #[display("E0006")]
+    UnresolvedImport,
+
+    #[display("E0007")]
+    UnreadableFile,
+
+    #[display("E0008")]
+    InvalidPackage,
+
+    #[display("E0009")]
+    MisplacedDeclaration,
+
+    #[display("E0010")]
+    ReservedUsage,
+
+    /* ------------------------------ Warnings ------------------------------ */
+    #[display("W0001")]
+    DeprecatedUsage,
+
+    #[display("W0002")]
+    NamingConvention,
+
+    #[display("W0003")]
+    KeywordCollision,
+
+    #[display("W0004")]
+    UnusedInclude,
+
+    #[display("W0005")]
+    UnusedType,
+
+    #[display("W0006")]
+    InvalidSuppression,
+}
+
+/* ------------------------------- Impl: Code ------------------------------- */
+
+impl Code {
+    /// `ALL` contains every [`Code`], ordered by its identifier.
+    pub const ALL: &[Code] = &[
+        Code::InvalidToken,
+        Code::InvalidSyntax,
+        Code::DuplicateIndex,
+        Code::UnresolvedReference,
+        Code::InvalidReferenceKind,
+        Code::UnresolvedImport,
+        Code::UnreadableFile,
+        Code::InvalidPackage,
+        Code::MisplacedDeclaration,
+        Code::ReservedUsage,
+        Code::DeprecatedUsage,
+        Code::NamingConvention,
+        Code::KeywordCollision,
+        Code::UnusedInclude,
+        Code::UnusedType,
+        Code::InvalidSuppression,
+    ];
+
+    /// `category` returns the name of the group of related warnings to which
+    /// the [`Code`] belongs, if any. Categories can be used to configure the
+    /// level of several lints at once.
+    pub fn category(&self) -> Option<&'static str> {
+        match self {
+            Code::DeprecatedUsage => Some("deprecated"),
+            Code::NamingConvention | Code::KeywordCollision => Some("naming"),
+            Code::UnusedInclude | Code::UnusedType => Some("unused"),
+            Code::InvalidSuppression => Some("suppression"),
+            _ => None,
+        }
+    }
+
+    /// `explanation` returns a long-form description of the [`Code`], including
+    /// a minimal example of how it arises and how to fix it.
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            Code::InvalidToken => include_str!("explain/E0001.md"),
+            Code::InvalidSyntax => include_str!("explain/E0002.md"),
+            Code::DuplicateIndex => include_str!("explain/E0003.md"),
+            Code::UnresolvedReference => include_str!("explain/E0004.md"),
+            Code::InvalidReferenceKind => include_str!("explain/E0005.md"),
+            Code::UnresolvedImport => include_str!("explain/E0006.md"),
+            Code::UnreadableFile => include_str!("explain/E0007.md"),
+            Code::InvalidPackage => include_str!("explain/E0008.md"),
+            Code::MisplacedDeclaration => include_str!("explain/E0009.md"),
+            Code::ReservedUsage => include_str!("explain/E0010.md"),
+            Code::DeprecatedUsage => include_str!("explain/W0001.md"),
+            Code::NamingConvention => include_str!("explain/W0002.md"),
+            Code::KeywordCollision => include_str!("explain/W0003.md"),
+            Code::UnusedInclude => include_str!("explain/W0004.md"),
+            Code::UnusedType => include_str!("explain/W0005.md"),
+            Code::InvalidSuppression => include_str!("explain/W0006.md"),
+        }
+    }
+
+    /// `name` returns a descriptive, kebab-case alias for the [`Code`] (e.g.
+    /// `unused-include`), which may be used in place of its identifier.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Code::InvalidToken => "invalid-token",
+            Code::InvalidSyntax => "invalid-syntax",
+            Code::DuplicateIndex => "duplicate-index",
+            Code::UnresolvedReference => "unresolved-reference",
+            Code::InvalidReferenceKind => "invalid-reference-kind",
+            Code::UnresolvedImport => "unresolved-import",
+            Code::UnreadableFile => "unreadable-file",
+            Code::InvalidPackage => "invalid-package",
+            Code::MisplacedDeclaration => "misplaced-declaration",
+            Code::ReservedUsage => "reserved-usage",
+            Code::DeprecatedUsage => "deprecated-usage",
+            Code::NamingConvention => "naming-convention",
+            Code::KeywordCollision => "keyword-collision",
+            Code::UnusedInclude => "unused-include",
+            Code::UnusedType => "unused-type",
+            Code::InvalidSuppression => "invalid-suppression",
+        }
+    }
+
+    /// `summary` returns a short, human-readable description of the [`Code`].
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Code::InvalidToken => "invalid token",
+            Code::InvalidSyntax => "invalid syntax",
+            Code::DuplicateIndex => "duplicate field index",
+            Code::UnresolvedReference => "unresolved type reference",
+            Code::InvalidReferenceKind => "reference to a non-type",
+            Code::UnresolvedImport => "unresolved import",
+            Code::UnreadableFile => "unreadable schema file",
+            Code::InvalidPackage => "invalid or missing package",
+            Code::MisplacedDeclaration => "declaration before package",
+            Code::ReservedUsage => "use of a reserved index or name",
+            Code::DeprecatedUsage => "use of a deprecated type",
+            Code::NamingConvention => "naming convention violation",
+            Code::KeywordCollision => "name collides with a target-language keyword",
+            Code::UnusedInclude => "unused include",
+            Code::UnusedType => "unused type",
+            Code::InvalidSuppression => "malformed suppression comment",
+        }
+    }
+}
+
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for Code {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Code::ALL
+            .iter()
+            .find(|code| code.to_string().eq_ignore_ascii_case(s) || code.name() == s)
+            .copied()
+            .ok_or_else(|| format!("unknown diagnostic code: {}", s))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ---------------------------- Tests: Code ----------------------------- */
+
+    #[test]
+    fn test_code_identifiers_are_unique_and_ordered() {
+        // Given: The identifiers of every code.
+        let ids = Code::ALL.iter().map(Code::to_string).collect::<Vec<_>>();
+
+        // When: Sorting and deduplicating the identifiers.
+        let mut sorted = ids.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        // Then: The identifiers are already unique and ordered.
+        assert_eq!(ids, sorted);
+    }
+
+    #[test]
+    fn test_code_from_str_round_trips() {
+        // Given: Every code.
+        for code in Code::ALL {
+            // When: Parsing the code's identifier (case-insensitively).
+            let parsed = code.to_string().to_lowercase().parse::<Code>();
+
+            // Then: The original code is returned.
+            assert_eq!(parsed, Ok(*code));
+        }
+    }
+
+    #[test]
+    fn test_code_from_str_accepts_names() {
+        // Given: Every code.
+        for code in Code::ALL {
+            // When: Parsing the code's descriptive name.
+            let parsed = code.name().parse::<Code>();
+
+            // Then: The original code is returned.
+            assert_eq!(parsed, Ok(*code));
+        }
+    }
+
+    #[test]
+    fn test_code_from_str_rejects_unknown() {
+        // Given: An unknown code identifier.
+        // When: Parsing the identifier.
+        let parsed = "E9999".parse::<Code>();
+
+        // Then: Parsing fails.
+        assert!(parsed.is_err());
+    }
+}
@@ -1,16 +1,30 @@
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
 
 use crate::lex::Span;
 
+use super::Code;
+
 /* -------------------------------------------------------------------------- */
 /*                             Struct: Diagnostic                             */
 /* -------------------------------------------------------------------------- */
 
 /// `Diagnostic` represents a compilation error, warning, or informational
 /// message with source location information.
-#[derive(Clone, Debug)]
+///
+/// Beyond its primary location, a diagnostic may point at related locations
+/// (possibly in other files) via secondary [`Label`]s and carry free-form
+/// notes and help text.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Diagnostic {
+    pub code: Option<Code>,
+    pub help: Vec<String>,
+    /// `label` is the text attached to the primary span; defaults to
+    /// `message` if unset.
+    pub label: Option<String>,
+    pub labels: Vec<Label>,
     pub message: String,
+    pub notes: Vec<String>,
     pub severity: Severity,
     pub span: Span,
 }
@@ -20,30 +34,91 @@ pub struct Diagnostic {
 impl Diagnostic {
     /// `error` creates a new error [`Diagnostic`].
     pub fn error<T: AsRef<str>>(span: Span, message: T) -> Self {
-        Self {
-            message: message.as_ref().to_owned(),
-            severity: Severity::Error,
-            span,
-        }
+        Self::new(Severity::Error, span, message)
+    }
+
+    /// `is_spanless` returns whether the [`Diagnostic`] lacks a source location
+    /// (e.g. an input file which couldn't be read).
+    pub fn is_spanless(&self) -> bool {
+        self.span.context.as_path().as_os_str().is_empty()
+    }
+
+    /// `primary_label` returns the text attached to the primary span.
+    pub fn primary_label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.message)
     }
 
-    #[allow(unused)]
     /// `warning` creates a new warning [`Diagnostic`].
     pub fn warning<T: AsRef<str>>(span: Span, message: T) -> Self {
+        Self::new(Severity::Warning, span, message)
+    }
+
+    /// `with_code` sets the stable [`Code`] identifying the [`Diagnostic`].
+    pub fn with_code(mut self, code: Code) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// `with_help` appends a suggestion for resolving the [`Diagnostic`].
+    pub fn with_help<T: AsRef<str>>(mut self, help: T) -> Self {
+        self.help.push(help.as_ref().to_owned());
+        self
+    }
+
+    /// `with_label` sets the text attached to the primary span.
+    pub fn with_label<T: AsRef<str>>(mut self, label: T) -> Self {
+        self.label = Some(label.as_ref().to_owned());
+        self
+    }
+
+    /// `with_note` appends additional context to the [`Diagnostic`].
+    pub fn with_note<T: AsRef<str>>(mut self, note: T) -> Self {
+        self.notes.push(note.as_ref().to_owned());
+        self
+    }
+
+    /// `with_secondary` attaches a secondary [`Label`] pointing at a related
+    /// location.
+    pub fn with_secondary<T: AsRef<str>>(mut self, span: Span, message: T) -> Self {
+        self.labels.push(Label {
+            message: message.as_ref().to_owned(),
+            span,
+        });
+        self
+    }
+
+    fn new<T: AsRef<str>>(severity: Severity, span: Span, message: T) -> Self {
         Self {
+            code: None,
+            help: Vec::new(),
+            label: None,
+            labels: Vec::new(),
             message: message.as_ref().to_owned(),
-            severity: Severity::Warning,
+            notes: Vec::new(),
+            severity,
             span,
         }
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Label                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Label` annotates a location related to a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Label {
+    pub message: String,
+    pub span: Span,
+}
+
 /* -------------------------------------------------------------------------- */
 /*                               Enum: Severity                               */
 /* -------------------------------------------------------------------------- */
 
 /// `Severity` indicates the importance level of a diagnostic message.
-#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Deserialize, Display, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     #[display("error")]
     Error,
@@ -73,3 +148,67 @@ impl<'a> From<Severity> for ariadne::ReportKind<'a> {
         }
     }
 }
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chumsky::span::Span as SpanTrait;
+
+    /* ------------------------- Tests: primary_label ----------------------- */
+
+    #[test]
+    fn test_primary_label_defaults_to_message() {
+        // Given: A diagnostic without an explicit primary label.
+        let diagnostic = Diagnostic::error(make_span(0..1), "duplicate index 0");
+
+        // When: Retrieving the primary label.
+        let label = diagnostic.primary_label();
+
+        // Then: The message is used.
+        assert_eq!(label, "duplicate index 0");
+    }
+
+    #[test]
+    fn test_primary_label_uses_override() {
+        // Given: A diagnostic with an explicit primary label.
+        let diagnostic =
+            Diagnostic::error(make_span(0..1), "duplicate index 0").with_label("reused here");
+
+        // When: Retrieving the primary label.
+        let label = diagnostic.primary_label();
+
+        // Then: The override is used.
+        assert_eq!(label, "reused here");
+    }
+
+    /* ------------------------ Tests: with_secondary ----------------------- */
+
+    #[test]
+    fn test_with_secondary_appends_labels_in_order() {
+        // Given: A diagnostic.
+        let diagnostic = Diagnostic::warning(make_span(4..5), "message");
+
+        // When: Attaching multiple secondary labels.
+        let diagnostic = diagnostic
+            .with_secondary(make_span(0..1), "first")
+            .with_secondary(make_span(2..3), "second");
+
+        // Then: The labels are recorded in order.
+        let got = diagnostic
+            .labels
+            .iter()
+            .map(|l| (l.message.as_str(), l.span.start))
+            .collect::<Vec<_>>();
+        assert_eq!(got, vec![("first", 0), ("second", 2)]);
+    }
+
+    /* --------------------------- Fn: make_span ---------------------------- */
+
+    fn make_span(range: std::ops::Range<usize>) -> Span {
+        SpanTrait::new(Default::default(), range)
+    }
+}
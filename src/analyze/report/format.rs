@@ -0,0 +1,32 @@
+use crate::compile::SourceCache;
+
+use super::{DiagnosticReporter, JsonReporter, Reporter, SarifReporter};
+
+/* -------------------------------------------------------------------------- */
+/*                          Enum: DiagnosticsFormat                           */
+/* -------------------------------------------------------------------------- */
+
+/// `DiagnosticsFormat` selects how diagnostics are rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiagnosticsFormat {
+    /// Rich, human-readable reports written to stderr.
+    #[default]
+    Human,
+    /// A JSON document written to stdout.
+    Json,
+    /// A SARIF 2.1.0 log written to stdout.
+    Sarif,
+}
+
+/* ------------------------ Impl: DiagnosticsFormat ------------------------- */
+
+impl DiagnosticsFormat {
+    /// `reporter` creates the [`Reporter`] implementing this format.
+    pub fn reporter<'a>(&self, sources: &'a SourceCache) -> Box<dyn Reporter + 'a> {
+        match self {
+            DiagnosticsFormat::Human => Box::new(DiagnosticReporter::new(sources)),
+            DiagnosticsFormat::Json => Box::new(JsonReporter::new(sources)),
+            DiagnosticsFormat::Sarif => Box::new(SarifReporter::new(sources)),
+        }
+    }
+}
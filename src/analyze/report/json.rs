@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use crate::analyze::Diagnostic;
+use crate::compile::SourceCache;
+
+use super::{Location, Position, Reporter};
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: JsonReporter                            */
+/* -------------------------------------------------------------------------- */
+
+/// `JsonReporter` renders diagnostics as a single JSON document, suitable for
+/// consumption by CI systems and editor integrations.
+pub struct JsonReporter<'a> {
+    sources: &'a SourceCache,
+}
+
+/* --------------------------- Impl: JsonReporter --------------------------- */
+
+impl<'a> JsonReporter<'a> {
+    /// `new` constructs a new [`JsonReporter`] using the provided
+    /// [`SourceCache`] for resolving line and column information.
+    pub fn new(sources: &'a SourceCache) -> Self {
+        Self { sources }
+    }
+}
+
+/* ----------------------------- Impl: Reporter ----------------------------- */
+
+impl Reporter for JsonReporter<'_> {
+    fn report(&self, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.write(diagnostics, &mut std::io::stdout())
+    }
+
+    fn write(
+        &self,
+        diagnostics: &[Diagnostic],
+        writer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        let output = Output {
+            diagnostics: diagnostics
+                .iter()
+                .map(|d| Entry {
+                    code: d.code.map(|c| c.to_string()),
+                    severity: d.severity.to_string(),
+                    message: &d.message,
+                    location: Location::of(d, self.sources).map(EntryLocation::from),
+                    label: d.primary_label(),
+                    labels: d
+                        .labels
+                        .iter()
+                        .map(|label| EntryLabel {
+                            message: &label.message,
+                            location: Location::of_span(&label.span, self.sources)
+                                .map(EntryLocation::from),
+                        })
+                        .collect(),
+                    notes: &d.notes,
+                    help: &d.help,
+                })
+                .collect(),
+        };
+
+        serde_json::to_writer_pretty(&mut *writer, &output)?;
+        writeln!(writer)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Output                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Output` is the top-level JSON document emitted by [`JsonReporter`].
+#[derive(Serialize)]
+struct Output<'a> {
+    diagnostics: Vec<Entry<'a>>,
+}
+
+/* ------------------------------ Struct: Entry ----------------------------- */
+
+/// `Entry` is the JSON representation of a single [`Diagnostic`].
+#[derive(Serialize)]
+struct Entry<'a> {
+    code: Option<String>,
+    severity: String,
+    message: &'a str,
+    location: Option<EntryLocation>,
+    /// `label` is the text attached to the primary location.
+    label: &'a str,
+    labels: Vec<EntryLabel<'a>>,
+    notes: &'a [String],
+    help: &'a [String],
+}
+
+/* ---------------------------- Struct: EntryLabel --------------------------- */
+
+/// `EntryLabel` is the JSON representation of a secondary [`super::Label`].
+#[derive(Serialize)]
+struct EntryLabel<'a> {
+    message: &'a str,
+    location: Option<EntryLocation>,
+}
+
+/* -------------------------- Struct: EntryLocation ------------------------- */
+
+/// `EntryLocation` is the JSON representation of a [`Location`]. Byte offsets
+/// are 0-based; lines and columns are 1-based. All ranges are end-exclusive.
+#[derive(Serialize)]
+struct EntryLocation {
+    file: String,
+    bytes: EntryRange<usize>,
+    range: EntryRange<Position>,
+}
+
+/* ---------------------------- Struct: EntryRange --------------------------- */
+
+#[derive(Serialize)]
+struct EntryRange<T> {
+    start: T,
+    end: T,
+}
+
+/* ------------------------ Impl: From<Location> ---------------------------- */
+
+impl From<Location> for EntryLocation {
+    fn from(value: Location) -> Self {
+        Self {
+            file: value.file,
+            bytes: EntryRange {
+                start: value.start,
+                end: value.end,
+            },
+            range: EntryRange {
+                start: value.start_position,
+                end: value.end_position,
+            },
+        }
+    }
+}
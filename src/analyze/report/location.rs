@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+use crate::analyze::Diagnostic;
+use crate::compile::SourceCache;
+use crate::lex::Span;
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Position                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Position` is a 1-based line and column within a source file. Columns are
+/// measured in Unicode code points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/* ---------------------------- Impl: Position ------------------------------ */
+
+impl Position {
+    /// `from_offset` converts a byte `offset` within `source` into a
+    /// [`Position`]. Offsets past the end of `source` are clamped.
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let prefix = &source[..offset];
+        let line_start = prefix.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        Self {
+            line: prefix.matches('\n').count() + 1,
+            column: prefix[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Location                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Location` describes where a [`Diagnostic`] (or one of its labels) occurs,
+/// as both a byte range and a line/column range (with an exclusive end).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub file: String,
+    pub start: usize,
+    pub end: usize,
+    pub start_position: Position,
+    pub end_position: Position,
+}
+
+/* ---------------------------- Impl: Location ------------------------------ */
+
+impl Location {
+    /// `of` determines the [`Location`] of the primary span of `diagnostic`,
+    /// returning `None` if it's spanless or its source isn't available in
+    /// `sources`.
+    pub fn of(diagnostic: &Diagnostic, sources: &SourceCache) -> Option<Self> {
+        if diagnostic.is_spanless() {
+            return None;
+        }
+
+        Self::of_span(&diagnostic.span, sources)
+    }
+
+    /// `of_span` determines the [`Location`] of `span`, returning `None` if its
+    /// source isn't available in `sources`.
+    pub fn of_span(span: &Span, sources: &SourceCache) -> Option<Self> {
+        let source = sources.read(&span.context)?;
+
+        Some(Self {
+            file: span.context.to_string(),
+            start: span.start,
+            end: span.end,
+            start_position: Position::from_offset(&source, span.start),
+            end_position: Position::from_offset(&source, span.end),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ------------------------- Tests: from_offset ------------------------- */
+
+    #[test]
+    fn test_position_from_offset_first_line() {
+        // Given: An offset within the first line.
+        // When: Converting the offset into a position.
+        let got = Position::from_offset("package a;\n", 8);
+
+        // Then: The position is on the first line.
+        assert_eq!(got, Position { line: 1, column: 9 });
+    }
+
+    #[test]
+    fn test_position_from_offset_later_line() {
+        // Given: An offset at the start of the third line.
+        // When: Converting the offset into a position.
+        let got = Position::from_offset("a\nb\ncd", 4);
+
+        // Then: The position is at the start of the third line.
+        assert_eq!(got, Position { line: 3, column: 1 });
+    }
+
+    #[test]
+    fn test_position_from_offset_counts_code_points() {
+        // Given: A line containing a multi-byte character.
+        // When: Converting an offset after the character into a position.
+        let got = Position::from_offset("// é x", 6);
+
+        // Then: The column counts the character once.
+        assert_eq!(got, Position { line: 1, column: 6 });
+    }
+
+    #[test]
+    fn test_position_from_offset_clamps_to_end() {
+        // Given: An offset past the end of the source.
+        // When: Converting the offset into a position.
+        let got = Position::from_offset("ab", 10);
+
+        // Then: The position is clamped to the end of the source.
+        assert_eq!(got, Position { line: 1, column: 3 });
+    }
+}
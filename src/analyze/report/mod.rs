@@ -1,9 +1,52 @@
+/* ------------------------------- Mod: Code -------------------------------- */
+
+mod code;
+pub use code::*;
+
 /* ----------------------------- Mod: Diagnostic ---------------------------- */
 
 mod diagnostic;
 pub use diagnostic::*;
 
-/* ------------------------------ Mod: Reporter ----------------------------- */
+/* ------------------------------- Mod: Format ------------------------------ */
+
+mod format;
+pub use format::*;
+
+/* ------------------------------ Mod: Location ----------------------------- */
+
+mod location;
+pub use location::*;
+
+/* ------------------------------ Mod: Reporters ---------------------------- */
+
+mod json;
+pub use json::JsonReporter;
 
 mod reporter;
 pub use reporter::*;
+
+mod sarif;
+pub use sarif::SarifReporter;
+
+/* -------------------------------------------------------------------------- */
+/*                              Trait: Reporter                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Reporter` defines the interface for rendering a set of [`Diagnostic`]s
+/// produced by a compilation.
+///
+/// Reporters receive all diagnostics at once so that structured formats can
+/// emit a single, well-formed document.
+pub trait Reporter {
+    /// `report` renders the provided diagnostics to the reporter's preferred
+    /// output stream.
+    fn report(&self, diagnostics: &[Diagnostic]) -> std::io::Result<()>;
+
+    /// `write` renders the provided diagnostics to `writer`.
+    fn write(
+        &self,
+        diagnostics: &[Diagnostic],
+        writer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()>;
+}
@@ -1,5 +1,8 @@
 use crate::analyze::Diagnostic;
 use crate::compile::SourceCache;
+use crate::lex::Span;
+
+use super::Reporter;
 
 /* -------------------------------------------------------------------------- */
 /*                         Struct: DiagnosticReporter                         */
@@ -20,45 +23,186 @@ impl<'a> DiagnosticReporter<'a> {
         Self { sources }
     }
 
-    /// `report` prints information about the provided diagnostic to
-    /// [`std::io::stderr`].
-    pub fn report(&self, diagnostic: &Diagnostic) {
-        self.write(diagnostic, std::io::stderr());
+    /// `build` starts an [`ariadne::Report`] for `diagnostic` at the provided
+    /// location, including its [`super::Code`] (if any).
+    fn build<S>(diagnostic: &Diagnostic, span: S) -> ariadne::ReportBuilder<'static, S>
+    where
+        S: ariadne::Span,
+    {
+        let mut builder = ariadne::Report::build(diagnostic.severity.into(), span)
+            .with_config(ariadne::Config::new().with_index_type(ariadne::IndexType::Byte))
+            .with_message(&diagnostic.message);
+
+        if let Some(code) = diagnostic.code {
+            builder = builder.with_code(code);
+        }
+
+        for note in &diagnostic.notes {
+            builder.add_note(note);
+        }
+
+        for help in &diagnostic.help {
+            builder.add_help(help);
+        }
+
+        builder
     }
 
-    /// `write` reports information about the provided diagnostic to the
+    /// `write_one` reports information about the provided diagnostic to the
     /// provided [`std::io::Write`] implementer, assuming the output ultimately
     /// ends up routing to [`std::io::stderr`].
-    pub fn write<W>(&self, diagnostic: &Diagnostic, writer: W)
+    fn write_one<W>(&self, diagnostic: &Diagnostic, writer: W) -> std::io::Result<()>
     where
         W: std::io::Write,
     {
-        let import = &diagnostic.span.context;
-        let location = import.to_string();
-
-        let source = self
-            .sources
-            .read(import)
-            .expect("missing source file in cache");
+        if diagnostic.is_spanless() {
+            return self.write_spanless(diagnostic, writer);
+        }
 
-        let range = diagnostic.span.start..diagnostic.span.end;
+        let location = |span: &Span| (span.context.to_string(), span.start..span.end);
 
-        ariadne::Report::build(
+        let mut labels = std::iter::once((
+            &diagnostic.span,
+            diagnostic.primary_label(),
             diagnostic.severity.into(),
-            (location.clone(), range.clone()),
-        )
-        .with_config(ariadne::Config::new().with_index_type(ariadne::IndexType::Byte))
-        .with_message(&diagnostic.message)
-        .with_label(
-            ariadne::Label::new((location.clone(), range))
-                .with_message(&diagnostic.message)
-                .with_color(diagnostic.severity.into()),
+        ))
+        .chain(
+            diagnostic
+                .labels
+                .iter()
+                .map(|label| (&label.span, label.message.as_str(), ariadne::Color::Cyan)),
         )
-        .finish()
-        .write(
-            (location.clone(), ariadne::Source::from(source.as_ref())),
-            writer,
-        )
-        .unwrap();
+        .collect::<Vec<_>>();
+
+        // Order labels by their position, starting with those in the primary
+        // file, so that labels within the same file are rendered together.
+        labels.sort_by_key(|(span, _, _)| {
+            (
+                span.context != diagnostic.span.context,
+                span.context.to_string(),
+                span.start,
+            )
+        });
+
+        let labels = labels
+            .into_iter()
+            .enumerate()
+            .map(|(i, (span, message, color))| {
+                ariadne::Label::new(location(span))
+                    .with_message(message)
+                    .with_color(color)
+                    .with_order(i as i32)
+            });
+
+        // Secondary labels may refer to other files, so every file referenced
+        // by the diagnostic must be made available to the report.
+        let sources = std::iter::once(&diagnostic.span)
+            .chain(diagnostic.labels.iter().map(|label| &label.span))
+            .map(|span| {
+                let source = self
+                    .sources
+                    .read(&span.context)
+                    .expect("missing source file in cache");
+
+                (span.context.to_string(), source.as_ref().clone())
+            })
+            .collect::<Vec<_>>();
+
+        Self::build(diagnostic, location(&diagnostic.span))
+            .with_labels(labels)
+            .finish()
+            .write(ariadne::sources(sources), writer)
+    }
+
+    /// `write_spanless` reports a [`Diagnostic`] without a source location,
+    /// rendering only its message, notes and help.
+    fn write_spanless<W>(&self, diagnostic: &Diagnostic, mut writer: W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        Self::build(diagnostic, ((), 0..0))
+            .finish()
+            .write(ariadne::Source::from(""), &mut writer)?;
+
+        // NOTE: Reports without labels omit their notes and help, so these
+        // are written separately.
+        for note in &diagnostic.notes {
+            writeln!(writer, "    Note: {}", note)?;
+        }
+
+        for help in &diagnostic.help {
+            writeln!(writer, "    Help: {}", help)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* ----------------------------- Impl: Reporter ----------------------------- */
+
+impl Reporter for DiagnosticReporter<'_> {
+    fn report(&self, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.write(diagnostics, &mut std::io::stderr())
+    }
+
+    fn write(
+        &self,
+        diagnostics: &[Diagnostic],
+        writer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        for diagnostic in diagnostics {
+            self.write_one(diagnostic, &mut *writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lex::Span;
+
+    /* ---------------------------- Tests: write ---------------------------- */
+
+    #[test]
+    fn test_write_spanless_diagnostic() {
+        // Given: A diagnostic without a source location.
+        let sources = SourceCache::default();
+        let reporter = DiagnosticReporter::new(&sources);
+        let diagnostic = Diagnostic::error(Span::default(), "failed to read file");
+
+        // When: Writing the diagnostic.
+        let mut out = Vec::new();
+        reporter.write(&[diagnostic], &mut out).unwrap();
+
+        // Then: Only the message is rendered.
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out, "Error: failed to read file\n");
+    }
+
+    #[test]
+    fn test_write_spanless_diagnostic_with_notes() {
+        // Given: A diagnostic without a source location but with notes.
+        let sources = SourceCache::default();
+        let reporter = DiagnosticReporter::new(&sources);
+        let diagnostic = Diagnostic::error(Span::default(), "unsupported type")
+            .with_note("reported by generator 'foo'")
+            .with_help("remove the field");
+
+        // When: Writing the diagnostic.
+        let mut out = Vec::new();
+        reporter.write(&[diagnostic], &mut out).unwrap();
+
+        // Then: The notes and help follow the message.
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out,
+            "Error: unsupported type\n    Note: reported by generator 'foo'\n    Help: remove the field\n"
+        );
     }
 }
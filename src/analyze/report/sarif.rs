@@ -0,0 +1,128 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Value, json};
+
+use crate::analyze::{Diagnostic, Severity};
+use crate::compile::SourceCache;
+
+use super::{Location, Reporter};
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: SarifReporter                            */
+/* -------------------------------------------------------------------------- */
+
+/// `SarifReporter` renders diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log, suitable for upload to code scanning services.
+pub struct SarifReporter<'a> {
+    sources: &'a SourceCache,
+}
+
+/* --------------------------- Impl: SarifReporter -------------------------- */
+
+impl<'a> SarifReporter<'a> {
+    /// `new` constructs a new [`SarifReporter`] using the provided
+    /// [`SourceCache`] for resolving line and column information.
+    pub fn new(sources: &'a SourceCache) -> Self {
+        Self { sources }
+    }
+
+    /// `result` converts a [`Diagnostic`] into a SARIF `result` object.
+    fn result(&self, diagnostic: &Diagnostic) -> Value {
+        let level = match diagnostic.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+
+        let mut result = json!({
+            "level": level,
+            "message": { "text": diagnostic.message },
+        });
+
+        if let Some(code) = diagnostic.code {
+            result["ruleId"] = json!(code.to_string());
+        }
+
+        if let Some(location) = Location::of(diagnostic, self.sources) {
+            result["locations"] = json!([Self::location(location, diagnostic.primary_label())]);
+        }
+
+        let related = diagnostic
+            .labels
+            .iter()
+            .filter_map(|label| {
+                Location::of_span(&label.span, self.sources)
+                    .map(|location| Self::location(location, &label.message))
+            })
+            .collect::<Vec<_>>();
+
+        if !related.is_empty() {
+            result["relatedLocations"] = json!(related);
+        }
+
+        result
+    }
+
+    /// `location` converts a [`Location`] into a SARIF `location` object
+    /// annotated with `message`.
+    fn location(location: Location, message: &str) -> Value {
+        json!({
+            "message": { "text": message },
+            "physicalLocation": {
+                "artifactLocation": { "uri": format!("file://{}", location.file) },
+                "region": {
+                    "startLine": location.start_position.line,
+                    "startColumn": location.start_position.column,
+                    "endLine": location.end_position.line,
+                    "endColumn": location.end_position.column,
+                    "byteOffset": location.start,
+                    "byteLength": location.end - location.start,
+                },
+            },
+        })
+    }
+}
+
+/* ----------------------------- Impl: Reporter ----------------------------- */
+
+impl Reporter for SarifReporter<'_> {
+    fn report(&self, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+        self.write(diagnostics, &mut std::io::stdout())
+    }
+
+    fn write(
+        &self,
+        diagnostics: &[Diagnostic],
+        writer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        let rules = diagnostics
+            .iter()
+            .filter_map(|d| d.code)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|code| {
+                json!({
+                    "id": code.to_string(),
+                    "shortDescription": { "text": code.summary() },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "baproto",
+                        "rules": rules,
+                    },
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": diagnostics.iter().map(|d| self.result(d)).collect::<Vec<_>>(),
+            }],
+        });
+
+        serde_json::to_writer_pretty(&mut *writer, &log)?;
+        writeln!(writer)
+    }
+}
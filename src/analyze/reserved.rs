@@ -0,0 +1,119 @@
+use crate::analyze::{Analyzer, Code, Diagnostic};
+use crate::ast;
+use crate::visit::{Visitor, walk};
+
+/* -------------------------------------------------------------------------- */
+/*                          Analyzer: ReservedUsage                           */
+/* -------------------------------------------------------------------------- */
+
+/// `ReservedUsage` validates that no field or variant within an
+/// [`ast::Message`] or [`ast::Enum`] uses an index or name which has been
+/// declared as reserved by that type.
+#[derive(Default)]
+pub struct ReservedUsage {
+    diagnostics: Vec<Diagnostic>,
+}
+
+/* --------------------------- Impl: ReservedUsage -------------------------- */
+
+impl ReservedUsage {
+    /// `check` validates a single field or variant against the provided set of
+    /// reserved items.
+    fn check(
+        &mut self,
+        reserved: &[&ast::ReservedItem],
+        index: Option<&ast::FieldIndex>,
+        name: &ast::Ident,
+    ) {
+        if let Some(index) = index {
+            let value = index.value.value;
+            if let Some(item) = reserved.iter().find(|r| r.contains_index(value)) {
+                let message = match item {
+                    ast::ReservedItem::Range(range) => {
+                        format!("index {} is reserved (by range '{}')", value, range)
+                    }
+                    _ => format!("index {} is reserved", value),
+                };
+
+                self.diagnostics.push(
+                    Diagnostic::error(index.span.clone(), message)
+                        .with_code(Code::ReservedUsage)
+                        .with_secondary(item.span().clone(), "reserved here"),
+                );
+            }
+        }
+
+        if let Some(item) = reserved.iter().find(|r| r.contains_name(&name.name)) {
+            self.diagnostics.push(
+                Diagnostic::error(
+                    name.span.clone(),
+                    format!("name '{}' is reserved", name.name),
+                )
+                .with_code(Code::ReservedUsage)
+                .with_secondary(item.span().clone(), "reserved here"),
+            );
+        }
+    }
+}
+
+/* ----------------------------- Impl: Analyzer ----------------------------- */
+
+impl Analyzer for ReservedUsage {
+    fn drain_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+/* ------------------------------ Impl: Visitor ----------------------------- */
+
+impl<'ast> Visitor<'ast> for ReservedUsage {
+    fn visit_enum(&mut self, enum_: &'ast ast::Enum) {
+        let reserved = enum_
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ast::EnumItem::Reserved(r) => Some(r),
+                _ => None,
+            })
+            .flat_map(|r| r.items.iter())
+            .collect::<Vec<_>>();
+
+        if !reserved.is_empty() {
+            for item in &enum_.items {
+                match item {
+                    ast::EnumItem::UnitVariant(variant) => {
+                        self.check(&reserved, variant.index.as_ref(), &variant.name)
+                    }
+                    ast::EnumItem::FieldVariant(field) => {
+                        self.check(&reserved, field.index.as_ref(), &field.name)
+                    }
+                    ast::EnumItem::CommentBlock(_) | ast::EnumItem::Reserved(_) => {}
+                }
+            }
+        }
+
+        walk::walk_enum(self, enum_);
+    }
+
+    fn visit_message(&mut self, msg: &'ast ast::Message) {
+        let reserved = msg
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ast::MessageItem::Reserved(r) => Some(r),
+                _ => None,
+            })
+            .flat_map(|r| r.items.iter())
+            .collect::<Vec<_>>();
+
+        if !reserved.is_empty() {
+            for item in &msg.items {
+                if let ast::MessageItem::Field(field) = item {
+                    self.check(&reserved, field.index.as_ref(), &field.name);
+                }
+            }
+        }
+
+        walk::walk_message(self, msg);
+    }
+}
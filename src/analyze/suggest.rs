@@ -0,0 +1,141 @@
+/* -------------------------------------------------------------------------- */
+/*                             Fn: edit_distance                              */
+/* -------------------------------------------------------------------------- */
+
+/// `edit_distance` computes the Levenshtein distance between `a` and `b`, i.e.
+/// the minimum number of single-character insertions, deletions, or
+/// substitutions required to transform one into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                Fn: suggest                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `MAX_SUGGESTIONS` is the maximum number of suggestions returned by
+/// [`suggest`].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// `suggest` returns the `candidates` closest to `target`, ordered by their
+/// edit distance (ties are broken alphabetically). Each candidate is paired
+/// with the key it should be compared by; candidates whose key is too
+/// dissimilar from `target` are excluded.
+pub fn suggest<I, K, V>(target: &str, candidates: I) -> Vec<V>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let threshold = (target.chars().count() / 3).max(1);
+
+    let mut matches = candidates
+        .into_iter()
+        .map(|(key, value)| (edit_distance(target, key.as_ref()), value))
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|(d1, v1), (d2, v2)| d1.cmp(d2).then_with(|| v1.as_ref().cmp(v2.as_ref())));
+    matches.dedup_by(|(_, v1), (_, v2)| v1.as_ref() == v2.as_ref());
+
+    matches
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, value)| value)
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ------------------------ Tests: edit_distance ------------------------ */
+
+    #[test]
+    fn test_edit_distance_identical_strings() {
+        // Given: Two identical strings.
+        // When: Computing the edit distance.
+        // Then: The distance is zero.
+        assert_eq!(edit_distance("User", "User"), 0);
+    }
+
+    #[test]
+    fn test_edit_distance_empty_string() {
+        // Given: An empty string and a non-empty string.
+        // When: Computing the edit distance.
+        // Then: The distance is the length of the non-empty string.
+        assert_eq!(edit_distance("", "User"), 4);
+        assert_eq!(edit_distance("User", ""), 4);
+    }
+
+    #[test]
+    fn test_edit_distance_mixed_edits() {
+        // Given: Strings differing by insertions and substitutions.
+        // When: Computing the edit distance.
+        // Then: The minimum number of edits is returned.
+        assert_eq!(edit_distance("Usr", "User"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    /* --------------------------- Tests: suggest --------------------------- */
+
+    #[test]
+    fn test_suggest_orders_by_distance() {
+        // Given: Candidates at varying distances from the target.
+        let candidates = vec![
+            ("Accounts", "a.Accounts"),
+            ("Account", "a.Account"),
+            ("Unrelated", "a.Unrelated"),
+        ];
+
+        // When: Computing suggestions.
+        let got = suggest("Acount", candidates);
+
+        // Then: Only close candidates are returned, closest first.
+        assert_eq!(got, vec!["a.Account", "a.Accounts"]);
+    }
+
+    #[test]
+    fn test_suggest_limits_results() {
+        // Given: More close candidates than the maximum.
+        let candidates = vec![("Ab", "Ab"), ("Ac", "Ac"), ("Ad", "Ad"), ("Ae", "Ae")];
+
+        // When: Computing suggestions.
+        let got = suggest("Aa", candidates);
+
+        // Then: At most `MAX_SUGGESTIONS` are returned, alphabetically.
+        assert_eq!(got, vec!["Ab", "Ac", "Ad"]);
+    }
+
+    #[test]
+    fn test_suggest_no_close_candidates() {
+        // Given: Candidates that are all dissimilar to the target.
+        let candidates = vec![("Message", "Message")];
+
+        // When: Computing suggestions.
+        let got = suggest("Foo", candidates);
+
+        // Then: No suggestions are returned.
+        assert!(got.is_empty());
+    }
+}
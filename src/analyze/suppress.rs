@@ -0,0 +1,394 @@
+use crate::analyze::{Analyzer, Code, Diagnostic, Lint, Severity, suggest};
+use crate::ast;
+use crate::lex::Span;
+use crate::visit::{Visitor, walk};
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: Suppression                            */
+/* -------------------------------------------------------------------------- */
+
+/// `Suppression` silences warnings selected by any of its [`Lint`]s within the
+/// span of a single declaration.
+#[derive(Clone, Debug)]
+pub struct Suppression {
+    pub lints: Vec<Lint>,
+    pub span: Span,
+}
+
+/* ---------------------------- Impl: Suppression --------------------------- */
+
+impl Suppression {
+    /// `covers` returns whether the [`Suppression`] silences `diagnostic`.
+    /// Errors can never be suppressed.
+    pub fn covers(&self, diagnostic: &Diagnostic) -> bool {
+        diagnostic.severity == Severity::Warning
+            && diagnostic.span.context == self.span.context
+            && diagnostic.span.start >= self.span.start
+            && diagnostic.span.end <= self.span.end
+            && self.lints.iter().any(|lint| lint.matches(diagnostic.code))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Fn: suppress                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `suppress` removes all diagnostics silenced by any of the `suppressions`.
+pub fn suppress(diagnostics: Vec<Diagnostic>, suppressions: &[Suppression]) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .filter(|d| !suppressions.iter().any(|s| s.covers(d)))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                        Analyzer: SuppressionCollector                      */
+/* -------------------------------------------------------------------------- */
+
+/// `SuppressionCollector` gathers inline suppressions from comments of the
+/// form `// baproto: allow(<lint>, ...)`. A suppression applies to the
+/// declaration the comment documents or trails or, for a freestanding comment,
+/// to the top-level item which immediately follows it.
+///
+/// NOTE: The collected suppressions can be extracted via
+/// [`SuppressionCollector::drain_suppressions`]; malformed suppression comments
+/// are reported as diagnostics.
+#[derive(Default)]
+pub struct SuppressionCollector {
+    diagnostics: Vec<Diagnostic>,
+    suppressions: Vec<Suppression>,
+}
+
+/* ------------------------ Impl: SuppressionCollector ---------------------- */
+
+impl SuppressionCollector {
+    /// `drain_suppressions` drains and returns all collected suppressions.
+    pub fn drain_suppressions(&mut self) -> Vec<Suppression> {
+        std::mem::take(&mut self.suppressions)
+    }
+
+    /// `collect` records the suppressions in `comments`, applying them to the
+    /// declaration spanning `span`.
+    fn collect<'c, T>(&mut self, comments: T, span: &Span)
+    where
+        T: IntoIterator<Item = &'c ast::Comment>,
+    {
+        for comment in comments {
+            let Some(directive) = comment.content.trim().strip_prefix(DIRECTIVE_PREFIX) else {
+                continue;
+            };
+
+            match self.parse_directive(directive.trim(), &comment.span) {
+                Some(lints) if !lints.is_empty() => self.suppressions.push(Suppression {
+                    lints,
+                    span: span.clone(),
+                }),
+                _ => {}
+            }
+        }
+    }
+
+    /// `parse_directive` parses the lints named by a `baproto:` directive,
+    /// reporting any which are malformed or unknown.
+    fn parse_directive(&mut self, directive: &str, span: &Span) -> Option<Vec<Lint>> {
+        let Some(names) = directive
+            .strip_prefix("allow(")
+            .and_then(|s| s.strip_suffix(')'))
+        else {
+            self.diagnostics.push(
+                Diagnostic::warning(span.clone(), "malformed suppression comment")
+                    .with_code(Code::InvalidSuppression)
+                    .with_help("expected 'baproto: allow(<lint>, ...)'"),
+            );
+
+            return None;
+        };
+
+        let mut lints = Vec::new();
+
+        for name in names.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            match name.parse::<Lint>() {
+                Ok(lint) => lints.push(lint),
+                Err(_) => {
+                    let mut diagnostic = Diagnostic::warning(
+                        span.clone(),
+                        format!("unknown lint in suppression comment: {}", name),
+                    )
+                    .with_code(Code::InvalidSuppression);
+
+                    let candidates = Code::ALL
+                        .iter()
+                        .filter(|code| code.category().is_some())
+                        .map(|code| (code.name(), code.name()));
+
+                    if let Some(name) = suggest(name, candidates).first() {
+                        diagnostic = diagnostic.with_help(format!("did you mean '{}'?", name));
+                    }
+
+                    self.diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        Some(lints)
+    }
+}
+
+/* ----------------------------- Impl: Analyzer ----------------------------- */
+
+impl Analyzer for SuppressionCollector {
+    fn drain_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+/* ------------------------------ Impl: Visitor ----------------------------- */
+
+impl<'ast> Visitor<'ast> for SuppressionCollector {
+    fn visit_schema(&mut self, schema: &'ast ast::Schema) {
+        for (item, next) in schema.items.iter().zip(schema.items.iter().skip(1)) {
+            let ast::SchemaItem::CommentBlock(comment) = item else {
+                continue;
+            };
+
+            let span = match next {
+                ast::SchemaItem::CommentBlock(_) => continue,
+                ast::SchemaItem::Enum(enum_) => &enum_.span,
+                ast::SchemaItem::Include(include) => &include.span,
+                ast::SchemaItem::Message(msg) => &msg.span,
+                ast::SchemaItem::Package(pkg) => &pkg.span,
+            };
+
+            self.collect(&comment.comments, span);
+        }
+
+        for item in &schema.items {
+            if let ast::SchemaItem::Include(include) = item {
+                self.collect(&include.inline_comment, &include.span);
+            }
+        }
+
+        walk::walk_schema(self, schema);
+    }
+
+    fn visit_message(&mut self, msg: &'ast ast::Message) {
+        if let Some(comment) = &msg.comment {
+            self.collect(&comment.comments, &msg.span);
+        }
+
+        walk::walk_message(self, msg);
+    }
+
+    fn visit_enum(&mut self, enum_: &'ast ast::Enum) {
+        if let Some(comment) = &enum_.comment {
+            self.collect(&comment.comments, &enum_.span);
+        }
+
+        walk::walk_enum(self, enum_);
+    }
+
+    fn visit_field(&mut self, field: &'ast ast::Field) {
+        let comments = field.comment.iter().flat_map(|c| &c.comments);
+        self.collect(comments.chain(&field.inline_comment), &field.span);
+
+        walk::walk_field(self, field);
+    }
+
+    fn visit_unit_variant(&mut self, variant: &'ast ast::UnitVariant) {
+        let comments = variant.comment.iter().flat_map(|c| &c.comments);
+        self.collect(comments.chain(&variant.inline_comment), &variant.span);
+
+        walk::walk_unit_variant(self, variant);
+    }
+}
+
+/* ------------------------- Const: DIRECTIVE_PREFIX ------------------------ */
+
+/// `DIRECTIVE_PREFIX` marks a comment as a compiler directive rather than
+/// documentation.
+pub const DIRECTIVE_PREFIX: &str = "baproto:";
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use chumsky::span::Span as SpanTrait;
+
+    use super::*;
+
+    /* ----------------------- Tests: SuppressionCollector ------------------ */
+
+    #[test]
+    fn test_suppression_applies_to_documented_declaration() {
+        // Given: A message whose doc comment allows two lints.
+        let msg = make_message(Some("baproto: allow(naming-convention, W0005)"), 10..40);
+
+        // When: Collecting suppressions.
+        let (suppressions, diagnostics) = collect(&msg);
+
+        // Then: A single suppression covers the message.
+        assert!(diagnostics.is_empty());
+        assert_eq!(suppressions.len(), 1);
+        assert_eq!(
+            suppressions[0].lints,
+            vec![
+                Lint::Code(Code::NamingConvention),
+                Lint::Code(Code::UnusedType)
+            ]
+        );
+        assert_eq!(
+            (suppressions[0].span.start, suppressions[0].span.end),
+            (10, 40)
+        );
+    }
+
+    #[test]
+    fn test_suppression_ignores_plain_comments() {
+        // Given: A message with an ordinary doc comment.
+        let msg = make_message(Some("allow(naming-convention)"), 10..40);
+
+        // When: Collecting suppressions.
+        let (suppressions, diagnostics) = collect(&msg);
+
+        // Then: Nothing is collected or reported.
+        assert!(suppressions.is_empty());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_suppression_reports_malformed_directive() {
+        // Given: A message with a directive that isn't an `allow`.
+        let msg = make_message(Some("baproto: deny(naming-convention)"), 10..40);
+
+        // When: Collecting suppressions.
+        let (suppressions, diagnostics) = collect(&msg);
+
+        // Then: The directive is reported as malformed.
+        assert!(suppressions.is_empty());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "malformed suppression comment");
+        assert_eq!(diagnostics[0].code, Some(Code::InvalidSuppression));
+    }
+
+    #[test]
+    fn test_suppression_reports_unknown_lint_with_suggestion() {
+        // Given: A message with a directive naming an unknown lint.
+        let msg = make_message(Some("baproto: allow(unused-includes, unused)"), 10..40);
+
+        // When: Collecting suppressions.
+        let (suppressions, diagnostics) = collect(&msg);
+
+        // Then: The known lint is collected and the unknown one is reported.
+        assert_eq!(suppressions.len(), 1);
+        assert_eq!(suppressions[0].lints, vec![Lint::Category("unused")]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "unknown lint in suppression comment: unused-includes"
+        );
+        assert_eq!(diagnostics[0].help, vec!["did you mean 'unused-include'?"]);
+    }
+
+    #[test]
+    fn test_suppression_applies_to_trailed_field() {
+        // Given: A field followed by a same-line suppression comment.
+        let field = ast::Field {
+            annotations: vec![],
+            comment: None,
+            encoding: None,
+            index: None,
+            inline_comment: Some(ast::Comment {
+                content: "baproto: allow(naming)".to_owned(),
+                span: make_span(20..40),
+            }),
+            kind: ast::Type::Scalar(ast::Scalar {
+                kind: ast::ScalarType::Uint32,
+                span: make_span(0..3),
+            }),
+            name: ast::Ident {
+                name: "playerId".to_owned(),
+                span: make_span(4..12),
+            },
+            span: make_span(0..13),
+        };
+
+        // When: Collecting suppressions.
+        let mut collector = SuppressionCollector::default();
+        collector.visit_field(&field);
+        let suppressions = collector.drain_suppressions();
+
+        // Then: The suppression covers the field.
+        assert!(collector.drain_diagnostics().is_empty());
+        assert_eq!(suppressions.len(), 1);
+        assert_eq!(suppressions[0].lints, vec![Lint::Category("naming")]);
+        assert_eq!(
+            (suppressions[0].span.start, suppressions[0].span.end),
+            (0, 13)
+        );
+    }
+
+    /* --------------------------- Tests: covers --------------------------- */
+
+    #[test]
+    fn test_suppression_covers_matching_warnings_within_span() {
+        // Given: A suppression of unused lints over a declaration.
+        let suppression = Suppression {
+            lints: vec![Lint::Category("unused")],
+            span: make_span(10..40),
+        };
+
+        // When: Checking several diagnostics.
+        let got = [
+            Diagnostic::warning(make_span(15..20), "inside").with_code(Code::UnusedType),
+            Diagnostic::warning(make_span(35..45), "overlapping").with_code(Code::UnusedType),
+            Diagnostic::warning(make_span(15..20), "other").with_code(Code::NamingConvention),
+            Diagnostic::error(make_span(15..20), "error").with_code(Code::DuplicateIndex),
+        ]
+        .iter()
+        .map(|d| suppression.covers(d))
+        .collect::<Vec<_>>();
+
+        // Then: Only the matching warning within the span is covered.
+        assert_eq!(got, vec![true, false, false, false]);
+    }
+
+    /* ---------------------------- Fn: collect ----------------------------- */
+
+    fn collect(msg: &ast::Message) -> (Vec<Suppression>, Vec<Diagnostic>) {
+        let mut collector = SuppressionCollector::default();
+        collector.visit_message(msg);
+        (
+            collector.drain_suppressions(),
+            collector.drain_diagnostics(),
+        )
+    }
+
+    /* -------------------------- Fn: make_message -------------------------- */
+
+    fn make_message(comment: Option<&str>, range: std::ops::Range<usize>) -> ast::Message {
+        ast::Message {
+            annotations: vec![],
+            comment: comment.map(|content| ast::CommentBlock {
+                comments: vec![ast::Comment {
+                    content: content.to_owned(),
+                    span: make_span(0..content.len()),
+                }],
+                span: make_span(0..content.len()),
+            }),
+            items: vec![],
+            name: ast::Ident {
+                name: "Message".to_owned(),
+                span: make_span(range.clone()),
+            },
+            span: make_span(range),
+        }
+    }
+
+    /* --------------------------- Fn: make_span ---------------------------- */
+
+    fn make_span(range: std::ops::Range<usize>) -> Span {
+        SpanTrait::new(Default::default(), range)
+    }
+}
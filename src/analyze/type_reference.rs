@@ -1,9 +1,10 @@
 use thiserror::Error;
 
-use crate::analyze::{Analyzer, Diagnostic};
+use crate::analyze::{Analyzer, Code, Diagnostic, suggest};
 use crate::ast;
 use crate::compile::Symbols;
 use crate::core::Descriptor;
+use crate::core::PackageName;
 use crate::ir::lower::TypeKind;
 use crate::visit::{Visitor, walk};
 
@@ -16,14 +17,15 @@ pub struct TypeReferenceResolver<'a> {
     symbols: &'a Symbols<TypeKind>,
     scope: Descriptor,
     diagnostics: Vec<Diagnostic>,
+    imports: Vec<PackageName>,
 }
 
 /* ------------------------------- Enum: Error ------------------------------ */
 
 #[derive(Debug, Error)]
 pub enum ReferenceError {
-    #[error("could not resolve reference: {0}")]
-    Unresolved(ast::Reference),
+    #[error("could not resolve reference: {0}{hint}", hint = format_suggestions(.1))]
+    Unresolved(ast::Reference, Vec<String>),
     #[error("invalid reference type: {0} is {1:?}, expected one of {2:?}")]
     InvalidType(ast::Reference, TypeKind, Vec<TypeKind>),
 }
@@ -36,11 +38,19 @@ impl<'a> TypeReferenceResolver<'a> {
     pub fn new(symbols: &'a Symbols<TypeKind>, scope: Descriptor) -> Self {
         Self {
             diagnostics: Vec::new(),
+            imports: Vec::new(),
             scope,
             symbols,
         }
     }
 
+    /// `with_imports` sets the packages imported by the schema being analyzed,
+    /// whose types are suggested for unresolvable relative references.
+    pub fn with_imports(mut self, imports: Vec<PackageName>) -> Self {
+        self.imports = imports;
+        self
+    }
+
     /// `resolve` attempts to resolve the provided type reference.
     ///
     /// Validates that:
@@ -65,9 +75,67 @@ impl<'a> TypeReferenceResolver<'a> {
 
                 Ok((descriptor, kind))
             }
-            None => Err(ReferenceError::Unresolved(reference.clone())),
+            None => Err(ReferenceError::Unresolved(
+                reference.clone(),
+                self.suggest(reference),
+            )),
         }
     }
+
+    /// `suggest` returns the known type [`Descriptor`]s which most closely
+    /// match the unresolvable `reference`.
+    ///
+    /// Relative references are compared against the paths of types within the
+    /// current and imported packages, while absolute references are compared
+    /// against fully qualified type names. Types within imported packages are
+    /// suggested as absolute references, since only those can refer to them.
+    fn suggest(&self, reference: &ast::Reference) -> Vec<String> {
+        let target = reference
+            .components
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let candidates = self
+            .symbols
+            .iter()
+            .filter(|(_, kind)| matches!(kind, TypeKind::Message | TypeKind::Enum))
+            .filter_map(|(descriptor, _)| {
+                if reference.is_absolute {
+                    return Some((descriptor.to_string(), descriptor.to_string()));
+                }
+
+                if descriptor.package == self.scope.package {
+                    return Some((descriptor.path.join("."), descriptor.to_string()));
+                }
+
+                self.imports
+                    .contains(&descriptor.package)
+                    .then(|| (descriptor.path.join("."), format!(".{}", descriptor)))
+            });
+
+        suggest(&target, candidates)
+    }
+}
+
+/* ------------------------ Fn: format_suggestions -------------------------- */
+
+/// `format_suggestions` formats a "did you mean" hint for the provided type
+/// names, returning an empty string if there are none.
+fn format_suggestions(suggestions: &[String]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [name] => format!("; did you mean '{}'?", name),
+        names => format!(
+            "; did you mean one of {}?",
+            names
+                .iter()
+                .map(|n| format!("'{}'", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
 }
 
 /* ------------------------- Impl: Analyzer --------------------------------- */
@@ -105,8 +173,13 @@ impl<'ast> Visitor<'ast> for TypeReferenceResolver<'_> {
 
     fn visit_reference(&mut self, reference: &'ast ast::Reference) {
         if let Err(err) = self.resolve(reference) {
+            let code = match err {
+                ReferenceError::Unresolved(..) => Code::UnresolvedReference,
+                ReferenceError::InvalidType(..) => Code::InvalidReferenceKind,
+            };
+
             self.diagnostics
-                .push(Diagnostic::error(reference.span.clone(), err.to_string()));
+                .push(Diagnostic::error(reference.span.clone(), err.to_string()).with_code(code));
         }
     }
 }
@@ -142,7 +215,7 @@ mod tests {
         // Then: Should return Unresolved error.
         assert!(result.is_err());
         match result.unwrap_err() {
-            ReferenceError::Unresolved(_) => {}
+            ReferenceError::Unresolved(_, _) => {}
             _ => panic!("Expected Unresolved error"),
         }
     }
@@ -296,6 +369,69 @@ mod tests {
         assert_eq!(kind, TypeKind::Message);
     }
 
+    #[test]
+    fn test_resolve_unresolved_reference_suggests_similar_types() {
+        // Given: Symbols with a type whose name is similar to the reference.
+        let mut symbols = Symbols::<TypeKind>::default();
+        let message_desc = DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .path(vec!["User".to_string()])
+            .build()
+            .unwrap();
+        symbols.insert(message_desc, TypeKind::Message);
+
+        let scope = DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .build()
+            .unwrap();
+
+        let resolver = TypeReferenceResolver::new(&symbols, scope);
+        let reference = make_test_reference(vec!["Usr"], false);
+
+        // When: Resolve the misspelled reference.
+        let err = resolver.resolve(&reference).unwrap_err();
+
+        // Then: The similar type is suggested.
+        assert_eq!(
+            err.to_string(),
+            "could not resolve reference: Usr; did you mean 'com.example.User'?"
+        );
+    }
+
+    #[test]
+    fn test_resolve_unresolved_reference_suggests_imported_types() {
+        // Given: Symbols with a type in an imported package and another in a
+        // package which isn't imported.
+        let mut symbols = Symbols::<TypeKind>::default();
+        for package in [vec!["com", "imported"], vec!["com", "other"]] {
+            let descriptor = DescriptorBuilder::default()
+                .package(PackageName::try_from(package).unwrap())
+                .path(vec!["User".to_string()])
+                .build()
+                .unwrap();
+            symbols.insert(descriptor, TypeKind::Message);
+        }
+
+        let scope = DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .build()
+            .unwrap();
+
+        let resolver = TypeReferenceResolver::new(&symbols, scope).with_imports(vec![
+            PackageName::try_from(vec!["com", "imported"]).unwrap(),
+        ]);
+        let reference = make_test_reference(vec!["Usr"], false);
+
+        // When: Resolve the misspelled reference.
+        let err = resolver.resolve(&reference).unwrap_err();
+
+        // Then: Only the imported type is suggested, as an absolute reference.
+        assert_eq!(
+            err.to_string(),
+            "could not resolve reference: Usr; did you mean '.com.imported.User'?"
+        );
+    }
+
     /* --------------------------- Tests: visitor --------------------------- */
 
     #[test]
@@ -310,6 +446,7 @@ mod tests {
         let mut resolver = TypeReferenceResolver::new(&symbols, scope.clone());
 
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             items: vec![],
             name: make_test_ident("TestMessage"),
@@ -335,6 +472,7 @@ mod tests {
         let mut resolver = TypeReferenceResolver::new(&symbols, scope.clone());
 
         let enum_ = ast::Enum {
+            annotations: vec![],
             comment: None,
             items: vec![],
             name: make_test_ident("TestEnum"),
@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use crate::analyze::{Analyzer, Code, Diagnostic};
+use crate::ast;
+use crate::compile::{Declaration, IncludeGraph, Symbols};
+use crate::core::{Descriptor, SchemaImport};
+use crate::visit::{Visitor, walk};
+
+/* -------------------------------------------------------------------------- */
+/*                           Analyzer: UnusedInclude                          */
+/* -------------------------------------------------------------------------- */
+
+/// `UnusedInclude` warns about `include` statements which don't supply any of
+/// the types referenced by a schema. An include is considered used if the
+/// included schema, or any schema it transitively includes, declares a type
+/// which is referenced.
+///
+/// NOTE: The set of referenced type [`Descriptor`]s is retained and can be
+/// extracted via [`UnusedInclude::drain_references`] for whole-program
+/// analysis of unused types.
+pub struct UnusedInclude<'a> {
+    declarations: &'a Symbols<Declaration>,
+    diagnostics: Vec<Diagnostic>,
+    includes: &'a IncludeGraph,
+    references: HashSet<Descriptor>,
+    scope: Descriptor,
+}
+
+/* --------------------------- Impl: UnusedInclude -------------------------- */
+
+impl<'a> UnusedInclude<'a> {
+    /// `new` creates a new [`UnusedInclude`] analyzer for the given package
+    /// scope.
+    pub fn new(
+        declarations: &'a Symbols<Declaration>,
+        includes: &'a IncludeGraph,
+        scope: Descriptor,
+    ) -> Self {
+        Self {
+            declarations,
+            diagnostics: Vec::new(),
+            includes,
+            references: HashSet::new(),
+            scope,
+        }
+    }
+
+    /// `drain_references` drains and returns the [`Descriptor`]s of all types
+    /// referenced by the analyzed schema.
+    pub fn drain_references(&mut self) -> HashSet<Descriptor> {
+        std::mem::take(&mut self.references)
+    }
+
+    /// `check_includes` reports each `include` of `file` which doesn't supply
+    /// any referenced types.
+    fn check_includes(&mut self, file: &SchemaImport) {
+        let used = self
+            .references
+            .iter()
+            .filter_map(|d| self.declarations.get(d))
+            .map(|d| d.span.context)
+            .collect::<HashSet<_>>();
+
+        for (include, target) in self.includes.includes(file) {
+            if self.includes.closure(target).is_disjoint(&used) {
+                self.diagnostics.push(
+                    Diagnostic::warning(
+                        include.span.clone(),
+                        format!("unused include: \"{}\"", include.path.display()),
+                    )
+                    .with_code(Code::UnusedInclude),
+                );
+            }
+        }
+    }
+}
+
+/* ----------------------------- Impl: Analyzer ----------------------------- */
+
+impl Analyzer for UnusedInclude<'_> {
+    fn drain_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+/* ------------------------------ Impl: Visitor ----------------------------- */
+
+impl<'ast> Visitor<'ast> for UnusedInclude<'_> {
+    fn visit_schema(&mut self, schema: &'ast ast::Schema) {
+        walk::walk_schema(self, schema);
+        self.check_includes(&schema.span.context);
+    }
+
+    fn visit_message(&mut self, msg: &'ast ast::Message) {
+        self.scope.push(msg.name.name.clone());
+        walk::walk_message(self, msg);
+        self.scope.pop();
+    }
+
+    fn visit_enum(&mut self, enum_: &'ast ast::Enum) {
+        self.scope.push(enum_.name.name.clone());
+        walk::walk_enum(self, enum_);
+        self.scope.pop();
+    }
+
+    fn visit_reference(&mut self, reference: &'ast ast::Reference) {
+        if let Some((descriptor, _)) = self.declarations.resolve(&self.scope, reference) {
+            self.references.insert(descriptor);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Fn: unused_types                              */
+/* -------------------------------------------------------------------------- */
+
+/// `unused_types` reports every declared type which is neither referenced nor
+/// marked as a `@root` type. Diagnostics are ordered by source location.
+pub fn unused_types(
+    declarations: &Symbols<Declaration>,
+    references: &HashSet<Descriptor>,
+) -> Vec<Diagnostic> {
+    let mut unused = declarations
+        .iter()
+        .filter(|(descriptor, declaration)| {
+            !declaration.is_root() && !references.contains(*descriptor)
+        })
+        .collect::<Vec<_>>();
+
+    unused.sort_by(|(_, a), (_, b)| {
+        (a.span.context.to_string(), a.span.start).cmp(&(b.span.context.to_string(), b.span.start))
+    });
+
+    unused
+        .into_iter()
+        .map(|(descriptor, declaration)| {
+            Diagnostic::warning(
+                declaration.span.clone(),
+                format!("type '{}' is never referenced", descriptor),
+            )
+            .with_code(Code::UnusedType)
+            .with_help("mark it '@root' if it's an entry point")
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DescriptorBuilder, PackageName};
+    use crate::lex::Span;
+
+    /* ------------------------- Tests: unused_types ------------------------ */
+
+    #[test]
+    fn test_unused_types_reports_unreferenced_type() {
+        // Given: A single unreferenced, non-root type.
+        let declarations = make_declarations(vec![]);
+
+        // When: Checking for unused types.
+        let diagnostics = unused_types(&declarations, &HashSet::new());
+
+        // Then: A warning is reported.
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "type 'com.example.Orphan' is never referenced"
+        );
+    }
+
+    #[test]
+    fn test_unused_types_ignores_referenced_type() {
+        // Given: A single referenced type.
+        let declarations = make_declarations(vec![]);
+        let references = HashSet::from([make_descriptor()]);
+
+        // When: Checking for unused types.
+        let diagnostics = unused_types(&declarations, &references);
+
+        // Then: No warnings are reported.
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_unused_types_ignores_root_type() {
+        // Given: A single unreferenced type marked as a root.
+        let declarations = make_declarations(vec![ast::Annotation {
+            kind: ast::AnnotationKind::Root,
+            span: Span::default(),
+        }]);
+
+        // When: Checking for unused types.
+        let diagnostics = unused_types(&declarations, &HashSet::new());
+
+        // Then: No warnings are reported.
+        assert!(diagnostics.is_empty());
+    }
+
+    /* ------------------------ Fn: make_declarations ----------------------- */
+
+    fn make_declarations(annotations: Vec<ast::Annotation>) -> Symbols<Declaration> {
+        let mut declarations = Symbols::default();
+
+        declarations.insert(
+            make_descriptor(),
+            Declaration {
+                annotations,
+                span: Span::default(),
+            },
+        );
+
+        declarations
+    }
+
+    /* ------------------------- Fn: make_descriptor ------------------------ */
+
+    fn make_descriptor() -> Descriptor {
+        DescriptorBuilder::default()
+            .package(PackageName::try_from(vec!["com", "example"]).unwrap())
+            .path(vec!["Orphan".to_string()])
+            .build()
+            .unwrap()
+    }
+}
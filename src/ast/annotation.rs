@@ -0,0 +1,60 @@
+use derive_more::Display;
+
+use crate::ast;
+use crate::lex::Span;
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: Annotation                             */
+/* -------------------------------------------------------------------------- */
+
+/// `Annotation` represents a single `@`-prefixed annotation attached to a
+/// declaration (e.g. `@deprecated("use pos_v2")`).
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("@{}", kind)]
+pub struct Annotation {
+    pub kind: AnnotationKind,
+    pub span: Span,
+}
+
+/* -------------------------- Enum: AnnotationKind -------------------------- */
+
+/// `AnnotationKind` enumerates the supported annotation types.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub enum AnnotationKind {
+    /// `Deprecated` marks a declaration as deprecated, with an optional note
+    /// describing what to use instead.
+    #[display("deprecated{}", _0.as_ref().map(|t| format!("(\"{}\")", t)).unwrap_or_default())]
+    Deprecated(Option<ast::Text>),
+    /// `Root` marks a type as an entry point which is expected to be used even
+    /// if nothing within the schema tree references it.
+    #[display("root")]
+    Root,
+}
+
+/* --------------------------- Impl: Annotation ---------------------------- */
+
+impl Annotation {
+    /// `find_deprecated` returns the first `@deprecated` annotation within the
+    /// provided set of annotations, if any.
+    pub fn find_deprecated(annotations: &[Annotation]) -> Option<&Annotation> {
+        annotations
+            .iter()
+            .find(|a| matches!(a.kind, AnnotationKind::Deprecated(_)))
+    }
+
+    /// `is_root` returns whether the provided set of annotations contains a
+    /// `@root` annotation.
+    pub fn is_root(annotations: &[Annotation]) -> bool {
+        annotations
+            .iter()
+            .any(|a| matches!(a.kind, AnnotationKind::Root))
+    }
+
+    /// `note` returns the annotation's note argument, if one was provided.
+    pub fn note(&self) -> Option<&str> {
+        match &self.kind {
+            AnnotationKind::Deprecated(note) => note.as_ref().map(|t| t.content.as_str()),
+            AnnotationKind::Root => None,
+        }
+    }
+}
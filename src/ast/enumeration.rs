@@ -11,6 +11,7 @@ use crate::lex::Span;
 #[derive(Clone, Debug, Display, Eq, PartialEq)]
 #[display("enum {}", name)]
 pub struct Enum {
+    pub annotations: Vec<ast::Annotation>,
     pub comment: Option<ast::CommentBlock>,
     pub items: Vec<EnumItem>,
     pub name: ast::Ident,
@@ -25,6 +26,7 @@ pub struct Enum {
 pub enum EnumItem {
     CommentBlock(ast::CommentBlock),
     FieldVariant(ast::Field),
+    Reserved(ast::Reserved),
     UnitVariant(UnitVariant),
 }
 
@@ -36,8 +38,10 @@ pub enum EnumItem {
 /// identifier.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UnitVariant {
+    pub annotations: Vec<ast::Annotation>,
     pub comment: Option<ast::CommentBlock>,
     pub index: Option<ast::FieldIndex>,
+    pub inline_comment: Option<ast::Comment>,
     pub name: ast::Ident,
     pub span: Span,
 }
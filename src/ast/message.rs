@@ -11,6 +11,7 @@ use crate::lex::Span;
 #[derive(Clone, Debug, Display, Eq, PartialEq)]
 #[display("message {}", name)]
 pub struct Message {
+    pub annotations: Vec<ast::Annotation>,
     pub comment: Option<ast::CommentBlock>,
     pub items: Vec<MessageItem>,
     pub name: ast::Ident,
@@ -27,6 +28,7 @@ pub enum MessageItem {
     Enum(ast::Enum),
     Field(Field),
     Message(Message),
+    Reserved(ast::Reserved),
 }
 
 /* -------------------------------------------------------------------------- */
@@ -36,9 +38,11 @@ pub enum MessageItem {
 /// `Field` represents a field within a [`ast::Message`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Field {
+    pub annotations: Vec<ast::Annotation>,
     pub comment: Option<ast::CommentBlock>,
     pub encoding: Option<ast::Encoding>,
     pub index: Option<FieldIndex>,
+    pub inline_comment: Option<ast::Comment>,
     pub kind: ast::Type,
     pub name: ast::Ident,
     pub span: Span,
@@ -2,6 +2,11 @@ use derive_more::Display;
 
 use crate::lex::Span;
 
+/* ----------------------------- Mod: Annotation ---------------------------- */
+
+mod annotation;
+pub use annotation::*;
+
 /* ------------------------------ Mod: Comment ------------------------------ */
 
 mod comment;
@@ -27,6 +32,11 @@ pub use message::*;
 mod package;
 pub use package::*;
 
+/* ------------------------------ Mod: Reserved ----------------------------- */
+
+mod reserved;
+pub use reserved::*;
+
 /* ------------------------------- Mod: Schema ------------------------------ */
 
 mod schema;
@@ -15,6 +15,7 @@ use crate::lex::Span;
 pub struct Package {
     pub comment: Option<super::CommentBlock>,
     pub components: Vec<super::Ident>,
+    pub inline_comment: Option<super::Comment>,
     pub span: Span,
 }
 
@@ -42,6 +43,7 @@ impl TryFrom<Package> for PackageName {
 #[derive(Clone, Debug, Display, Eq, PartialEq)]
 #[display("{}", path.display())]
 pub struct Include {
+    pub inline_comment: Option<super::Comment>,
     pub path: PathBuf,
     pub span: Span,
 }
@@ -0,0 +1,85 @@
+use derive_more::Display;
+use itertools::Itertools;
+
+use crate::ast;
+use crate::lex::Span;
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Reserved                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Reserved` represents a `reserved` statement within a [`ast::Message`] or
+/// [`ast::Enum`], which prevents field indices and names from being reused.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("reserved {}", items.iter().join(", "))]
+pub struct Reserved {
+    pub comment: Option<ast::CommentBlock>,
+    pub inline_comment: Option<ast::Comment>,
+    pub items: Vec<ReservedItem>,
+    pub span: Span,
+}
+
+/* --------------------------- Enum: ReservedItem --------------------------- */
+
+/// `ReservedItem` represents a single entry within a [`Reserved`] statement.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+pub enum ReservedItem {
+    #[display("{_0}")]
+    Index(ast::Uint),
+    #[display("\"{_0}\"")]
+    Name(ast::Text),
+    #[display("{_0}")]
+    Range(ReservedRange),
+}
+
+/* ------------------------ Impl: ReservedItem ------------------------------ */
+
+impl ReservedItem {
+    /// `contains_index` returns whether the provided index is reserved by this
+    /// item.
+    pub fn contains_index(&self, index: u64) -> bool {
+        match self {
+            ReservedItem::Index(value) => value.value == index,
+            ReservedItem::Name(_) => false,
+            ReservedItem::Range(range) => range.contains(index),
+        }
+    }
+
+    /// `contains_name` returns whether the provided name is reserved by this
+    /// item.
+    pub fn contains_name(&self, name: &str) -> bool {
+        matches!(self, ReservedItem::Name(text) if text.content == name)
+    }
+
+    /// `span` returns the source location of this item.
+    pub fn span(&self) -> &Span {
+        match self {
+            ReservedItem::Index(value) => &value.span,
+            ReservedItem::Name(text) => &text.span,
+            ReservedItem::Range(range) => &range.span,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: ReservedRange                           */
+/* -------------------------------------------------------------------------- */
+
+/// `ReservedRange` represents an inclusive range of reserved indices (e.g.
+/// `5 to 8`).
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("{} to {}", start, end)]
+pub struct ReservedRange {
+    pub end: ast::Uint,
+    pub span: Span,
+    pub start: ast::Uint,
+}
+
+/* --------------------------- Impl: ReservedRange -------------------------- */
+
+impl ReservedRange {
+    /// `contains` returns whether the provided index falls within the range.
+    pub fn contains(&self, index: u64) -> bool {
+        (self.start.value..=self.end.value).contains(&index)
+    }
+}
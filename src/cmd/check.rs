@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use crate::compile::CompileOptions;
+use crate::compile::check;
+
+use super::compile::OptionsArgs;
+
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Args                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// A root directory to search for imported '.baproto' files. Can be
+    /// specified multiple times. Imports are resolved by searching each root in
+    /// order. If not specified, defaults to the current working directory.
+    #[arg(short = 'I', long = "import_root", value_name = "DIR")]
+    pub import_roots: Vec<PathBuf>,
+
+    #[command(flatten)]
+    pub options: OptionsArgs,
+
+    /// A path to a message definition file to check, or '-' to read it from
+    /// stdin.
+    #[arg(value_name = "FILES", required = true, num_args = 1..)]
+    pub files: Vec<PathBuf>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Function: handle                              */
+/* -------------------------------------------------------------------------- */
+
+/// [`handle`] implements the `check` command.
+#[allow(unused)]
+pub fn handle(args: Args) -> anyhow::Result<()> {
+    check(
+        args.files,
+        args.import_roots,
+        CompileOptions::from(args.options),
+    )
+}
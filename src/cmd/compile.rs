@@ -1,9 +1,24 @@
 use anyhow::anyhow;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use crate::compile::compile;
+use crate::analyze::Case;
+use crate::analyze::DiagnosticsFormat;
+use crate::analyze::Lint;
+use crate::compile::CompileOptions;
+use crate::compile::GeneratorTarget;
+use crate::compile::TargetGroup;
+use crate::compile::compile_groups;
+use crate::compile::verify_groups;
+use crate::compile::watch;
+use crate::config::NamingOptions;
+use crate::config::PROJECT_FILE_NAME;
+use crate::config::Project;
+use crate::config::ProjectOptions;
 use crate::generate::ExternalGenerator;
+use crate::generate::PluginSearchPath;
 use crate::generate::RustGenerator;
+use crate::generate::plugin_binary_name;
 
 /* -------------------------------------------------------------------------- */
 /*                                Struct: Args                                */
@@ -11,11 +26,19 @@ use crate::generate::RustGenerator;
 
 #[derive(clap::Args, Debug)]
 pub struct Args {
+    /// A path to a project file declaring the inputs, import roots and
+    /// generator targets to build. If not specified, a 'baproto.toml' file in
+    /// the current directory is used, if present. Command-line arguments take
+    /// precedence over the project file.
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
     #[command(flatten)]
     pub generator: GeneratorSelection,
 
-    /// A path to a directory in which to generate language bindings in.
-    #[arg(short, long, value_name = "OUT_DIR")]
+    /// A path to a directory in which to generate language bindings in; it's
+    /// created if missing. Requires a generator to be selected.
+    #[arg(short, long, value_name = "OUT_DIR", requires = "GeneratorSelection")]
     pub out: Option<PathBuf>,
 
     /// A root directory to search for imported '.baproto' files. Can be
@@ -24,23 +47,271 @@ pub struct Args {
     #[arg(short = 'I', long = "import_root", value_name = "DIR")]
     pub import_roots: Vec<PathBuf>,
 
-    /// A path to a message definition file to compile.
-    #[arg(value_name = "FILES", required = true, num_args = 1..)]
+    #[command(flatten)]
+    pub options: OptionsArgs,
+
+    /// Pass the option KEY=VALUE to all external generators. Can be specified
+    /// multiple times; takes precedence over options declared in the project
+    /// file.
+    #[arg(long = "plugin-opt", value_name = "KEY=VALUE")]
+    pub plugin_opts: Vec<PluginOptArg>,
+
+    /// A directory to search for plugins selected by name, taking precedence
+    /// over 'PATH'.
+    #[arg(long, value_name = "DIR")]
+    pub plugins_dir: Option<PathBuf>,
+
+    /// Write the bare schema to external generators instead of the versioned
+    /// request envelope. Provided for compatibility with older plugins, which
+    /// don't receive any options.
+    #[arg(long)]
+    pub legacy_plugin_protocol: bool,
+
+    /// Keep running and recompile whenever an input file or one of its
+    /// imports changes.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Check that the generated bindings are up to date without writing them.
+    /// A diff is printed for each file which differs from its generated
+    /// contents, and the command fails if any file is out of date.
+    #[arg(long, conflicts_with = "watch")]
+    pub check: bool,
+
+    /// Write a Make-format depfile to PATH, listing every generated file as
+    /// depending on every schema read during compilation. Intended for build
+    /// systems such as Make, Ninja and CMake. The modification time of every
+    /// generated file is updated, even if its contents are unchanged.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["watch", "check"])]
+    pub depfile: Option<PathBuf>,
+
+    /// A path to a message definition file to compile, or '-' to read it from
+    /// stdin. If not specified, the inputs declared in the project file are
+    /// compiled.
+    #[arg(value_name = "FILES")]
     pub files: Vec<PathBuf>,
 }
 
 /* ------------------------- Struct: GeneratorSelection ------------------------- */
 
+/// Generators may be combined, in which case the schemas are compiled once and
+/// all generators run in parallel. Each generator writes to its own OUT_DIR if
+/// specified, and to the directory given by '--out' otherwise.
 #[derive(clap::Args, Debug)]
-#[group(required = true, multiple = false)]
+#[group(required = false, multiple = true)]
 pub struct GeneratorSelection {
     /// Generate Rust language bindings.
+    #[arg(long, value_name = "OUT_DIR", num_args = 0..=1, require_equals = true)]
+    pub rust: Option<Option<PathBuf>>,
+
+    /// Use an external generator binary. Can be specified multiple times.
+    #[arg(long = "plugin", value_name = "BINARY[=OUT_DIR]")]
+    pub plugin: Vec<PluginArg>,
+
+    /// Use the external generator named NAME, i.e. the 'baproto-gen-NAME'
+    /// executable found in the plugins directory, on 'PATH' or next to the
+    /// 'baproto' binary. Can be specified multiple times.
+    #[arg(long = "gen", value_name = "NAME[=OUT_DIR]")]
+    pub named: Vec<NamedPluginArg>,
+}
+
+/* ----------------------------- Struct: PluginArg -------------------------- */
+
+/// `PluginArg` selects an external generator binary and, optionally, the
+/// directory its output is written to.
+#[derive(Clone, Debug)]
+pub struct PluginArg {
+    pub binary: PathBuf,
+    pub out: Option<PathBuf>,
+}
+
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for PluginArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (binary, out) = split_out_dir(s, "BINARY")?;
+
+        Ok(Self {
+            binary: PathBuf::from(binary),
+            out,
+        })
+    }
+}
+
+/* -------------------------- Struct: NamedPluginArg ------------------------ */
+
+/// `NamedPluginArg` selects an external generator by name and, optionally,
+/// the directory its output is written to.
+#[derive(Clone, Debug)]
+pub struct NamedPluginArg {
+    pub name: String,
+    pub out: Option<PathBuf>,
+}
+
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for NamedPluginArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, out) = split_out_dir(s, "NAME")?;
+
+        Ok(Self {
+            name: name.to_owned(),
+            out,
+        })
+    }
+}
+
+/* --------------------------- Fn: split_out_dir ---------------------------- */
+
+/// `split_out_dir` splits a generator argument of the form `VALUE[=OUT_DIR]`,
+/// where `value` describes the expected value in error messages.
+fn split_out_dir<'a>(s: &'a str, value: &str) -> Result<(&'a str, Option<PathBuf>), String> {
+    let (selected, out) = match s.split_once('=') {
+        Some((selected, out)) => (selected, Some(PathBuf::from(out))),
+        None => (s, None),
+    };
+
+    if selected.is_empty() || out.as_ref().is_some_and(|out| out.as_os_str().is_empty()) {
+        return Err(format!("expected '{}[=OUT_DIR]', found '{}'", value, s));
+    }
+
+    Ok((selected, out))
+}
+
+/* ---------------------------- Struct: PluginOptArg ------------------------ */
+
+/// `PluginOptArg` is an option passed through to external generators.
+#[derive(Clone, Debug)]
+pub struct PluginOptArg {
+    pub key: String,
+    pub value: String,
+}
+
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for PluginOptArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok(Self {
+                key: key.to_owned(),
+                value: value.to_owned(),
+            }),
+            _ => Err(format!("expected 'KEY=VALUE', found '{}'", s)),
+        }
+    }
+}
+
+/* --------------------------- Struct: OptionsArgs -------------------------- */
+
+/// `OptionsArgs` contains the arguments which configure [`CompileOptions`];
+/// they're shared by all commands which compile schemas.
+#[derive(clap::Args, Debug)]
+pub struct OptionsArgs {
+    /// The format in which to report diagnostics. Structured formats are
+    /// written to stdout.
+    #[arg(long, value_name = "FORMAT", value_enum, default_value_t = DiagnosticsFormat::Human)]
+    pub diagnostics_format: DiagnosticsFormat,
+
+    #[command(flatten)]
+    pub lints: LintArgs,
+
+    #[command(flatten)]
+    pub naming: NamingArgs,
+
+    /// Warn about messages and enums which are never referenced and aren't
+    /// marked with '@root'.
     #[arg(long)]
-    pub rust: bool,
+    pub warn_unused_types: bool,
+}
+
+/* --------------------------- Impl: OptionsArgs --------------------------- */
+
+impl OptionsArgs {
+    /// `apply` layers the options specified on the command line on top of
+    /// `options` (e.g. those declared in a project file).
+    pub fn apply(&self, options: &mut CompileOptions) {
+        options.diagnostics_format = self.diagnostics_format;
+
+        let lints = &self.lints;
+        let naming = &self.naming;
+
+        let overrides = ProjectOptions {
+            allow: lints.allow.clone(),
+            deny: lints.deny.clone(),
+            deny_warnings: lints.deny_warnings,
+            naming: NamingOptions {
+                field_case: naming.field_case,
+                type_case: naming.type_case,
+                variant_case: naming.variant_case,
+            },
+            warn: lints.warn.clone(),
+            warn_unused_types: self.warn_unused_types.then_some(true),
+        };
 
-    /// Use an external generator binary.
-    #[arg(long = "plugin", value_name = "BINARY")]
-    pub plugin: Option<PathBuf>,
+        overrides.apply(options);
+    }
+}
+
+/* ------------------------ Impl: From<OptionsArgs> ------------------------- */
+
+impl From<OptionsArgs> for CompileOptions {
+    fn from(args: OptionsArgs) -> Self {
+        let mut options = CompileOptions::default();
+        args.apply(&mut options);
+        options
+    }
+}
+
+/* ---------------------------- Struct: LintArgs ---------------------------- */
+
+/// Lints may be named by code (e.g. 'W0004'), by name (e.g. 'unused-include'),
+/// by category (e.g. 'unused') or as 'warnings' (i.e. all warnings). More
+/// specific lints take precedence; otherwise '-D' beats '-W', which beats '-A'.
+#[derive(clap::Args, Debug)]
+pub struct LintArgs {
+    /// Suppress warnings selected by LINT. Can be specified multiple times.
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    pub allow: Vec<Lint>,
+
+    /// Report warnings selected by LINT as errors. Can be specified multiple
+    /// times.
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    pub deny: Vec<Lint>,
+
+    /// Report all warnings as errors (equivalent to '-D warnings').
+    #[arg(long)]
+    pub deny_warnings: bool,
+
+    /// Report warnings selected by LINT as warnings. Can be specified multiple
+    /// times.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    pub warn: Vec<Lint>,
+}
+
+/* --------------------------- Struct: NamingArgs --------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct NamingArgs {
+    /// The naming convention required for message and enum names. Defaults to
+    /// 'pascal'.
+    #[arg(long, value_name = "CASE", value_enum)]
+    pub type_case: Option<Case>,
+
+    /// The naming convention required for message field names. Defaults to
+    /// 'snake'.
+    #[arg(long, value_name = "CASE", value_enum)]
+    pub field_case: Option<Case>,
+
+    /// The naming convention required for enum variant names. Defaults to
+    /// 'pascal'.
+    #[arg(long, value_name = "CASE", value_enum)]
+    pub variant_case: Option<Case>,
 }
 
 /* -------------------------------------------------------------------------- */
@@ -50,12 +321,147 @@ pub struct GeneratorSelection {
 /// [`handle`] implements the `compile` command.
 #[allow(unused)]
 pub fn handle(args: Args) -> anyhow::Result<()> {
-    if args.generator.rust {
-        compile(args.files, args.import_roots, args.out, RustGenerator)
-    } else if let Some(plugin_path) = args.generator.plugin {
-        let generator = ExternalGenerator::new(plugin_path).map_err(|e| anyhow!(e))?;
-        compile(args.files, args.import_roots, args.out, generator)
+    let project = match &args.config {
+        Some(path) => Project::load(path)?,
+        None => Project::discover(std::env::current_dir()?)?.unwrap_or_default(),
+    };
+
+    let files = match args.files.is_empty() {
+        true => project.files()?,
+        false => args.files.clone(),
+    };
+
+    if files.is_empty() {
+        return Err(anyhow!(
+            "no input files specified; pass FILES or declare 'inputs' in '{}'",
+            PROJECT_FILE_NAME
+        ));
+    }
+
+    let import_roots = match args.import_roots.is_empty() {
+        true => project.import_roots.clone(),
+        false => args.import_roots.clone(),
+    };
+
+    let is_selected = args.generator.rust.is_some()
+        || !args.generator.plugin.is_empty()
+        || !args.generator.named.is_empty();
+
+    let search = PluginSearchPath::new(
+        args.plugins_dir
+            .as_deref()
+            .or(project.plugins_dir.as_deref()),
+    );
+
+    let find = |name: &str| {
+        search.find(name).ok_or_else(|| {
+            anyhow!(
+                "plugin not found: '{}' (searched: {})",
+                plugin_binary_name(name),
+                search
+            )
+        })
+    };
+
+    let plugin = |path: &PathBuf, parameters: &BTreeMap<String, String>, legacy: bool| {
+        let mut parameters = parameters.clone();
+        for opt in &args.plugin_opts {
+            parameters.insert(opt.key.clone(), opt.value.clone());
+        }
+
+        ExternalGenerator::new(path)
+            .map(|generator| {
+                generator
+                    .with_legacy_protocol(legacy || args.legacy_plugin_protocol)
+                    .with_parameters(parameters)
+            })
+            .map_err(|e| anyhow!(e))
+    };
+
+    // NOTE: Schemas are compiled once; targets are grouped by their options so
+    // that each group's lints are applied to the shared compilation.
+    let mut groups: Vec<TargetGroup> = Vec::new();
+
+    let mut add = |target_options: Option<&ProjectOptions>, target: GeneratorTarget| {
+        let mut options = CompileOptions::default();
+        project.options.apply(&mut options);
+        if let Some(target_options) = target_options {
+            target_options.apply(&mut options);
+        }
+        args.options.apply(&mut options);
+
+        match groups.iter_mut().find(|group| group.options == options) {
+            Some(group) => group.targets.push(target),
+            None => groups.push(TargetGroup::new(options, vec![target])),
+        }
+    };
+
+    if is_selected {
+        if let Some(out) = &args.generator.rust {
+            let out = out.clone().or_else(|| args.out.clone());
+            add(None, GeneratorTarget::new(RustGenerator, out));
+        }
+
+        for binary in &args.generator.plugin {
+            let generator = plugin(&binary.binary, &BTreeMap::default(), false)?;
+            let out = binary.out.clone().or_else(|| args.out.clone());
+            add(None, GeneratorTarget::new(generator, out));
+        }
+
+        for named in &args.generator.named {
+            let generator = plugin(&find(&named.name)?, &BTreeMap::default(), false)?;
+            let out = named.out.clone().or_else(|| args.out.clone());
+            add(None, GeneratorTarget::new(generator, out));
+        }
     } else {
-        unreachable!()
+        for target in &project.targets {
+            let out = Some(target.out.clone());
+
+            let path = match (&target.plugin, &target.generator) {
+                (Some(path), _) => path.clone(),
+                (None, Some(name)) => find(name)?,
+                (None, None) => {
+                    add(
+                        Some(&target.options),
+                        GeneratorTarget::new(RustGenerator, out),
+                    );
+                    continue;
+                }
+            };
+
+            let generator = plugin(&path, &target.parameters, target.legacy_protocol)?;
+            add(Some(&target.options), GeneratorTarget::new(generator, out));
+        }
+    }
+
+    if groups.is_empty() {
+        return Err(anyhow!(
+            "no generator specified; pass '--rust', '--plugin' or '--gen', or declare 'targets' in '{}'",
+            PROJECT_FILE_NAME
+        ));
+    }
+
+    if args.watch {
+        if groups.len() > 1 {
+            return Err(anyhow!(
+                "'--watch' requires all targets to share the same options"
+            ));
+        }
+
+        let group = groups.remove(0);
+        return watch(files, import_roots, group.targets, group.options);
     }
+
+    if args.check {
+        return verify_groups(files, import_roots, groups);
+    }
+
+    let depfile = compile_groups(files, import_roots, groups)?;
+
+    if let Some(path) = &args.depfile {
+        depfile.touch()?;
+        depfile.write(path)?;
+    }
+
+    Ok(())
 }
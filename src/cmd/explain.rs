@@ -0,0 +1,25 @@
+use crate::analyze::Code;
+
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Args                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The diagnostic code to explain (e.g. 'E0003').
+    #[arg(value_name = "CODE")]
+    pub code: Code,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Function: handle                              */
+/* -------------------------------------------------------------------------- */
+
+/// [`handle`] implements the `explain` command.
+#[allow(unused)]
+pub fn handle(args: Args) -> anyhow::Result<()> {
+    println!("{}: {}\n", args.code, args.code.summary());
+    print!("{}", args.code.explanation());
+
+    Ok(())
+}
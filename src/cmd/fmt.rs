@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use crate::format::format;
+
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Args                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Don't rewrite any files; instead, list the files which aren't formatted
+    /// and exit with an error if there are any.
+    #[arg(long)]
+    pub check: bool,
+
+    /// A path to a message definition file to format in place.
+    #[arg(value_name = "FILES", required = true, num_args = 1..)]
+    pub files: Vec<PathBuf>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Function: handle                              */
+/* -------------------------------------------------------------------------- */
+
+/// [`handle`] implements the `fmt` command.
+#[allow(unused)]
+pub fn handle(args: Args) -> anyhow::Result<()> {
+    format(args.files, args.check)
+}
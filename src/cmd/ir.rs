@@ -0,0 +1,131 @@
+use anyhow::anyhow;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::analyze::DiagnosticsFormat;
+use crate::compile::CompileOptions;
+use crate::compile::lower_request;
+use crate::core::PackageName;
+use crate::generate::PluginRequest;
+
+use super::compile::OptionsArgs;
+use super::compile::PluginOptArg;
+
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Args                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Write the JSON without any whitespace (i.e. exactly as provided to
+    /// generator plugins when combined with '--envelope').
+    #[arg(long)]
+    pub compact: bool,
+
+    /// Wrap the schema in the versioned request envelope written to external
+    /// generators (see '--legacy-plugin-protocol' of the 'compile' command).
+    #[arg(long)]
+    pub envelope: bool,
+
+    /// A root directory to search for imported '.baproto' files. Can be
+    /// specified multiple times. Imports are resolved by searching each root in
+    /// order. If not specified, defaults to the current working directory.
+    #[arg(short = 'I', long = "import_root", value_name = "DIR")]
+    pub import_roots: Vec<PathBuf>,
+
+    #[command(flatten)]
+    pub options: OptionsArgs,
+
+    /// A path to a file in which to write the JSON. If not specified, the JSON
+    /// is written to stdout, in which case diagnostics must be reported in the
+    /// 'human' format (i.e. to stderr).
+    #[arg(short, long, value_name = "FILE")]
+    pub out: Option<PathBuf>,
+
+    /// Only include the specified package (e.g. 'foo.bar'). Can be specified
+    /// multiple times.
+    #[arg(short, long, value_name = "PACKAGE")]
+    pub package: Vec<PackageName>,
+
+    /// Pass the option KEY=VALUE to the envelope's parameters. Can be
+    /// specified multiple times.
+    #[arg(long = "plugin-opt", value_name = "KEY=VALUE", requires = "envelope")]
+    pub plugin_opts: Vec<PluginOptArg>,
+
+    /// A path to a message definition file to compile, or '-' to read it from
+    /// stdin.
+    #[arg(value_name = "FILES", required = true, num_args = 1..)]
+    pub files: Vec<PathBuf>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Function: handle                              */
+/* -------------------------------------------------------------------------- */
+
+/// [`handle`] implements the `ir` command.
+#[allow(unused)]
+pub fn handle(args: Args) -> anyhow::Result<()> {
+    // NOTE: Structured diagnostics are written to stdout, where they'd be
+    // interleaved with the IR.
+    if args.out.is_none() && args.options.diagnostics_format != DiagnosticsFormat::Human {
+        return Err(anyhow!(
+            "invalid argument: structured diagnostics formats require '--out'"
+        ));
+    }
+
+    let mut request = lower_request(
+        args.files,
+        args.import_roots,
+        CompileOptions::from(args.options),
+    )?;
+
+    if !args.package.is_empty() {
+        let schema = &mut request.schema;
+
+        if let Some(name) = args
+            .package
+            .iter()
+            .find(|name| !schema.packages.iter().any(|pkg| pkg.name == **name))
+        {
+            return Err(anyhow!("package not found: {}", name));
+        }
+
+        schema
+            .packages
+            .retain(|pkg| args.package.contains(&pkg.name));
+        request.packages.retain(|name| args.package.contains(name));
+    }
+
+    let mut json = match args.envelope {
+        true => {
+            let parameters = args
+                .plugin_opts
+                .iter()
+                .map(|opt| (opt.key.clone(), opt.value.clone()))
+                .collect::<BTreeMap<_, _>>();
+
+            to_json(&PluginRequest::new(&request, &parameters), args.compact)?
+        }
+        false => to_json(&request.schema, args.compact)?,
+    };
+
+    json.push('\n');
+
+    match args.out {
+        Some(path) => std::fs::write(path, json)?,
+        None => std::io::stdout().write_all(json.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+/* ------------------------------- Fn: to_json ------------------------------ */
+
+/// `to_json` serializes `value` as JSON, without any whitespace if `compact`.
+fn to_json<T: serde::Serialize>(value: &T, compact: bool) -> serde_json::Result<String> {
+    match compact {
+        true => serde_json::to_string(value),
+        false => serde_json::to_string_pretty(value),
+    }
+}
@@ -1,4 +1,9 @@
+pub mod check;
 pub mod compile;
+pub mod explain;
+pub mod fmt;
+pub mod ir;
+pub mod plugins;
 
 /* -------------------------------------------------------------------------- */
 /*                               Enum: Commands                               */
@@ -8,8 +13,31 @@ use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /* --------------------------- Category: Check -------------------------- */
+    /// Validate the specified message definitions without generating any
+    /// language bindings.
+    Check(check::Args),
+
     /* -------------------------- Category: Compile ------------------------- */
     /// Compile the specified message definitions into bindings for the
     /// specified language.
     Compile(compile::Args),
+
+    /* -------------------------- Category: Explain ------------------------- */
+    /// Print a detailed explanation of a diagnostic code.
+    Explain(explain::Args),
+
+    /* ---------------------------- Category: Fmt --------------------------- */
+    /// Format the specified message definitions in the canonical style.
+    Fmt(fmt::Args),
+
+    /* ----------------------------- Category: Ir --------------------------- */
+    /// Print the intermediate representation of the specified message
+    /// definitions as JSON, optionally wrapped in the request envelope provided
+    /// to generator plugins.
+    Ir(ir::Args),
+
+    /* -------------------------- Category: Plugins ------------------------- */
+    /// Manage the external generator plugins which can be selected by name.
+    Plugins(plugins::Args),
 }
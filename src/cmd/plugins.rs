@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use crate::config::Project;
+use crate::generate::ExternalGenerator;
+use crate::generate::PluginSearchPath;
+
+/* -------------------------------------------------------------------------- */
+/*                                Struct: Args                                */
+/* -------------------------------------------------------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/* ------------------------------ Enum: Command ----------------------------- */
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// List the plugins which can be selected by name (i.e. via '--gen'),
+    /// along with their self-reported name and version.
+    List(ListArgs),
+}
+
+/* ---------------------------- Struct: ListArgs ---------------------------- */
+
+#[derive(clap::Args, Debug)]
+pub struct ListArgs {
+    /// A directory to search for plugins, taking precedence over 'PATH'. If
+    /// not specified, the 'plugins_dir' of the project file in the current
+    /// directory is used, if present.
+    #[arg(long, value_name = "DIR")]
+    pub plugins_dir: Option<PathBuf>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Function: handle                              */
+/* -------------------------------------------------------------------------- */
+
+/// [`handle`] implements the `plugins` command.
+#[allow(unused)]
+pub fn handle(args: Args) -> anyhow::Result<()> {
+    match args.command {
+        Command::List(args) => list(args),
+    }
+}
+
+/* ------------------------------ Function: list ---------------------------- */
+
+/// [`list`] implements the `plugins list` command.
+fn list(args: ListArgs) -> anyhow::Result<()> {
+    let plugins_dir = match args.plugins_dir {
+        Some(dir) => Some(dir),
+        None => Project::discover(std::env::current_dir()?)?.and_then(|p| p.plugins_dir),
+    };
+
+    let search = PluginSearchPath::new(plugins_dir.as_deref());
+
+    let rows = search
+        .list()
+        .into_iter()
+        .map(|(name, path)| {
+            let description = ExternalGenerator::new(&path)
+                .and_then(|generator| generator.describe())
+                .map(|d| format!("{} {}", d.name, d.version))
+                .unwrap_or_else(|err| format!("(unknown: {})", err));
+
+            (name, description, path)
+        })
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        eprintln!("No plugins found (searched: {}).", search);
+        return Ok(());
+    }
+
+    let name_width = rows.iter().map(|(n, _, _)| n.len()).max().unwrap_or(0);
+    let description_width = rows.iter().map(|(_, d, _)| d.len()).max().unwrap_or(0);
+
+    for (name, description, path) in rows {
+        println!(
+            "{:name_width$}  {:description_width$}  {}",
+            name,
+            description,
+            path.display()
+        );
+    }
+
+    Ok(())
+}
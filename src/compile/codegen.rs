@@ -0,0 +1,521 @@
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use thiserror::Error;
+
+use crate::analyze::Code;
+use crate::analyze::Diagnostic;
+use crate::analyze::Location;
+use crate::analyze::Severity;
+use crate::core::OsFileSystem;
+use crate::generate::Generator;
+use crate::generate::RustGenerator;
+
+use super::CompileOptions;
+use super::GeneratorTarget;
+use super::OutputError;
+use super::SourceCache;
+use super::TargetGroup;
+use super::output::{normalize, resolves_within};
+use super::{build_compiler, count_errors, prepare, run_generators};
+use super::{write_if_changed, write_output};
+
+/* -------------------------------------------------------------------------- */
+/*                             Enum: CodegenError                             */
+/* -------------------------------------------------------------------------- */
+
+/// Errors that can occur while generating bindings via [`Codegen`].
+#[allow(unused)]
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    /// The inputs, import roots or output directory are invalid.
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    /// The schemas contain errors.
+    #[error(
+        "compilation failed with {} error(s):{}",
+        count(diagnostics),
+        list(diagnostics)
+    )]
+    Compilation { diagnostics: Vec<CodegenDiagnostic> },
+
+    /// The generator failed (or reported errors).
+    #[error("generation failed: {}{}", failures.join("; "), list(diagnostics))]
+    Generation {
+        diagnostics: Vec<CodegenDiagnostic>,
+        failures: Vec<String>,
+    },
+
+    /// A file couldn't be written.
+    #[error("failed to write '{path}': {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/* --------------------------- Impl: CodegenError --------------------------- */
+
+#[allow(unused)]
+impl CodegenError {
+    /// `diagnostics` returns the diagnostics reported before the error
+    /// occurred, if any.
+    pub fn diagnostics(&self) -> &[CodegenDiagnostic] {
+        match self {
+            Self::Compilation { diagnostics } | Self::Generation { diagnostics, .. } => diagnostics,
+            Self::Config(_) | Self::Io { .. } => &[],
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Codegen                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Codegen` generates bindings from within a Cargo build script (i.e.
+/// `build.rs`). Unlike [`super::compile`], nothing is printed other than
+/// `cargo:rerun-if-changed` directives; diagnostics are returned instead.
+///
+/// ```no_run
+/// let output = baproto::CodegenBuilder::default()
+///     .input("schemas/foo.baproto")
+///     .import_root("schemas")
+///     .include_file("baproto.rs")
+///     .build()
+///     .unwrap()
+///     .run()
+///     .unwrap();
+///
+/// for diagnostic in output.diagnostics {
+///     println!("cargo:warning={}", diagnostic);
+/// }
+/// ```
+///
+/// The generated modules can then be included into the crate:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/baproto.rs"));
+/// ```
+#[allow(unused)]
+#[derive(Builder)]
+#[builder(pattern = "owned")]
+pub struct Codegen {
+    /// `inputs` contains the schema files to compile.
+    #[builder(default, setter(each(name = "input", into)))]
+    inputs: Vec<PathBuf>,
+    /// `import_roots` contains the directories searched for included schemas;
+    /// defaults to the current directory (i.e. the package root).
+    #[builder(default, setter(each(name = "import_root", into)))]
+    import_roots: Vec<PathBuf>,
+    /// `out_dir` is the directory into which bindings are generated; defaults
+    /// to the `OUT_DIR` set by Cargo.
+    #[builder(default, setter(into, strip_option))]
+    out_dir: Option<PathBuf>,
+    /// `generator` produces the bindings; defaults to [`RustGenerator`].
+    #[builder(default = "Box::new(RustGenerator)", setter(custom))]
+    generator: Box<dyn Generator>,
+    /// `options` configures optional compilation behavior.
+    #[builder(default)]
+    options: CompileOptions,
+    /// `include_file` is the path, relative to `out_dir`, of a Rust file which
+    /// declares a module for each package, `include!`-ing its bindings.
+    #[builder(default, setter(into, strip_option))]
+    include_file: Option<PathBuf>,
+    /// `emit_rerun_if_changed` prints a `cargo:rerun-if-changed` directive for
+    /// each schema read.
+    #[builder(default = "true")]
+    emit_rerun_if_changed: bool,
+}
+
+/* ------------------------- Impl: CodegenBuilder --------------------------- */
+
+#[allow(unused)]
+impl CodegenBuilder {
+    /// `generator` sets the [`Generator`] which produces the bindings.
+    pub fn generator<G: Generator + 'static>(mut self, generator: G) -> Self {
+        self.generator = Some(Box::new(generator));
+        self
+    }
+}
+
+/* ------------------------------ Impl: Codegen ----------------------------- */
+
+#[allow(unused)]
+impl Codegen {
+    /// `run` compiles the input schemas and writes the generated bindings into
+    /// the output directory, returning all reported diagnostics along with the
+    /// files which were read and written.
+    pub fn run(self) -> Result<CodegenOutput, CodegenError> {
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or_else(|| {
+                    CodegenError::Config(
+                        "'OUT_DIR' isn't set; specify an output directory".to_owned(),
+                    )
+                })?,
+        };
+
+        let include_file = self
+            .include_file
+            .as_deref()
+            .map(normalize)
+            .transpose()
+            .map_err(|e| CodegenError::Config(e.to_string()))?;
+
+        let target = GeneratorTarget {
+            generator: self.generator,
+            out: Some(out_dir.clone()),
+        };
+
+        let groups = [TargetGroup::new(self.options, vec![target])];
+
+        let compiler = build_compiler(Rc::new(OsFileSystem), &self.inputs, &self.import_roots);
+
+        let mut compiler = match compiler {
+            Ok(compiler) => compiler,
+            Err(err) => {
+                // NOTE: Rerun once the missing inputs are (re)created.
+                if self.emit_rerun_if_changed {
+                    rerun_if_changed(&self.inputs);
+                }
+
+                return Err(CodegenError::Config(err.to_string()));
+            }
+        };
+
+        prepare(&mut compiler, &groups).map_err(|source| CodegenError::Io {
+            path: out_dir.clone(),
+            source,
+        })?;
+
+        let inputs = compiler
+            .sources
+            .files()
+            .filter(|f| !f.is_stdin())
+            .map(|f| f.as_path().to_owned())
+            .collect::<BTreeSet<_>>();
+
+        if self.emit_rerun_if_changed {
+            rerun_if_changed(&inputs);
+        }
+
+        let mut diagnostics = convert(&compiler.diagnostics, &compiler.sources);
+
+        if count_errors(&compiler.diagnostics) > 0 {
+            return Err(CodegenError::Compilation { diagnostics });
+        }
+
+        std::fs::create_dir_all(&out_dir).map_err(|source| CodegenError::Io {
+            path: out_dir.clone(),
+            source,
+        })?;
+
+        let out_dir = out_dir.canonicalize().map_err(|source| CodegenError::Io {
+            path: out_dir.clone(),
+            source,
+        })?;
+
+        let generation = run_generators(
+            &compiler,
+            &groups[0].targets,
+            std::slice::from_ref(&out_dir),
+            &groups[0].options,
+            &write_output,
+        );
+
+        diagnostics.extend(convert(&generation.diagnostics, &compiler.sources));
+
+        if !generation.failures.is_empty() {
+            return Err(CodegenError::Generation {
+                diagnostics,
+                failures: generation.failures,
+            });
+        }
+
+        if let Some(include_file) = include_file {
+            write_include_file(&out_dir, &include_file, &generation.outputs)?;
+        }
+
+        Ok(CodegenOutput {
+            diagnostics,
+            inputs,
+            outputs: generation.outputs.into_iter().collect(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: CodegenOutput                            */
+/* -------------------------------------------------------------------------- */
+
+/// `CodegenOutput` describes the result of a successful [`Codegen::run`].
+#[allow(unused)]
+#[derive(Clone, Debug)]
+pub struct CodegenOutput {
+    /// `diagnostics` contains all reported warnings.
+    pub diagnostics: Vec<CodegenDiagnostic>,
+    /// `inputs` contains every schema file which was read.
+    pub inputs: BTreeSet<PathBuf>,
+    /// `outputs` contains every file which was generated.
+    pub outputs: BTreeSet<PathBuf>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                         Struct: CodegenDiagnostic                          */
+/* -------------------------------------------------------------------------- */
+
+/// `CodegenDiagnostic` is a diagnostic reported by [`Codegen`], with its
+/// location resolved to a line and column within the schema file.
+#[allow(unused)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodegenDiagnostic {
+    /// `code` is the stable identifier of the diagnostic, if any.
+    pub code: Option<Code>,
+    /// `help` contains suggestions for resolving the diagnostic.
+    pub help: Vec<String>,
+    /// `location` is where the diagnostic occurs, if known.
+    pub location: Option<Location>,
+    /// `message` describes the diagnostic.
+    pub message: String,
+    /// `notes` contains additional context about the diagnostic.
+    pub notes: Vec<String>,
+    /// `severity` is the importance of the diagnostic.
+    pub severity: Severity,
+}
+
+/* ------------------------------ Impl: Display ----------------------------- */
+
+impl std::fmt::Display for CodegenDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(
+                f,
+                "{}:{}:{}: ",
+                location.file, location.start_position.line, location.start_position.column
+            )?;
+        }
+
+        write!(f, "{}", self.severity)?;
+
+        if let Some(code) = self.code {
+            write!(f, "[{}]", code)?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/* ------------------------------- Fn: convert ------------------------------ */
+
+/// `convert` resolves the locations of `diagnostics` using `sources`.
+fn convert(diagnostics: &[Diagnostic], sources: &SourceCache) -> Vec<CodegenDiagnostic> {
+    diagnostics
+        .iter()
+        .map(|d| CodegenDiagnostic {
+            code: d.code,
+            help: d.help.clone(),
+            location: Location::of(d, sources),
+            message: d.message.clone(),
+            notes: d.notes.clone(),
+            severity: d.severity,
+        })
+        .collect()
+}
+
+/* -------------------------------- Fn: count ------------------------------- */
+
+/// `count` returns the number of `diagnostics` which are errors.
+fn count(diagnostics: &[CodegenDiagnostic]) -> usize {
+    diagnostics
+        .iter()
+        .filter(|d| matches!(d.severity, Severity::Error))
+        .count()
+}
+
+/* -------------------------------- Fn: list -------------------------------- */
+
+/// `list` formats each of the `diagnostics` on its own line.
+fn list(diagnostics: &[CodegenDiagnostic]) -> String {
+    diagnostics.iter().map(|d| format!("\n  {}", d)).collect()
+}
+
+/* --------------------------- Fn: rerun_if_changed ------------------------- */
+
+/// `rerun_if_changed` instructs Cargo to rerun the build script whenever one
+/// of `paths` changes.
+fn rerun_if_changed<'a, T: IntoIterator<Item = &'a PathBuf>>(paths: T) {
+    for path in paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+/* -------------------------- Fn: write_include_file ------------------------ */
+
+/// `write_include_file` writes a Rust file at `path` (relative to `out_dir`)
+/// which declares a module for each package, `include!`-ing the generated Rust
+/// `outputs` within `out_dir`.
+fn write_include_file(
+    out_dir: &Path,
+    path: &Path,
+    outputs: &[PathBuf],
+) -> Result<(), CodegenError> {
+    // NOTE: `include!` resolves paths relative to the file containing it.
+    let prefix = "../".repeat(path.components().count() - 1);
+
+    let mut root = ModuleTree::default();
+
+    for output in outputs {
+        let Ok(relative) = output.strip_prefix(out_dir) else {
+            continue;
+        };
+
+        if relative.extension().is_none_or(|ext| ext != "rs") || relative == path {
+            continue;
+        }
+
+        let mut module = &mut root;
+        for component in relative.with_extension("").iter() {
+            module = module
+                .children
+                .entry(component.to_string_lossy().into_owned())
+                .or_default();
+        }
+
+        let components = relative
+            .iter()
+            .map(|c| c.to_string_lossy())
+            .collect::<Vec<_>>();
+
+        module.include = Some(format!("{}{}", prefix, components.join("/")));
+    }
+
+    let mut contents = String::new();
+    contents.push_str("// This file was automatically generated by baproto.\n");
+    contents.push_str("// Do not edit manually.\n");
+    root.write(&mut contents, 0);
+
+    let full = out_dir.join(path);
+
+    let within = resolves_within(out_dir, path).map_err(|source| CodegenError::Io {
+        path: full.clone(),
+        source,
+    })?;
+
+    if !within {
+        let err = OutputError::EscapesOutDir(path.to_owned());
+        return Err(CodegenError::Config(err.to_string()));
+    }
+
+    let path = full;
+
+    write_if_changed(&path, &contents)
+        .map(|_| ())
+        .map_err(|source| CodegenError::Io { path, source })
+}
+
+/* ---------------------------- Struct: ModuleTree -------------------------- */
+
+/// `ModuleTree` is a Rust module within a generated include file.
+#[derive(Default)]
+struct ModuleTree {
+    /// `children` contains the nested modules, by name.
+    children: BTreeMap<String, ModuleTree>,
+    /// `include` is the path of the file which is `include!`-ed within the
+    /// module, if any.
+    include: Option<String>,
+}
+
+/* ---------------------------- Impl: ModuleTree ---------------------------- */
+
+impl ModuleTree {
+    /// `write` appends the module's contents to `out`, indented by `depth`.
+    fn write(&self, out: &mut String, depth: usize) {
+        let indent = "    ".repeat(depth);
+
+        if let Some(include) = &self.include {
+            out.push_str(&format!("{}include!({:?});\n", indent, include));
+        }
+
+        for (i, (name, child)) in self.children.iter().enumerate() {
+            // NOTE: Separate each item from the one preceding it.
+            if depth == 0 || i > 0 || self.include.is_some() {
+                out.push('\n');
+            }
+
+            out.push_str(&format!("{}pub mod {} {{\n", indent, name));
+            child.write(out, depth + 1);
+            out.push_str(&format!("{}}}\n", indent));
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /* ------------------------ Tests: write_include_file ------------------- */
+
+    #[test]
+    fn test_write_include_file_declares_nested_modules() {
+        // Given: Generated files for nested packages.
+        let dir = TempDir::new().unwrap();
+        let outputs = vec![
+            dir.path().join("foo.rs"),
+            dir.path().join("foo/bar.rs"),
+            dir.path().join("foo/bar.json"),
+        ];
+
+        // When: Writing an include file within a subdirectory.
+        write_include_file(dir.path(), Path::new("gen/mod.rs"), &outputs).unwrap();
+
+        // Then: Each Rust file is included within its package's module.
+        let contents = std::fs::read_to_string(dir.path().join("gen/mod.rs")).unwrap();
+        assert_eq!(
+            contents,
+            "// This file was automatically generated by baproto.\n\
+             // Do not edit manually.\n\
+             \n\
+             pub mod foo {\n    \
+                 include!(\"../foo.rs\");\n\
+             \n    \
+                 pub mod bar {\n        \
+                     include!(\"../foo/bar.rs\");\n    \
+                 }\n\
+             }\n"
+        );
+    }
+
+    /* ---------------------- Tests: CodegenDiagnostic ---------------------- */
+
+    #[test]
+    fn test_codegen_diagnostic_display_without_location() {
+        // Given: A diagnostic without a location.
+        let diagnostic = CodegenDiagnostic {
+            code: Some(Code::UnresolvedImport),
+            help: vec![],
+            location: None,
+            message: "failed to resolve import".to_owned(),
+            notes: vec![],
+            severity: Severity::Error,
+        };
+
+        // When: Formatting the diagnostic.
+        // Then: The severity, code and message are included.
+        assert_eq!(
+            diagnostic.to_string(),
+            "error[E0006]: failed to resolve import"
+        );
+    }
+}
@@ -1,7 +1,9 @@
+use crate::analyze::Code;
 use crate::analyze::Diagnostic;
 use crate::ast;
+use crate::compile::Declaration;
 use crate::compile::symbol::Symbols;
-use crate::core::{Descriptor, DescriptorBuilder, ImportRoot, PackageName, SchemaImport};
+use crate::core::{Descriptor, DescriptorBuilder, PackageName, SchemaImport};
 use crate::ir::lower::TypeKind;
 use crate::lex::Span;
 use crate::visit::{Visitable, Visitor, walk};
@@ -16,8 +18,10 @@ use crate::visit::{Visitable, Visitor, walk};
 /// definitions (messages and enums) immediately as they're discovered. It
 /// tracks type descriptors for module metadata and collects any diagnostics.
 pub struct TypeCollector<'a> {
+    declarations: &'a mut Symbols<Declaration>,
     descriptors: Vec<Descriptor>,
     diagnostics: Vec<Diagnostic>,
+    members: &'a mut Symbols<Span>,
     package: Option<PackageName>,
     path: Vec<String>,
     symbols: &'a mut Symbols<TypeKind>,
@@ -29,50 +33,43 @@ impl<'a> TypeCollector<'a> {
     /// Registers all types and module metadata from a schema.
     ///
     /// This function visits the AST and registers types directly into the
-    /// symbol table as they're discovered.
+    /// symbol table as they're discovered. Each type's declaration site is
+    /// recorded in `declarations`, and that of each field and enum variant in
+    /// `members`.
     ///
     /// Returns a vector of diagnostics (empty on success).
     pub fn register(
         ast: &ast::Schema,
         _import: &SchemaImport,
         symbols: &'a mut Symbols<TypeKind>,
-        import_roots: &[ImportRoot],
+        declarations: &'a mut Symbols<Declaration>,
+        members: &'a mut Symbols<Span>,
     ) -> Vec<Diagnostic> {
-        let mut collector = Self::new(symbols);
+        let mut collector = Self::new(symbols, declarations, members);
         ast.visit(&mut collector);
 
         if !collector.diagnostics.is_empty() {
             return collector.diagnostics;
         }
 
-        let _package_name = match collector.package {
-            Some(pkg) => pkg,
-            None => {
-                return vec![Diagnostic::error(
-                    Span::default(),
-                    "schema missing package declaration",
-                )];
-            }
-        };
-
-        let _deps = ast
-            .iter_includes()
-            .filter_map(|inc| {
-                // Try each import root in order.
-                for root in import_roots {
-                    if let Ok(schema) = root.resolve_schema_import(&inc.path) {
-                        return Some(schema);
-                    }
-                }
-                None
-            })
-            .collect::<Vec<_>>();
+        if collector.package.is_none() {
+            return vec![
+                Diagnostic::error(Span::default(), "schema missing package declaration")
+                    .with_code(Code::InvalidPackage),
+            ];
+        }
 
         Vec::new()
     }
 
-    fn new(symbols: &'a mut Symbols<TypeKind>) -> Self {
+    fn new(
+        symbols: &'a mut Symbols<TypeKind>,
+        declarations: &'a mut Symbols<Declaration>,
+        members: &'a mut Symbols<Span>,
+    ) -> Self {
         Self {
+            declarations,
+            members,
             symbols,
             package: None,
             path: Vec::new(),
@@ -94,13 +91,31 @@ impl<'a> TypeCollector<'a> {
             .ok()
     }
 
-    fn register_type(&mut self, name: &str, kind: TypeKind) {
-        if let Some(descriptor) = self.descriptor(name) {
+    fn register_type(
+        &mut self,
+        name: &ast::Ident,
+        annotations: &[ast::Annotation],
+        kind: TypeKind,
+    ) {
+        if let Some(descriptor) = self.descriptor(&name.name) {
             self.symbols.insert(descriptor.clone(), kind);
+            self.declarations.insert(
+                descriptor.clone(),
+                Declaration {
+                    annotations: annotations.to_vec(),
+                    span: name.span.clone(),
+                },
+            );
 
             self.descriptors.push(descriptor);
         }
     }
+
+    fn register_member(&mut self, name: &ast::Ident) {
+        if let Some(descriptor) = self.descriptor(&name.name) {
+            self.members.insert(descriptor, name.span.clone());
+        }
+    }
 }
 
 /* ---------------------- Impl: Visitor<TypeCollector> --------------------- */
@@ -109,31 +124,37 @@ impl<'ast> Visitor<'ast> for TypeCollector<'_> {
     fn visit_package(&mut self, pkg: &'ast ast::Package) {
         match PackageName::try_from(pkg.clone()) {
             Ok(name) => self.package = Some(name),
-            Err(e) => self.diagnostics.push(Diagnostic::error(
-                pkg.span.clone(),
-                format!("invalid package name: {}", e),
-            )),
+            Err(e) => self.diagnostics.push(
+                Diagnostic::error(pkg.span.clone(), format!("invalid package name: {}", e))
+                    .with_code(Code::InvalidPackage),
+            ),
         }
     }
 
     fn visit_include(&mut self, node: &'ast ast::Include) {
         if self.package.is_none() {
-            self.diagnostics.push(Diagnostic::error(
-                node.span.clone(),
-                "'include' statement cannot come before 'package' declaration",
-            ));
+            self.diagnostics.push(
+                Diagnostic::error(
+                    node.span.clone(),
+                    "'include' statement cannot come before 'package' declaration",
+                )
+                .with_code(Code::MisplacedDeclaration),
+            );
         }
     }
 
     fn visit_message(&mut self, msg: &'ast ast::Message) {
         if self.package.is_none() {
-            self.diagnostics.push(Diagnostic::error(
-                msg.span.clone(),
-                "'message' definition cannot come before 'package' declaration",
-            ));
+            self.diagnostics.push(
+                Diagnostic::error(
+                    msg.span.clone(),
+                    "'message' definition cannot come before 'package' declaration",
+                )
+                .with_code(Code::MisplacedDeclaration),
+            );
         }
 
-        self.register_type(&msg.name.name, TypeKind::Message);
+        self.register_type(&msg.name, &msg.annotations, TypeKind::Message);
 
         self.path.push(msg.name.name.clone());
 
@@ -142,15 +163,26 @@ impl<'ast> Visitor<'ast> for TypeCollector<'_> {
         self.path.pop();
     }
 
+    fn visit_field(&mut self, field: &'ast ast::Field) {
+        self.register_member(&field.name);
+    }
+
+    fn visit_unit_variant(&mut self, variant: &'ast ast::UnitVariant) {
+        self.register_member(&variant.name);
+    }
+
     fn visit_enum(&mut self, enm: &'ast ast::Enum) {
         if self.package.is_none() {
-            self.diagnostics.push(Diagnostic::error(
-                enm.span.clone(),
-                "'enum' definition cannot come before 'package' declaration",
-            ));
+            self.diagnostics.push(
+                Diagnostic::error(
+                    enm.span.clone(),
+                    "'enum' definition cannot come before 'package' declaration",
+                )
+                .with_code(Code::MisplacedDeclaration),
+            );
         }
 
-        self.register_type(&enm.name.name, TypeKind::Enum);
+        self.register_type(&enm.name, &enm.annotations, TypeKind::Enum);
 
         self.path.push(enm.name.name.clone());
 
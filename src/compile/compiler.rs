@@ -1,19 +1,34 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::path::PathBuf;
 
 use derive_builder::Builder;
 
 use crate::analyze::Analyzer;
+use crate::analyze::Code;
+use crate::analyze::DeprecatedUsage;
 use crate::analyze::Diagnostic;
 use crate::analyze::FieldIndexUniqueness;
+use crate::analyze::Keywords;
+use crate::analyze::NamingConvention;
+use crate::analyze::ReservedUsage;
+use crate::analyze::Severity;
+use crate::analyze::Suppression;
+use crate::analyze::SuppressionCollector;
 use crate::analyze::TypeReferenceResolver;
+use crate::analyze::UnusedInclude;
+use crate::analyze::unused_types;
 use crate::ast;
-use crate::core::{Descriptor, ImportRoot, SchemaImport};
+use crate::core::{Descriptor, ImportRoot, PackageName, SchemaImport};
+use crate::generate::{GeneratorDiagnostic, GeneratorRequest};
 use crate::ir;
 use crate::ir::lower::{Lower, LowerContext, TypeKind};
 use crate::lex::Span;
 use crate::visit::Visitable;
 
+use super::CompileOptions;
+use super::Declaration;
+use super::IncludeGraph;
 use super::SourceCache;
 use super::symbol::Symbols;
 
@@ -31,22 +46,50 @@ use super::symbol::Symbols;
 /// 5. Lower to IR and merge incrementally
 #[derive(Builder)]
 pub struct Compiler {
+    /// `declarations` records the definition site of each registered type.
+    #[builder(default)]
+    pub declarations: Symbols<Declaration>,
     /// `diagnostics` contains all reported diagnostics collected during
     /// compilation.
     #[builder(default)]
     pub diagnostics: Vec<Diagnostic>,
     /// Import search paths
     import_roots: Vec<ImportRoot>,
+    /// `includes` records the resolved `include` statements of each schema.
+    #[builder(default)]
+    includes: IncludeGraph,
+    /// `members` records the definition site of each message field and enum
+    /// variant.
+    #[builder(default)]
+    members: Symbols<Span>,
     /// `ir` accumulates the intermediate representation incrementally during
     /// compilation.
     #[builder(default)]
     ir: ir::Schema,
+    /// `packages` records the package declared by each compiled schema.
+    #[builder(default)]
+    packages: HashMap<SchemaImport, PackageName>,
+    /// `references` contains the descriptors of all referenced types.
+    #[builder(default)]
+    references: HashSet<Descriptor>,
+    /// `schemas` contains the AST of each successfully parsed schema, so that
+    /// analyses which depend on [`CompileOptions`] can be run afterwards.
+    #[builder(default)]
+    schemas: Vec<ast::Schema>,
+    /// `requested` contains the schemas explicitly requested for compilation,
+    /// in the order they were requested.
+    #[builder(default)]
+    requested: Vec<SchemaImport>,
     /// `processed` is the set of already-processed imports (prevents cycles).
     #[builder(default)]
     processed: HashSet<SchemaImport>,
     /// `sources` caches source code by its import path for diagnostic reports.
     #[builder(default)]
     pub sources: SourceCache,
+    /// `suppressions` contains the inline lint suppressions of all compiled
+    /// schemas.
+    #[builder(default)]
+    pub suppressions: Vec<Suppression>,
     /// `symbols` tracks types and modules encountered during compilation.
     #[builder(default)]
     pub symbols: Symbols<TypeKind>,
@@ -55,6 +98,7 @@ pub struct Compiler {
 /* ---------------------------- Impl: Compiler ------------------------------ */
 
 impl Compiler {
+    #[allow(unused)]
     /// `new` instantiates a new compiler with the provided import search roots.
     pub fn new(import_roots: Vec<ImportRoot>) -> Self {
         CompilerBuilder::default()
@@ -67,6 +111,17 @@ impl Compiler {
     ///
     /// Returns the symbol table and any diagnostics produced.
     pub fn compile(&mut self, import: SchemaImport) {
+        if !self.requested.contains(&import) {
+            self.requested.push(import.clone());
+        }
+
+        self.compile_from(import, None);
+    }
+
+    /// Compiles a schema import and all its dependencies, attributing errors
+    /// reading the schema to the `origin` span of the `include` statement
+    /// which introduced it (if any).
+    fn compile_from(&mut self, import: SchemaImport, origin: Option<&Span>) {
         // Skip if already processed (prevents infinite loops).
         if self.processed.contains(&import) {
             return;
@@ -75,7 +130,7 @@ impl Compiler {
         self.processed.insert(import.clone());
 
         // 1. Parse the schema file
-        let ast = match self.parse(&import) {
+        let ast = match self.parse(&import, origin) {
             Ok(ast) => ast,
             Err(errs) => {
                 self.diagnostics.extend(errs);
@@ -83,81 +138,151 @@ impl Compiler {
             }
         };
 
+        if let Some(package_name) = ast.get_package_name() {
+            self.packages.insert(import.clone(), package_name);
+        }
+
         // 2. Register types in symbol table (before processing deps).
-        let errors =
-            super::TypeCollector::register(&ast, &import, &mut self.symbols, &self.import_roots);
+        let errors = super::TypeCollector::register(
+            &ast,
+            &import,
+            &mut self.symbols,
+            &mut self.declarations,
+            &mut self.members,
+        );
         self.diagnostics.extend(errors);
 
         // 3. Process imports first (DFS ensures deps ready before analysis).
         for include in ast.iter_includes() {
-            match self.resolve_import(&include.path) {
-                Ok(dep) => self.compile(dep),
-                Err(e) => self.diagnostics.push(e),
+            match self.resolve_import(include) {
+                Ok(dep) => {
+                    self.includes
+                        .insert(import.clone(), include.clone(), dep.clone());
+                    self.compile_from(dep, Some(&include.span));
+                }
+                Err(e) => self.diagnostics.push(*e),
             }
         }
 
         // 4. Run semantic analyzers.
-        self.run_analyzers(&ast);
+        self.run_analyzers(&ast, &import);
 
         // 5. Lower to IR and merge.
         self.lower_and_merge(&ast);
+
+        self.schemas.push(ast);
     }
 
-    /// Parses a schema file into an AST.
-    fn parse(&mut self, import: &SchemaImport) -> Result<ast::Schema, Vec<Diagnostic>> {
-        let contents = self.sources.insert(import).map_err(|e| {
-            vec![Diagnostic::error(
-                Span::default(),
-                format!("failed to read file: {}", e),
-            )]
-        })?;
+    /// `diagnose` converts a diagnostic reported by the generator `name` into
+    /// a [`Diagnostic`] located at the declaration it refers to.
+    pub fn diagnose(&self, name: &str, diagnostic: &GeneratorDiagnostic) -> Diagnostic {
+        let mut descriptor = diagnostic.descriptor.clone();
+        if let (Some(descriptor), Some(field)) = (descriptor.as_mut(), &diagnostic.field) {
+            descriptor.push(field);
+        }
 
-        // Lex
-        let result = crate::lex::lex(&contents, import.clone());
-        if !result.errors.is_empty() {
-            return Err(result
-                .errors
-                .into_iter()
-                .map(|e| Diagnostic::error(e.span().clone(), e.to_string()))
-                .collect());
+        let span = match (&diagnostic.descriptor, &descriptor) {
+            (Some(parent), Some(descriptor)) => self
+                .members
+                .get(descriptor)
+                .or_else(|| self.declarations.get(descriptor).map(|d| d.span))
+                .or_else(|| self.declarations.get(parent).map(|d| d.span)),
+            _ => None,
+        };
+
+        let mut result = match diagnostic.severity {
+            Severity::Error => {
+                Diagnostic::error(span.clone().unwrap_or_default(), &diagnostic.message)
+            }
+            Severity::Warning => {
+                Diagnostic::warning(span.clone().unwrap_or_default(), &diagnostic.message)
+            }
+        };
+
+        if let (None, Some(descriptor)) = (&span, &descriptor) {
+            result = result.with_note(format!("referenced declaration: '{}'", descriptor));
         }
 
-        let tokens = result.tokens.ok_or_else(|| {
-            vec![Diagnostic::error(
-                Span::default(),
-                "lexing failed with no specific error",
-            )]
-        })?;
+        result.with_note(format!("reported by generator '{}'", name))
+    }
+
+    /// `lint` runs the analyses which depend on `options` (i.e. naming
+    /// conventions, reserved words of the target languages' `keywords` and
+    /// unused types) once all schemas have been compiled. The diagnostics are
+    /// returned rather than recorded, so that the same compilation can be
+    /// linted with different options.
+    pub fn lint(&self, options: &CompileOptions, keywords: &[Keywords]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
 
-        // Parse
-        let result = crate::parse::parse(&tokens, import.clone());
-        if !result.errors.is_empty() {
-            return Err(result
-                .errors
-                .into_iter()
-                .map(|e| Diagnostic::error(e.span().clone(), e.to_string()))
-                .collect());
+        for ast in &self.schemas {
+            diagnostics.extend(Self::run_analyzer(
+                ast,
+                NamingConvention::new(&options.naming, keywords),
+            ));
         }
 
-        result.ast.ok_or_else(|| {
-            vec![Diagnostic::error(
-                Span::default(),
-                "parse failed with no specific error",
-            )]
-        })
+        if options.warn_unused_types {
+            diagnostics.extend(unused_types(&self.declarations, &self.references));
+        }
+
+        diagnostics
+    }
+
+    /// Parses a schema file into an AST. Syntax errors from which the parser
+    /// recovered are recorded directly, with the partial AST being returned.
+    fn parse(
+        &mut self,
+        import: &SchemaImport,
+        origin: Option<&Span>,
+    ) -> Result<ast::Schema, Vec<Diagnostic>> {
+        let contents = self.sources.insert(import).map_err(|e| {
+            vec![
+                Diagnostic::error(
+                    origin.cloned().unwrap_or_default(),
+                    format!("failed to read file: {}: {}", import, e),
+                )
+                .with_code(Code::UnreadableFile),
+            ]
+        })?;
+
+        let (ast, errors) = parse_schema(&contents, import);
+
+        match ast {
+            // NOTE: A recovered AST is still analyzed so that errors in its
+            // valid declarations are reported alongside the syntax errors.
+            Some(ast) => {
+                self.diagnostics.extend(errors);
+                Ok(ast)
+            }
+            None => Err(errors),
+        }
     }
 
-    /// Resolves an include path to a SchemaImport using import roots.
-    fn resolve_import(&self, path: &PathBuf) -> Result<SchemaImport, Diagnostic> {
+    /// Resolves an include statement to a SchemaImport using import roots.
+    fn resolve_import(&self, include: &ast::Include) -> Result<SchemaImport, Box<Diagnostic>> {
         for root in &self.import_roots {
-            if let Ok(schema) = root.resolve_schema_import(path) {
+            if let Ok(schema) = root.resolve_schema_import_in(self.sources.fs(), &include.path) {
                 return Ok(schema);
             }
         }
 
-        Err(Diagnostic::error(
-            Span::default(),
-            format!("failed to resolve import: {}", path.display()),
+        let searched = self
+            .import_roots
+            .iter()
+            .map(|root| format!("'{}'", root.as_path().display()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(Box::new(
+            Diagnostic::error(
+                include.span.clone(),
+                format!(
+                    "failed to resolve import: {} (searched import roots: {})",
+                    include.path.display(),
+                    searched
+                ),
+            )
+            .with_code(Code::UnresolvedImport),
         ))
     }
 
@@ -171,16 +296,48 @@ impl Compiler {
     }
 
     /// Runs all semantic analyzers on the AST.
-    fn run_analyzers(&mut self, ast: &ast::Schema) {
+    fn run_analyzers(&mut self, ast: &ast::Schema, import: &SchemaImport) {
         // Declarative list of analyzers to run
         self.diagnostics
             .extend(Self::run_analyzer(ast, FieldIndexUniqueness::default()));
+        self.diagnostics
+            .extend(Self::run_analyzer(ast, ReservedUsage::default()));
+
+        let mut collector = SuppressionCollector::default();
+        ast.visit(&mut collector);
+
+        self.suppressions.extend(collector.drain_suppressions());
+        self.diagnostics.extend(collector.drain_diagnostics());
 
         if let Some(package_name) = ast.get_package_name() {
+            let imports = self
+                .includes
+                .includes(import)
+                .iter()
+                .filter_map(|(_, dep)| self.packages.get(dep))
+                .filter(|pkg| **pkg != package_name)
+                .cloned()
+                .collect::<Vec<_>>();
+
             self.diagnostics.extend(Self::run_analyzer(
                 ast,
-                TypeReferenceResolver::new(&self.symbols, Descriptor::from(package_name)),
+                TypeReferenceResolver::new(&self.symbols, Descriptor::from(package_name.clone()))
+                    .with_imports(imports),
             ));
+            self.diagnostics.extend(Self::run_analyzer(
+                ast,
+                DeprecatedUsage::new(&self.declarations, Descriptor::from(package_name.clone())),
+            ));
+
+            let mut analyzer = UnusedInclude::new(
+                &self.declarations,
+                &self.includes,
+                Descriptor::from(package_name),
+            );
+            ast.visit(&mut analyzer);
+
+            self.references.extend(analyzer.drain_references());
+            self.diagnostics.extend(analyzer.drain_diagnostics());
         }
 
         // TODO: Add more analyzers (encoding validation, etc.)
@@ -220,3 +377,88 @@ impl From<Compiler> for ir::Schema {
         value.ir
     }
 }
+
+/* ----------------------- Impl: Into<GeneratorRequest> --------------------- */
+
+impl From<&Compiler> for GeneratorRequest {
+    fn from(value: &Compiler) -> Self {
+        let files = value
+            .requested
+            .iter()
+            .map(|import| {
+                let path = import.as_path();
+
+                value
+                    .import_roots
+                    .iter()
+                    .find_map(|root| path.strip_prefix(root.as_path()).ok())
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| path.to_owned())
+            })
+            .collect();
+
+        let mut packages: Vec<PackageName> = Vec::new();
+        for package in value.requested.iter().filter_map(|i| value.packages.get(i)) {
+            if !packages.contains(package) {
+                packages.push(package.clone());
+            }
+        }
+
+        Self {
+            files,
+            packages,
+            schema: value.ir.clone(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Fn: parse_schema                              */
+/* -------------------------------------------------------------------------- */
+
+/// `parse_schema` lexes and parses the `contents` of the schema `import`. The
+/// parsed AST is returned (possibly partial, if the parser recovered from
+/// syntax errors) alongside diagnostics for all lexing and syntax errors.
+pub fn parse_schema(
+    contents: &str,
+    import: &SchemaImport,
+) -> (Option<ast::Schema>, Vec<Diagnostic>) {
+    // Lex
+    let result = crate::lex::lex(contents, import.clone());
+    if !result.errors.is_empty() {
+        let errors = result
+            .errors
+            .into_iter()
+            .map(|e| {
+                Diagnostic::error(e.span().clone(), e.to_string()).with_code(Code::InvalidToken)
+            })
+            .collect();
+
+        return (None, errors);
+    }
+
+    let Some(tokens) = result.tokens else {
+        let error = Diagnostic::error(Span::default(), "lexing failed with no specific error")
+            .with_code(Code::InvalidToken);
+
+        return (None, vec![error]);
+    };
+
+    // Parse (recovering from syntax errors where possible).
+    let result = crate::parse::parse(&tokens, import.clone());
+
+    let mut errors = result
+        .errors
+        .into_iter()
+        .map(|e| Diagnostic::error(e.span().clone(), e.to_string()).with_code(Code::InvalidSyntax))
+        .collect::<Vec<_>>();
+
+    if result.ast.is_none() && errors.is_empty() {
+        errors.push(
+            Diagnostic::error(Span::default(), "parse failed with no specific error")
+                .with_code(Code::InvalidSyntax),
+        );
+    }
+
+    (result.ast, errors)
+}
@@ -0,0 +1,30 @@
+use crate::ast;
+use crate::lex::Span;
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: Declaration                            */
+/* -------------------------------------------------------------------------- */
+
+/// `Declaration` records where a type was declared along with any annotations
+/// attached to its definition.
+#[derive(Clone, Debug)]
+pub struct Declaration {
+    /// `annotations` attached to the type's definition.
+    pub annotations: Vec<ast::Annotation>,
+    /// `span` locates the type's name within its defining schema.
+    pub span: Span,
+}
+
+/* --------------------------- Impl: Declaration ---------------------------- */
+
+impl Declaration {
+    /// `deprecated` returns the type's `@deprecated` annotation, if any.
+    pub fn deprecated(&self) -> Option<&ast::Annotation> {
+        ast::Annotation::find_deprecated(&self.annotations)
+    }
+
+    /// `is_root` returns whether the type has been marked with `@root`.
+    pub fn is_root(&self) -> bool {
+        ast::Annotation::is_root(&self.annotations)
+    }
+}
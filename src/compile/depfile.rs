@@ -0,0 +1,143 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::write_if_changed;
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Depfile                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Depfile` records the files read and written by a compilation so that build
+/// systems (e.g. Make or Ninja) know when the generated bindings need to be
+/// rebuilt.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Depfile {
+    /// `inputs` contains every schema file read during compilation.
+    pub inputs: BTreeSet<PathBuf>,
+    /// `outputs` contains every file generated during compilation.
+    pub outputs: BTreeSet<PathBuf>,
+}
+
+/* ----------------------------- Impl: Depfile ------------------------------ */
+
+impl Depfile {
+    /// `touch` updates the modification time of every output to the current
+    /// time.
+    ///
+    /// NOTE: Unchanged outputs aren't rewritten, so without this they'd remain
+    /// older than the modified schemas they depend on and build systems which
+    /// compare modification times (e.g. Make) would rebuild them every time.
+    pub fn touch(&self) -> std::io::Result<()> {
+        let now = SystemTime::now();
+
+        for output in &self.outputs {
+            std::fs::File::options()
+                .write(true)
+                .open(output)?
+                .set_modified(now)?;
+        }
+
+        Ok(())
+    }
+
+    /// `write` writes the depfile, in Make format, to `path`.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        write_if_changed(path, &self.to_string()).map(|_| ())
+    }
+}
+
+/* ------------------------------ Impl: Display ----------------------------- */
+
+impl std::fmt::Display for Depfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outputs = self.outputs.iter().map(|p| escape(p)).collect::<Vec<_>>();
+
+        write!(f, "{}:", outputs.join(" "))?;
+
+        for input in &self.inputs {
+            write!(f, " \\\n  {}", escape(input))?;
+        }
+
+        writeln!(f)?;
+
+        // NOTE: Empty rules for each input (like `gcc -MP`) prevent Make from
+        // failing once a schema is deleted or no longer included.
+        for input in &self.inputs {
+            write!(f, "\n{}:\n", escape(input))?;
+        }
+
+        Ok(())
+    }
+}
+
+/* ------------------------------- Fn: escape ------------------------------- */
+
+/// `escape` formats `path` as a Make target or prerequisite, escaping the
+/// characters which are otherwise interpreted by Make.
+fn escape(path: &Path) -> String {
+    let mut escaped = String::new();
+
+    for c in path.to_string_lossy().chars() {
+        match c {
+            ' ' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '$' => escaped.push_str("$$"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ---------------------------- Tests: Depfile --------------------------- */
+
+    #[test]
+    fn test_depfile_display_lists_outputs_and_inputs() {
+        // Given: A depfile with multiple inputs and outputs.
+        let depfile = Depfile {
+            inputs: BTreeSet::from([
+                PathBuf::from("/in/a.baproto"),
+                PathBuf::from("/in/b.baproto"),
+            ]),
+            outputs: BTreeSet::from([PathBuf::from("/out/a.rs"), PathBuf::from("/out/b.rs")]),
+        };
+
+        // When: Formatting the depfile.
+        let contents = depfile.to_string();
+
+        // Then: Every output depends on every input.
+        assert_eq!(
+            contents,
+            "/out/a.rs /out/b.rs: \\\n  /in/a.baproto \\\n  /in/b.baproto\n\n/in/a.baproto:\n\n/in/b.baproto:\n"
+        );
+    }
+
+    #[test]
+    fn test_depfile_display_escapes_special_characters() {
+        // Given: A depfile with paths containing special characters.
+        let depfile = Depfile {
+            inputs: BTreeSet::from([PathBuf::from("/in/my schema#1.baproto")]),
+            outputs: BTreeSet::from([PathBuf::from("/out/$a.rs")]),
+        };
+
+        // When: Formatting the depfile.
+        let contents = depfile.to_string();
+
+        // Then: The special characters are escaped.
+        assert_eq!(
+            contents,
+            "/out/$$a.rs: \\\n  /in/my\\ schema\\#1.baproto\n\n/in/my\\ schema\\#1.baproto:\n"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::ast;
+use crate::core::SchemaImport;
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: IncludeGraph                            */
+/* -------------------------------------------------------------------------- */
+
+/// `IncludeGraph` records the resolved `include` statements of each compiled
+/// schema, forming a (possibly cyclic) dependency graph between schemas.
+#[derive(Clone, Debug, Default)]
+pub struct IncludeGraph {
+    edges: HashMap<SchemaImport, Vec<(ast::Include, SchemaImport)>>,
+}
+
+/* --------------------------- Impl: IncludeGraph --------------------------- */
+
+impl IncludeGraph {
+    /// `insert` records that `from` includes `to` via the `include` statement.
+    pub fn insert(&mut self, from: SchemaImport, include: ast::Include, to: SchemaImport) {
+        self.edges.entry(from).or_default().push((include, to));
+    }
+
+    /// `includes` returns the resolved `include` statements of `from`.
+    pub fn includes(&self, from: &SchemaImport) -> &[(ast::Include, SchemaImport)] {
+        self.edges.get(from).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// `closure` returns `from` along with every schema it transitively
+    /// includes.
+    pub fn closure(&self, from: &SchemaImport) -> HashSet<SchemaImport> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![from.clone()];
+
+        while let Some(import) = stack.pop() {
+            if !visited.insert(import.clone()) {
+                continue;
+            }
+
+            stack.extend(self.includes(&import).iter().map(|(_, to)| to.clone()));
+        }
+
+        visited
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lex::Span;
+
+    /* ---------------------------- Tests: closure -------------------------- */
+
+    #[test]
+    fn test_closure_follows_transitive_includes() {
+        // Given: A graph where 'a' includes 'b', which includes 'c'.
+        let (a, b, c) = (import("a"), import("b"), import("c"));
+
+        let mut graph = IncludeGraph::default();
+        graph.insert(a.clone(), include("b"), b.clone());
+        graph.insert(b.clone(), include("c"), c.clone());
+
+        // When: Computing the closure of 'b'.
+        let closure = graph.closure(&b);
+
+        // Then: Only 'b' and its transitive dependencies are included.
+        assert_eq!(closure, HashSet::from([b, c]));
+    }
+
+    #[test]
+    fn test_closure_handles_cycles() {
+        // Given: A graph where 'a' and 'b' include each other.
+        let (a, b) = (import("a"), import("b"));
+
+        let mut graph = IncludeGraph::default();
+        graph.insert(a.clone(), include("b"), b.clone());
+        graph.insert(b.clone(), include("a"), a.clone());
+
+        // When: Computing the closure of 'a'.
+        let closure = graph.closure(&a);
+
+        // Then: Both schemas are visited exactly once.
+        assert_eq!(closure, HashSet::from([a, b]));
+    }
+
+    /* ---------------------------- Fn: import ------------------------------ */
+
+    fn import(name: &str) -> SchemaImport {
+        SchemaImport::named(name)
+    }
+
+    /* ---------------------------- Fn: include ----------------------------- */
+
+    fn include(name: &str) -> ast::Include {
+        ast::Include {
+            inline_comment: None,
+            path: std::path::PathBuf::from(name),
+            span: Span::default(),
+        }
+    }
+}
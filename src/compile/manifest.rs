@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use super::output::{normalize, resolves_within};
+use super::write_if_changed;
+
+/* -------------------------------------------------------------------------- */
+/*                            Enum: ManifestError                             */
+/* -------------------------------------------------------------------------- */
+
+/// Errors that can occur while reading a generated-files manifest.
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    /// The manifest could not be read.
+    #[error("failed to read '{path}': {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The manifest is not valid.
+    #[error("invalid manifest '{path}' (delete it to regenerate): {source}")]
+    Invalid {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Struct: Manifest                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Manifest` records the files written into an output directory by each
+/// generator, along with a hash of their contents. It's used to remove stale
+/// outputs without touching files which weren't generated.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Manifest {
+    /// `version` is the version of the manifest format.
+    pub version: u32,
+    /// `generators` maps the name of each generator to the files it wrote
+    /// (relative to the output directory) and their content hashes.
+    pub generators: BTreeMap<String, BTreeMap<PathBuf, String>>,
+}
+
+/* ----------------------------- Impl: Manifest ----------------------------- */
+
+impl Manifest {
+    /// `load` reads the manifest within `out_dir`, returning an empty
+    /// manifest if none exists.
+    pub fn load<P: AsRef<Path>>(out_dir: P) -> Result<Self, ManifestError> {
+        let path = out_dir.as_ref().join(MANIFEST_FILE_NAME);
+
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => return Err(ManifestError::Io { path, source }),
+        };
+
+        serde_json::from_str(&contents).map_err(|source| ManifestError::Invalid { path, source })
+    }
+
+    /// `save` writes the manifest into `out_dir`.
+    pub fn save<P: AsRef<Path>>(&self, out_dir: P) -> std::io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        contents.push('\n');
+
+        write_if_changed(out_dir.as_ref().join(MANIFEST_FILE_NAME), &contents).map(|_| ())
+    }
+
+    /// `update` records `files` as the outputs of `generator`, returning the
+    /// previously recorded outputs (and their hashes) which are no longer
+    /// generated.
+    pub fn update(&mut self, generator: &str, files: &[(PathBuf, &str)]) -> Vec<(PathBuf, String)> {
+        let next = files
+            .iter()
+            .map(|(path, contents)| (path.clone(), hash(contents.as_bytes())))
+            .collect::<BTreeMap<_, _>>();
+
+        let previous = self
+            .generators
+            .insert(generator.to_owned(), next)
+            .unwrap_or_default();
+
+        let current = &self.generators[generator];
+
+        previous
+            .into_iter()
+            .filter(|(path, _)| !current.contains_key(path))
+            .collect()
+    }
+}
+
+/* ------------------------------ Impl: Default ----------------------------- */
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            generators: BTreeMap::new(),
+        }
+    }
+}
+
+/* ----------------------------- Fn: remove_stale ---------------------------- */
+
+/// `remove_stale` deletes the `stale` outputs recorded in a manifest from
+/// `out_dir`, along with any directories left empty. Files which were
+/// modified since they were generated are left in place and reported.
+pub fn remove_stale<P: AsRef<Path>>(
+    out_dir: P,
+    stale: Vec<(PathBuf, String)>,
+) -> std::io::Result<()> {
+    let out_dir = out_dir.as_ref();
+
+    for (path, expected) in stale {
+        // NOTE: Manifests may have been edited, and directories replaced by
+        // symbolic links; never delete outside `out_dir`.
+        let Ok(path) = normalize(&path) else {
+            continue;
+        };
+
+        if !resolves_within(out_dir, &path)? {
+            continue;
+        }
+
+        let full = out_dir.join(&path);
+
+        let contents = match std::fs::read(&full) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+
+        if hash(&contents) != expected {
+            eprintln!(
+                "Warning: not removing stale output '{}': modified since it was generated",
+                full.display()
+            );
+            continue;
+        }
+
+        std::fs::remove_file(&full)?;
+
+        for dir in path.ancestors().skip(1) {
+            if dir.as_os_str().is_empty() || std::fs::remove_dir(out_dir.join(dir)).is_err() {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/* --------------------------------- Fn: hash -------------------------------- */
+
+/// `hash` returns the content hash of `contents` recorded in a [`Manifest`].
+fn hash(contents: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(contents))
+}
+
+/* ------------------------- Const: MANIFEST_FILE_NAME ----------------------- */
+
+/// `MANIFEST_FILE_NAME` is the name of the manifest within an output
+/// directory.
+pub const MANIFEST_FILE_NAME: &str = ".baproto-manifest.json";
+
+/* -------------------------- Const: MANIFEST_VERSION ------------------------ */
+
+/// `MANIFEST_VERSION` is the current version of the manifest format.
+const MANIFEST_VERSION: u32 = 1;
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /* --------------------------- Tests: Manifest --------------------------- */
+
+    #[test]
+    fn test_manifest_update_returns_stale_outputs() {
+        // Given: A manifest recording the outputs of two generators.
+        let mut manifest = Manifest::default();
+        manifest.update(
+            "rust",
+            &[(PathBuf::from("a.rs"), "a"), (PathBuf::from("b.rs"), "b")],
+        );
+        manifest.update("cpp", &[(PathBuf::from("c.h"), "c")]);
+
+        // When: One generator no longer produces one of its outputs.
+        let stale = manifest.update("rust", &[(PathBuf::from("a.rs"), "changed")]);
+
+        // Then: Only that output is stale.
+        assert_eq!(stale, vec![(PathBuf::from("b.rs"), hash(b"b"))]);
+        assert_eq!(manifest.generators["rust"].len(), 1);
+        assert_eq!(manifest.generators["cpp"].len(), 1);
+    }
+
+    #[test]
+    fn test_manifest_save_and_load_round_trip() {
+        // Given: A manifest recording an output.
+        let dir = TempDir::new().unwrap();
+        let mut manifest = Manifest::default();
+        manifest.update("rust", &[(PathBuf::from("a/b.rs"), "contents")]);
+
+        // When: Saving and then loading the manifest.
+        manifest.save(dir.path()).unwrap();
+        let loaded = Manifest::load(dir.path()).unwrap();
+
+        // Then: The loaded manifest is unchanged.
+        assert_eq!(loaded, manifest);
+    }
+
+    #[test]
+    fn test_manifest_load_missing_is_empty() {
+        // Given: An output directory without a manifest.
+        let dir = TempDir::new().unwrap();
+
+        // When: Loading the manifest.
+        let manifest = Manifest::load(dir.path()).unwrap();
+
+        // Then: The manifest is empty.
+        assert_eq!(manifest, Manifest::default());
+    }
+
+    /* ------------------------- Tests: remove_stale ------------------------- */
+
+    #[test]
+    fn test_remove_stale_deletes_unmodified_outputs() {
+        // Given: A stale output within a nested directory.
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("a/b")).unwrap();
+        std::fs::write(dir.path().join("a/b/c.rs"), "generated").unwrap();
+        std::fs::write(dir.path().join("keep.rs"), "user").unwrap();
+
+        // When: Removing the stale output.
+        let stale = vec![(PathBuf::from("a/b/c.rs"), hash(b"generated"))];
+        remove_stale(dir.path(), stale).unwrap();
+
+        // Then: The file and its empty parent directories are removed.
+        assert!(!dir.path().join("a").exists());
+        assert!(dir.path().join("keep.rs").exists());
+    }
+
+    #[test]
+    fn test_remove_stale_keeps_modified_outputs() {
+        // Given: A stale output which was modified after being generated.
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "edited").unwrap();
+
+        // When: Removing the stale output.
+        let stale = vec![(PathBuf::from("a.rs"), hash(b"generated"))];
+        remove_stale(dir.path(), stale).unwrap();
+
+        // Then: The file is kept.
+        assert!(dir.path().join("a.rs").exists());
+    }
+
+    #[test]
+    fn test_remove_stale_ignores_paths_outside_out_dir() {
+        // Given: A manifest entry pointing outside of the output directory.
+        let parent = TempDir::new().unwrap();
+        let out_dir = parent.path().join("out");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        std::fs::write(parent.path().join("victim.rs"), "generated").unwrap();
+
+        // When: Removing the stale output.
+        let stale = vec![(PathBuf::from("../victim.rs"), hash(b"generated"))];
+        remove_stale(&out_dir, stale).unwrap();
+
+        // Then: The file is kept.
+        assert!(parent.path().join("victim.rs").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_stale_ignores_paths_within_symlinked_directories() {
+        // Given: A stale output whose directory was replaced by a link to
+        // another directory.
+        let dir = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        std::fs::write(outside.path().join("a.rs"), "generated").unwrap();
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("link")).unwrap();
+
+        // When: Removing the stale output.
+        let stale = vec![(PathBuf::from("link/a.rs"), hash(b"generated"))];
+        remove_stale(dir.path(), stale).unwrap();
+
+        // Then: The file outside of the output directory is kept.
+        assert!(outside.path().join("a.rs").exists());
+    }
+}
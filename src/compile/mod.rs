@@ -1,13 +1,27 @@
 use anyhow::anyhow;
 use std::path::Path;
 use std::path::PathBuf;
-
-use crate::analyze::DiagnosticReporter;
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use crate::analyze::Diagnostic;
+use crate::analyze::DiagnosticsFormat;
+use crate::analyze::Keywords;
+use crate::analyze::suppress;
+use crate::core::FileSystem;
 use crate::core::ImportRoot;
+use crate::core::OsFileSystem;
 use crate::core::SchemaImport;
-use crate::generate::Generator;
+use crate::generate::GeneratorOutput;
+use crate::generate::GeneratorRequest;
 use crate::ir;
 
+/* ------------------------------ Mod: Codegen ------------------------------ */
+
+mod codegen;
+#[allow(unused_imports)]
+pub use codegen::*;
+
 /* ------------------------------ Mod: Collect ------------------------------ */
 
 mod collect;
@@ -18,6 +32,36 @@ pub use collect::*;
 mod compiler;
 pub use compiler::*;
 
+/* ---------------------------- Mod: Declaration ---------------------------- */
+
+mod declaration;
+pub use declaration::*;
+
+/* ------------------------------ Mod: Depfile ------------------------------ */
+
+mod depfile;
+pub use depfile::*;
+
+/* ------------------------------ Mod: Include ------------------------------ */
+
+mod include;
+pub use include::*;
+
+/* ------------------------------ Mod: Manifest ----------------------------- */
+
+mod manifest;
+pub use manifest::*;
+
+/* ------------------------------ Mod: Options ------------------------------ */
+
+mod options;
+pub use options::*;
+
+/* ------------------------------- Mod: Output ------------------------------ */
+
+mod output;
+pub use output::*;
+
 /* ------------------------------- Mod: Source ------------------------------ */
 
 mod source;
@@ -28,118 +72,769 @@ pub use source::*;
 mod symbol;
 pub use symbol::*;
 
+/* ------------------------------- Mod: Target ------------------------------ */
+
+mod target;
+pub use target::*;
+
+/* ------------------------------- Mod: Watch ------------------------------- */
+
+mod watch;
+pub use watch::*;
+
+/* -------------------------------------------------------------------------- */
+/*                                  Fn: check                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `check` validates the provided input schema `files` without generating any
+/// target language bindings. Schema imports will be searched for within
+/// `import_roots`. Optional behavior (e.g. lint configuration) is controlled
+/// by `options`.
+pub fn check<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    options: CompileOptions,
+) -> anyhow::Result<()> {
+    check_in(OsFileSystem, files, import_roots, options)
+}
+
+/// `check_in` behaves like [`check`], but reads all schemas from `fs`.
+pub fn check_in<F: FileSystem + 'static, P: AsRef<Path>>(
+    fs: F,
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    options: CompileOptions,
+) -> anyhow::Result<()> {
+    run_compiler(Rc::new(fs), &files, &import_roots, options).map(|_| ())
+}
+
 /* -------------------------------------------------------------------------- */
 /*                                 Fn: compile                                */
 /* -------------------------------------------------------------------------- */
 
-/// `compile` compiles the provided input schema `files` into the specified
-/// `out` directory. Schema imports will be searched for within `import_roots`;
-/// target language bindings will be generated using `generator`.
-pub fn compile<P: AsRef<Path>, G: Generator>(
+/// `compile` compiles the provided input schema `files` and then generates
+/// target language bindings for each of the `targets`. Schema imports will be
+/// searched for within `import_roots`. Optional behavior (e.g. lint
+/// configuration) is controlled by `options`.
+///
+/// The returned [`Depfile`] records every schema read and every file
+/// generated, for integration with build systems.
+///
+/// NOTE: Schemas are compiled once; all generators share the same
+/// [`ir::Schema`] and run in parallel.
+#[allow(unused)]
+pub fn compile<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    targets: Vec<GeneratorTarget>,
+    options: CompileOptions,
+) -> anyhow::Result<Depfile> {
+    compile_in(OsFileSystem, files, import_roots, targets, options)
+}
+
+/// `compile_in` behaves like [`compile`], but reads all schemas from `fs`.
+/// Generated bindings are still written to the local disk.
+#[allow(unused)]
+pub fn compile_in<F: FileSystem + 'static, P: AsRef<Path>>(
+    fs: F,
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    targets: Vec<GeneratorTarget>,
+    options: CompileOptions,
+) -> anyhow::Result<Depfile> {
+    let groups = vec![TargetGroup::new(options, targets)];
+
+    compile_groups_in(Rc::new(fs), &files, &import_roots, &groups)
+}
+
+/// `compile_groups` behaves like [`compile`], but generates bindings for each
+/// of the target `groups` with the group's own options. Schemas are still
+/// compiled once, and the diagnostics of all groups are reported together.
+/// All groups must share the same diagnostics format.
+pub fn compile_groups<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    groups: Vec<TargetGroup>,
+) -> anyhow::Result<Depfile> {
+    compile_groups_in(Rc::new(OsFileSystem), &files, &import_roots, &groups)
+}
+
+/// `compile_groups_in` behaves like [`compile_groups`], but reads all schemas
+/// from `fs`.
+fn compile_groups_in<P: AsRef<Path>>(
+    fs: Rc<dyn FileSystem>,
+    files: &[P],
+    import_roots: &[P],
+    groups: &[TargetGroup],
+) -> anyhow::Result<Depfile> {
+    let format = diagnostics_format(groups)?;
+    let out_dirs = parse_group_out_dirs(groups)?;
+
+    let compiler = load_compiler(fs, files, import_roots, groups, format)?;
+
+    let outputs = generate(&compiler, groups, &out_dirs, format, &write_output)?;
+
+    Ok(Depfile {
+        inputs: compiler
+            .sources
+            .files()
+            .filter(|f| !f.is_stdin())
+            .map(|f| f.as_path().to_owned())
+            .collect(),
+        outputs: outputs.into_iter().collect(),
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Fn: verify                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `verify` behaves like [`compile`], but instead of writing the generated
+/// bindings it compares them against the files within each target's output
+/// directory. A unified diff is printed for each file which differs, along
+/// with the paths of missing files and of previously generated files which
+/// are no longer generated. An error is returned if any file is out of date.
+#[allow(unused)]
+pub fn verify<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    targets: Vec<GeneratorTarget>,
+    options: CompileOptions,
+) -> anyhow::Result<()> {
+    verify_groups(
+        files,
+        import_roots,
+        vec![TargetGroup::new(options, targets)],
+    )
+}
+
+/// `verify_groups` behaves like [`verify`], but checks the bindings of each of
+/// the target `groups` as generated with the group's own options (see
+/// [`compile_groups`]).
+pub fn verify_groups<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    groups: Vec<TargetGroup>,
+) -> anyhow::Result<()> {
+    let format = diagnostics_format(&groups)?;
+    let out_dirs = parse_group_out_dirs(&groups)?;
+
+    let compiler = load_compiler(
+        Rc::new(OsFileSystem),
+        &files,
+        &import_roots,
+        &groups,
+        format,
+    )?;
+
+    let emit = |name: &str, output: &GeneratorOutput, out_dir: &Path| {
+        verify_output(name, output, out_dir, format)
+    };
+
+    generate(&compiler, &groups, &out_dirs, format, &emit).map(|_| ())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  Fn: watch                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `watch` behaves like [`compile`], but recompiles whenever one of the input
+/// schema `files` or the schemas they (transitively) import changes. Failed
+/// compilations are reported without modifying previously generated files.
+///
+/// NOTE: This function only returns if the initial arguments are invalid.
+pub fn watch<P: AsRef<Path>>(
     files: Vec<P>,
     import_roots: Vec<P>,
-    out: Option<P>,
-    generator: G,
+    targets: Vec<GeneratorTarget>,
+    options: CompileOptions,
 ) -> anyhow::Result<()> {
-    let out_dir = parse_out_dir(out)?;
-    let import_roots = parse_import_roots(import_roots)?;
+    let format = options.diagnostics_format;
+    let groups = [TargetGroup::new(options, targets)];
+    let out_dirs = parse_group_out_dirs(&groups)?;
+
+    if files.iter().any(|path| is_stdin(path.as_ref())) {
+        return Err(anyhow!(
+            "invalid argument: can't watch schemas read from stdin ('-')"
+        ));
+    }
+
+    loop {
+        let started = SystemTime::now();
+
+        let mut watched = files
+            .iter()
+            .map(|path| path.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        let result = build_compiler(Rc::new(OsFileSystem), &files, &import_roots).and_then(
+            |mut compiler| {
+                watched.extend(compiler.sources.files().map(|f| f.as_path().to_owned()));
+
+                prepare_compiler(&mut compiler, &groups, format)?;
+                generate(&compiler, &groups, &out_dirs, format, &write_output).map(|_| ())
+            },
+        );
+
+        match result {
+            Ok(()) => eprintln!("Compilation succeeded; watching for changes..."),
+            Err(err) => eprintln!("Error: {}\nWatching for changes...", err),
+        }
+
+        Watcher::new(watched, started).wait();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  Fn: lower                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `lower` compiles the provided input schema `files` into the intermediate
+/// representation consumed by generators. Schema imports will be searched for
+/// within `import_roots`. Optional behavior (e.g. lint configuration) is
+/// controlled by `options`.
+#[allow(unused)]
+pub fn lower<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    options: CompileOptions,
+) -> anyhow::Result<ir::Schema> {
+    lower_in(OsFileSystem, files, import_roots, options)
+}
+
+/// `lower_in` behaves like [`lower`], but reads all schemas from `fs`.
+pub fn lower_in<F: FileSystem + 'static, P: AsRef<Path>>(
+    fs: F,
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    options: CompileOptions,
+) -> anyhow::Result<ir::Schema> {
+    run_compiler(Rc::new(fs), &files, &import_roots, options).map(ir::Schema::from)
+}
+
+/// `lower_request` behaves like [`lower`], but returns the request provided to
+/// generators (i.e. the schema along with the requested files and packages).
+pub fn lower_request<P: AsRef<Path>>(
+    files: Vec<P>,
+    import_roots: Vec<P>,
+    options: CompileOptions,
+) -> anyhow::Result<GeneratorRequest> {
+    run_compiler(Rc::new(OsFileSystem), &files, &import_roots, options)
+        .map(|compiler| GeneratorRequest::from(&compiler))
+}
+
+/* ----------------------------- Fn: run_compiler ---------------------------- */
+
+/// `run_compiler` compiles the provided input schema `files` within `fs`,
+/// reporting all diagnostics according to `options`. The [`Compiler`] is
+/// returned if no errors were found.
+fn run_compiler<P: AsRef<Path>>(
+    fs: Rc<dyn FileSystem>,
+    files: &[P],
+    import_roots: &[P],
+    options: CompileOptions,
+) -> anyhow::Result<Compiler> {
+    let format = options.diagnostics_format;
+    let groups = [TargetGroup::new(options, vec![])];
+
+    let compiler = load_compiler(fs, files, import_roots, &groups, format)?;
+
+    report(&compiler.sources, &compiler.diagnostics, format)?;
+
+    Ok(compiler)
+}
+
+/* ---------------------------- Fn: load_compiler ---------------------------- */
+
+/// `load_compiler` compiles the provided input schema `files` within `fs` for
+/// each of the target `groups`. If any errors were found, all diagnostics are
+/// reported in `format` and an error is returned; otherwise, the [`Compiler`]
+/// is returned with its diagnostics left unreported so that they can be
+/// reported alongside those of the generators.
+fn load_compiler<P: AsRef<Path>>(
+    fs: Rc<dyn FileSystem>,
+    files: &[P],
+    import_roots: &[P],
+    groups: &[TargetGroup],
+    format: DiagnosticsFormat,
+) -> anyhow::Result<Compiler> {
+    let mut compiler = build_compiler(fs, files, import_roots)?;
+
+    prepare_compiler(&mut compiler, groups, format)?;
+
+    Ok(compiler)
+}
+
+/* ---------------------------- Fn: build_compiler --------------------------- */
+
+/// `build_compiler` compiles the provided input schema `files` within `fs`,
+/// returning the [`Compiler`] along with all unreported diagnostics which
+/// don't depend on compile options (see [`prepare`]). A file named `-` is
+/// read from stdin.
+fn build_compiler<P: AsRef<Path>>(
+    fs: Rc<dyn FileSystem>,
+    files: &[P],
+    import_roots: &[P],
+) -> anyhow::Result<Compiler> {
+    let import_roots = parse_import_roots(fs.as_ref(), import_roots)?;
 
     let inputs: Vec<SchemaImport> = files
-        .into_iter()
-        .map(|path| SchemaImport::try_from(path.as_ref()).map_err(|e| anyhow!(e)))
+        .iter()
+        .map(|path| match is_stdin(path.as_ref()) {
+            true => Ok(SchemaImport::stdin()),
+            false => SchemaImport::new_in(fs.as_ref(), path).map_err(|e| anyhow!(e)),
+        })
         .collect::<Result<Vec<_>, _>>()?;
 
-    let mut compiler = Compiler::new(import_roots.clone());
+    let mut compiler = CompilerBuilder::default()
+        .import_roots(import_roots)
+        .sources(SourceCache::new(fs))
+        .build()?;
 
     for schema in inputs {
         compiler.compile(schema);
     }
 
-    for diagnostic in &compiler.diagnostics {
-        if let Err(err) = compiler.sources.insert(&diagnostic.span.context) {
-            return Err(anyhow!("Failed to read source file: {}", err));
+    Ok(compiler)
+}
+
+/* -------------------------- Fn: prepare_compiler -------------------------- */
+
+/// `prepare_compiler` lints `compiler` for each of the target `groups` (see
+/// [`prepare`]). If any of the resulting diagnostics are errors, they're all
+/// reported in `format` and an error is returned.
+fn prepare_compiler(
+    compiler: &mut Compiler,
+    groups: &[TargetGroup],
+    format: DiagnosticsFormat,
+) -> anyhow::Result<()> {
+    prepare(compiler, groups).map_err(|err| anyhow!("Failed to read source file: {}", err))?;
+
+    if count_errors(&compiler.diagnostics) > 0 {
+        report(&compiler.sources, &compiler.diagnostics, format)?;
+    }
+
+    Ok(())
+}
+
+/* ------------------------------- Fn: report ------------------------------- */
+
+/// `report` reports `diagnostics` in `format`. An error is returned if any of
+/// the diagnostics are errors.
+fn report(
+    sources: &SourceCache,
+    diagnostics: &[Diagnostic],
+    format: DiagnosticsFormat,
+) -> anyhow::Result<()> {
+    format.reporter(sources).report(diagnostics)?;
+
+    let error_count = count_errors(diagnostics);
+
+    if error_count > 0 {
+        return Err(anyhow!("Compilation failed with {} error(s).", error_count));
+    }
+
+    Ok(())
+}
+
+/* ------------------------------ Fn: prepare ------------------------------- */
+
+/// `prepare` lints `compiler` with the options of each of the target `groups`
+/// and filters the diagnostics of each group according to the configured
+/// suppressions and the group's lint levels. The diagnostics of all groups are
+/// merged, with duplicates reported once, and the sources they refer to are
+/// loaded.
+fn prepare(compiler: &mut Compiler, groups: &[TargetGroup]) -> std::io::Result<()> {
+    let unlinted = std::mem::take(&mut compiler.diagnostics);
+
+    for group in groups {
+        let mut diagnostics = unlinted.clone();
+        diagnostics.extend(compiler.lint(&group.options, &keywords(&group.targets)));
+
+        let diagnostics = suppress(diagnostics, &compiler.suppressions);
+
+        for diagnostic in group.options.lints.apply(diagnostics) {
+            if !compiler.diagnostics.contains(&diagnostic) {
+                compiler.diagnostics.push(diagnostic);
+            }
         }
     }
 
-    if !compiler.diagnostics.is_empty() {
-        // TODO: Allow customize diagnostic reporting.
-        let reporter = DiagnosticReporter::new(&compiler.sources);
+    for diagnostic in compiler.diagnostics.iter().filter(|d| !d.is_spanless()) {
+        let spans = std::iter::once(&diagnostic.span)
+            .chain(diagnostic.labels.iter().map(|label| &label.span));
 
-        for diagnostic in &compiler.diagnostics {
-            reporter.report(diagnostic);
+        for span in spans {
+            compiler.sources.insert(&span.context)?;
         }
+    }
+
+    Ok(())
+}
+
+/* ---------------------------- Fn: count_errors ---------------------------- */
+
+/// `count_errors` returns the number of `diagnostics` which are errors.
+fn count_errors(diagnostics: &[Diagnostic]) -> usize {
+    diagnostics
+        .iter()
+        .filter(|d| matches!(d.severity, crate::analyze::Severity::Error))
+        .count()
+}
+
+/* ------------------------------ Fn: generate ------------------------------ */
+
+/// `generate` runs the targets of each of the `groups` on the successfully
+/// compiled schemas of `compiler`, passing their bindings to `emit` along with
+/// the corresponding `out_dirs`. The targets of each group run in parallel.
+/// The unreported diagnostics of `compiler` and those reported by the
+/// generators are reported together in `format`, and failures are reported
+/// for each generator. The paths of all generated files are returned.
+fn generate(
+    compiler: &Compiler,
+    groups: &[TargetGroup],
+    out_dirs: &[Vec<PathBuf>],
+    format: DiagnosticsFormat,
+    emit: Emit<'_>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let mut generation = Generation {
+        diagnostics: Vec::new(),
+        failures: Vec::new(),
+        outputs: Vec::new(),
+    };
+
+    for (group, out_dirs) in groups.iter().zip(out_dirs) {
+        let result = run_generators(compiler, &group.targets, out_dirs, &group.options, emit);
+
+        generation.diagnostics.extend(result.diagnostics);
+        generation.failures.extend(result.failures);
+        generation.outputs.extend(result.outputs);
+    }
+
+    // NOTE: Structured formats write a single document, so all diagnostics
+    // must be reported at once.
+    let diagnostics = compiler
+        .diagnostics
+        .iter()
+        .chain(&generation.diagnostics)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    format.reporter(&compiler.sources).report(&diagnostics)?;
+
+    for failure in &generation.failures {
+        eprintln!("Error: {}", failure);
+    }
+
+    if !generation.failures.is_empty() {
+        let target_count = groups.iter().map(|g| g.targets.len()).sum::<usize>();
+
+        return Err(anyhow!(
+            "Generation failed for {} of {} generator(s).",
+            generation.failures.len(),
+            target_count
+        ));
+    }
+
+    Ok(generation.outputs)
+}
 
-        let error_count = compiler
-            .diagnostics
+/* ---------------------------- Struct: Generation -------------------------- */
+
+/// `Generation` contains the results of running a set of generators.
+struct Generation {
+    /// `diagnostics` contains the diagnostics reported by the generators.
+    diagnostics: Vec<Diagnostic>,
+    /// `failures` describes each generator which failed.
+    failures: Vec<String>,
+    /// `outputs` contains the paths of all generated files.
+    outputs: Vec<PathBuf>,
+}
+
+/* ------------------------------- Type: Emit ------------------------------- */
+
+/// `Emit` handles the output of the named generator for an output directory,
+/// returning the paths of the generated files.
+type Emit<'a> = &'a dyn Fn(&str, &GeneratorOutput, &Path) -> anyhow::Result<Vec<PathBuf>>;
+
+/* --------------------------- Fn: run_generators --------------------------- */
+
+/// `run_generators` runs each of the `targets` in parallel on the successfully
+/// compiled schemas of `compiler`, passing their bindings to `emit` along with
+/// the corresponding `out_dirs`. Nothing is reported; instead, diagnostics and
+/// failures are collected into the returned [`Generation`].
+fn run_generators(
+    compiler: &Compiler,
+    targets: &[GeneratorTarget],
+    out_dirs: &[PathBuf],
+    options: &CompileOptions,
+    emit: Emit<'_>,
+) -> Generation {
+    let request = GeneratorRequest::from(compiler);
+
+    let results = std::thread::scope(|s| {
+        let handles = targets
             .iter()
-            .filter(|d| matches!(d.severity, crate::analyze::Severity::Error))
-            .count();
+            .map(|target| s.spawn(|| target.generator.generate(&request).map_err(|e| anyhow!(e))))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow!("generator panicked")))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut generation = Generation {
+        diagnostics: Vec::new(),
+        failures: Vec::new(),
+        outputs: Vec::new(),
+    };
+
+    for ((target, out_dir), result) in targets.iter().zip(out_dirs).zip(results) {
+        let name = target.generator.name();
+
+        let result = result.and_then(|output| {
+            let reported = options.lints.apply(
+                output
+                    .diagnostics
+                    .iter()
+                    .map(|d| compiler.diagnose(name, d))
+                    .collect(),
+            );
+
+            let error_count = count_errors(&reported);
+
+            generation.diagnostics.extend(reported);
+
+            if error_count > 0 {
+                return Err(anyhow!("reported {} error(s)", error_count));
+            }
+
+            emit(name, &output, out_dir)
+        });
+
+        match result {
+            Ok(paths) => generation.outputs.extend(paths),
+            Err(err) => generation
+                .failures
+                .push(format!("generator '{}' failed: {}", name, err)),
+        }
+    }
+
+    generation
+}
+
+/* ----------------------------- Fn: write_output ---------------------------- */
+
+/// `write_output` writes the files generated by the generator `name` in
+/// `output` into `out_dir`, creating it if missing. No files are written
+/// unless all of their paths are valid, and files whose contents are unchanged
+/// are left untouched. Files generated by a previous run which are no longer
+/// generated are removed. Returns the paths of the generated files.
+fn write_output(
+    name: &str,
+    output: &GeneratorOutput,
+    out_dir: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let files = validate_output(output)?;
+
+    std::fs::create_dir_all(out_dir)?;
+
+    for (path, _) in &files {
+        if !resolves_within(out_dir, path)? {
+            return Err(OutputError::EscapesOutDir(path.clone()).into());
+        }
+    }
+
+    let mut manifest = Manifest::load(out_dir)?;
+
+    for (path, contents) in &files {
+        write_if_changed(out_dir.join(path), contents)?;
+    }
+
+    let stale = manifest.update(name, &files);
+    manifest.save(out_dir)?;
+
+    remove_stale(out_dir, stale)?;
+
+    Ok(files
+        .into_iter()
+        .map(|(path, _)| out_dir.join(path))
+        .collect())
+}
 
-        if error_count > 0 {
-            return Err(anyhow!("Compilation failed with {} error(s).", error_count));
+/* ---------------------------- Fn: verify_output ---------------------------- */
+
+/// `verify_output` compares the files generated by the generator `name` in
+/// `output` against those within `out_dir`, printing the differences. Extra
+/// files are only detected if they were recorded in the output directory's
+/// [`Manifest`], so that files which weren't generated are never reported.
+/// Diffs refer to files relative to `out_dir`, so that they can be applied
+/// (e.g. via `git apply`) from within it. Differences are printed to stdout
+/// unless `format` writes its document there, in which case they're printed
+/// to stderr. Returns the paths of the generated files.
+fn verify_output(
+    name: &str,
+    output: &GeneratorOutput,
+    out_dir: &Path,
+    format: DiagnosticsFormat,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let files = validate_output(output)?;
+    let manifest = Manifest::load(out_dir)?;
+
+    let mut out: Box<dyn std::io::Write> = match format {
+        DiagnosticsFormat::Human => Box::new(std::io::stdout()),
+        DiagnosticsFormat::Json | DiagnosticsFormat::Sarif => Box::new(std::io::stderr()),
+    };
+
+    let mut mismatches = 0;
+
+    for (path, contents) in &files {
+        let full = out_dir.join(path);
+
+        match std::fs::read_to_string(&full) {
+            Ok(existing) if existing == *contents => {}
+            Ok(existing) => {
+                write!(out, "{}", unified_diff(path, &existing, contents))?;
+                mismatches += 1;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                writeln!(out, "Missing generated file: {}", full.display())?;
+                mismatches += 1;
+            }
+            Err(e) => return Err(e.into()),
         }
     }
 
-    let ir = ir::Schema::from(compiler);
+    let previous = manifest
+        .generators
+        .get(name)
+        .into_iter()
+        .flat_map(|f| f.keys());
 
-    let output = generator.generate(&ir).map_err(|e| anyhow!(e))?;
+    for path in previous {
+        let is_generated = files.iter().any(|(p, _)| p == path);
+        let full = out_dir.join(path);
 
-    for (path, contents) in &output.files {
-        let path = out_dir.join(path);
-        write_generated_file(&path, contents)?;
+        if !is_generated && full.is_file() {
+            writeln!(out, "Extra generated file: {}", full.display())?;
+            mismatches += 1;
+        }
     }
 
-    Ok(())
+    if mismatches > 0 {
+        return Err(anyhow!("{} generated file(s) are out of date", mismatches));
+    }
+
+    Ok(files
+        .into_iter()
+        .map(|(path, _)| out_dir.join(path))
+        .collect())
+}
+
+/* ------------------------------ Fn: keywords ------------------------------ */
+
+/// `keywords` returns the reserved words of each language targeted by
+/// `targets`.
+fn keywords(targets: &[GeneratorTarget]) -> Vec<Keywords> {
+    targets
+        .iter()
+        .map(|target| Keywords {
+            language: target.generator.name().to_owned(),
+            words: target.generator.keywords().into_iter().collect(),
+        })
+        .collect()
 }
 
 /* ---------------------------- Fn: parse_out_dir --------------------------- */
 
 /// `parse_out_dir` accepts an optional output directory and returns the
-/// directory in which generated artifacts should be written.
+/// absolute directory in which generated artifacts should be written. Missing
+/// directories aren't created until artifacts are written.
 fn parse_out_dir<P: AsRef<Path>>(out_dir: Option<P>) -> anyhow::Result<PathBuf> {
-    let path: PathBuf;
+    let Some(directory) = out_dir else {
+        return Ok(std::env::current_dir()?.canonicalize()?);
+    };
 
-    if let Some(directory) = out_dir {
-        if !directory.as_ref().is_dir() {
-            return Err(anyhow!("invalid argument: expected a directory for 'out'"));
-        }
+    let path = directory.as_ref();
+
+    if !path.exists() {
+        return Ok(std::env::current_dir()?.join(path));
+    }
 
-        path = directory.as_ref().to_owned()
-    } else {
-        path = std::env::current_dir()?;
+    if !path.is_dir() {
+        return Err(anyhow!("invalid argument: expected a directory for 'out'"));
     }
 
     Ok(path.canonicalize()?)
 }
 
+/* --------------------------- Fn: parse_out_dirs --------------------------- */
+
+/// `parse_out_dirs` returns the directory in which each of the `targets`
+/// writes its generated artifacts.
+fn parse_out_dirs(targets: &[GeneratorTarget]) -> anyhow::Result<Vec<PathBuf>> {
+    targets
+        .iter()
+        .map(|target| parse_out_dir(target.out.as_ref()))
+        .collect()
+}
+
+/* ------------------------ Fn: parse_group_out_dirs ------------------------ */
+
+/// `parse_group_out_dirs` returns the directory in which each of the targets
+/// of each of the `groups` writes its generated artifacts.
+fn parse_group_out_dirs(groups: &[TargetGroup]) -> anyhow::Result<Vec<Vec<PathBuf>>> {
+    groups
+        .iter()
+        .map(|group| parse_out_dirs(&group.targets))
+        .collect()
+}
+
+/* ------------------------- Fn: diagnostics_format ------------------------- */
+
+/// `diagnostics_format` returns the format in which the diagnostics of all
+/// `groups` are reported; an error is returned if the groups disagree.
+fn diagnostics_format(groups: &[TargetGroup]) -> anyhow::Result<DiagnosticsFormat> {
+    let mut formats = groups.iter().map(|group| group.options.diagnostics_format);
+    let format = formats.next().unwrap_or_default();
+
+    if formats.any(|f| f != format) {
+        return Err(anyhow!(
+            "invalid argument: all targets must share the same diagnostics format"
+        ));
+    }
+
+    Ok(format)
+}
+
 /* ------------------------- Fn: parse_import_roots ------------------------- */
 
 /// `parse_import_roots` validates and canonicalizes the import root
-/// directories. If no roots are provided, defaults to the current working
-/// directory.
-fn parse_import_roots<P: AsRef<Path>>(roots: Vec<P>) -> anyhow::Result<Vec<ImportRoot>> {
+/// directories within `fs`. If no roots are provided, defaults to the current
+/// working directory.
+fn parse_import_roots<P: AsRef<Path>>(
+    fs: &dyn FileSystem,
+    roots: &[P],
+) -> anyhow::Result<Vec<ImportRoot>> {
     if roots.is_empty() {
-        let cwd = std::env::current_dir()?;
-        return Ok(vec![ImportRoot::try_from(cwd).map_err(|e| anyhow!(e))?]);
+        let cwd = fs.current_dir()?;
+        return Ok(vec![ImportRoot::new_in(fs, cwd).map_err(|e| anyhow!(e))?]);
     }
 
     roots
-        .into_iter()
-        .map(|root| ImportRoot::try_from(root.as_ref()).map_err(|e| anyhow!(e)))
+        .iter()
+        .map(|root| ImportRoot::new_in(fs, root).map_err(|e| anyhow!(e)))
         .collect()
 }
 
-/* ------------------------ Fn: write_generated_file ------------------------ */
-
-/// `write_generated_file` writes the specified `contents`` to the provided
-/// `path`, creating any intermediate directories as needed.
-fn write_generated_file<T: AsRef<Path>, U: AsRef<str>>(
-    path: T,
-    contents: U,
-) -> std::io::Result<()> {
-    if let Some(parent) = path.as_ref().parent() {
-        std::fs::create_dir_all(parent)?;
-    }
+/* ------------------------------ Fn: is_stdin ------------------------------ */
 
-    std::fs::write(path.as_ref(), contents.as_ref())
+/// `is_stdin` returns whether the input schema `path` refers to stdin.
+fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
 }
@@ -0,0 +1,25 @@
+use derive_builder::Builder;
+
+use crate::analyze::DiagnosticsFormat;
+use crate::analyze::LintLevels;
+use crate::analyze::NamingRules;
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: CompileOptions                           */
+/* -------------------------------------------------------------------------- */
+
+/// `CompileOptions` configures optional compilation behavior, such as which
+/// lints are enforced and how.
+#[derive(Builder, Clone, Debug, Default, PartialEq)]
+#[builder(default)]
+pub struct CompileOptions {
+    /// `diagnostics_format` selects how diagnostics are reported.
+    pub diagnostics_format: DiagnosticsFormat,
+    /// `lints` configures the level at which warnings are reported.
+    pub lints: LintLevels,
+    /// `naming` configures the naming conventions enforced on declarations.
+    pub naming: NamingRules,
+    /// `warn_unused_types` enables warnings for types which are never
+    /// referenced and aren't marked with `@root`.
+    pub warn_unused_types: bool,
+}
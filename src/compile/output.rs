@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+
+use crate::generate::GeneratorOutput;
+
+use super::MANIFEST_FILE_NAME;
+
+/* -------------------------------------------------------------------------- */
+/*                             Enum: OutputError                              */
+/* -------------------------------------------------------------------------- */
+
+/// Errors that can occur while validating the files produced by a generator.
+#[derive(Error, Debug, PartialEq)]
+pub enum OutputError {
+    /// An output path is absolute (or otherwise not relative).
+    #[error("invalid output path '{0}': must be relative")]
+    NotRelative(PathBuf),
+
+    /// An output path doesn't name a file.
+    #[error("invalid output path '{0}': must name a file")]
+    Empty(PathBuf),
+
+    /// An output path resolves to a location outside the output directory.
+    #[error("invalid output path '{0}': escapes the output directory")]
+    EscapesOutDir(PathBuf),
+
+    /// An output path refers to a file reserved by the compiler.
+    #[error("invalid output path '{0}': reserved for the generated-files manifest")]
+    Reserved(PathBuf),
+
+    /// Two output paths refer to the same file on case-insensitive file
+    /// systems.
+    #[error("invalid output path '{0}': conflicts with '{1}'")]
+    Duplicate(PathBuf, PathBuf),
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Fn: validate_output                             */
+/* -------------------------------------------------------------------------- */
+
+/// `validate_output` checks that every file in `output` can be safely written
+/// into an output directory, returning the normalized relative path of each
+/// file alongside its contents.
+pub fn validate_output(output: &GeneratorOutput) -> Result<Vec<(PathBuf, &str)>, OutputError> {
+    let mut files = output.files.iter().collect::<Vec<_>>();
+    files.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut seen: HashMap<String, &Path> = HashMap::new();
+    let mut validated = Vec::with_capacity(files.len());
+
+    for (path, contents) in files {
+        let normalized = normalize(path)?;
+        let key = normalized.to_string_lossy().to_lowercase();
+
+        if key == MANIFEST_FILE_NAME {
+            return Err(OutputError::Reserved(path.clone()));
+        }
+
+        if let Some(existing) = seen.get(&key) {
+            return Err(OutputError::Duplicate(path.clone(), existing.to_path_buf()));
+        }
+
+        seen.insert(key, path);
+        validated.push((normalized, contents.as_str()));
+    }
+
+    Ok(validated)
+}
+
+/* ------------------------------- Fn: normalize ----------------------------- */
+
+/// `normalize` lexically resolves `.` and `..` components of the relative
+/// output `path`, rejecting paths which don't stay within the output
+/// directory.
+pub(super) fn normalize(path: &Path) -> Result<PathBuf, OutputError> {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir => {
+                return Err(OutputError::NotRelative(path.to_owned()));
+            }
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return Err(OutputError::EscapesOutDir(path.to_owned()));
+                }
+            }
+            Component::Normal(name) => normalized.push(name),
+        }
+    }
+
+    if normalized.as_os_str().is_empty() {
+        return Err(OutputError::Empty(path.to_owned()));
+    }
+
+    Ok(normalized)
+}
+
+/* ---------------------------- Fn: resolves_within -------------------------- */
+
+/// `resolves_within` returns whether the directory containing the relative
+/// output `path` resolves to a location within `out_dir`, i.e. that it doesn't
+/// escape the output directory via symbolic links.
+pub(super) fn resolves_within<T: AsRef<Path>>(out_dir: T, path: &Path) -> std::io::Result<bool> {
+    let out_dir = out_dir.as_ref().canonicalize()?;
+
+    let mut dir = out_dir.join(path);
+    dir.pop();
+
+    // NOTE: Missing directories are created when writing the file, so only the
+    // nearest existing ancestor can be a symbolic link.
+    while std::fs::symlink_metadata(&dir).is_err() && dir.pop() {}
+
+    Ok(dir.canonicalize()?.starts_with(&out_dir))
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Fn: write_if_changed                             */
+/* -------------------------------------------------------------------------- */
+
+/// `write_if_changed` atomically replaces the file at `path` with `contents`,
+/// creating any intermediate directories as needed. Files which already
+/// contain `contents` aren't modified, so that their modification time is
+/// preserved. Returns whether the file was written.
+pub fn write_if_changed<T: AsRef<Path>>(path: T, contents: &str) -> std::io::Result<bool> {
+    let path = path.as_ref();
+
+    if std::fs::read(path).is_ok_and(|existing| existing == contents.as_bytes()) {
+        return Ok(false);
+    }
+
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    std::fs::create_dir_all(dir)?;
+
+    let mut builder = tempfile::Builder::new();
+
+    // NOTE: Unlike `NamedTempFile::new`, which restricts the file to its owner,
+    // new files are created with the same permissions as `File::create` (i.e.
+    // subject to the umask).
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o666));
+    }
+
+    // NOTE: The temporary file is created alongside the destination so that
+    // the rename never crosses file systems.
+    let mut file = builder.tempfile_in(dir)?;
+    file.write_all(contents.as_bytes())?;
+
+    // NOTE: Replaced files retain their permissions.
+    if let Ok(metadata) = std::fs::metadata(path) {
+        file.as_file().set_permissions(metadata.permissions())?;
+    }
+
+    file.persist(path).map_err(|e| e.error)?;
+
+    Ok(true)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Fn: unified_diff                              */
+/* -------------------------------------------------------------------------- */
+
+/// `unified_diff` returns a unified diff which transforms the `old` contents of
+/// the file at the relative `path` into the `new` contents.
+pub fn unified_diff<T: AsRef<Path>>(path: T, old: &str, new: &str) -> String {
+    let path = path.as_ref().display();
+
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /* ------------------------- Tests: validate_output ---------------------- */
+
+    #[test]
+    fn test_validate_output_normalizes_paths() {
+        // Given: An output with relative paths containing '.' and '..'.
+        let output = output(&["./a/b.rs", "a/../c.rs"]);
+
+        // When: Validating the output.
+        let files = validate_output(&output).unwrap();
+
+        // Then: The paths are normalized.
+        let paths = files.into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+        assert_eq!(paths, vec![PathBuf::from("a/b.rs"), PathBuf::from("c.rs")]);
+    }
+
+    #[test]
+    fn test_validate_output_rejects_absolute_paths() {
+        // Given: An output with an absolute path.
+        let output = output(&["/etc/passwd"]);
+
+        // When: Validating the output.
+        let err = validate_output(&output).unwrap_err();
+
+        // Then: The path is rejected.
+        assert_eq!(err, OutputError::NotRelative(PathBuf::from("/etc/passwd")));
+    }
+
+    #[test]
+    fn test_validate_output_rejects_escaping_paths() {
+        // Given: An output with a path escaping the output directory.
+        let output = output(&["a/../../x"]);
+
+        // When: Validating the output.
+        let err = validate_output(&output).unwrap_err();
+
+        // Then: The path is rejected.
+        assert_eq!(err, OutputError::EscapesOutDir(PathBuf::from("a/../../x")));
+    }
+
+    #[test]
+    fn test_validate_output_rejects_empty_paths() {
+        // Given: An output with a path which doesn't name a file.
+        let output = output(&["a/.."]);
+
+        // When: Validating the output.
+        let err = validate_output(&output).unwrap_err();
+
+        // Then: The path is rejected.
+        assert_eq!(err, OutputError::Empty(PathBuf::from("a/..")));
+    }
+
+    #[test]
+    fn test_validate_output_rejects_case_duplicates() {
+        // Given: An output with two paths differing only by case.
+        let output = output(&["foo/Bar.rs", "foo/bar.rs"]);
+
+        // When: Validating the output.
+        let err = validate_output(&output).unwrap_err();
+
+        // Then: The conflicting paths are reported.
+        assert_eq!(
+            err,
+            OutputError::Duplicate(PathBuf::from("foo/bar.rs"), PathBuf::from("foo/Bar.rs"))
+        );
+    }
+
+    #[test]
+    fn test_validate_output_rejects_manifest_path() {
+        // Given: An output which would overwrite the manifest.
+        let output = output(&["./.baproto-manifest.json"]);
+
+        // When: Validating the output.
+        let err = validate_output(&output).unwrap_err();
+
+        // Then: The path is rejected.
+        assert_eq!(
+            err,
+            OutputError::Reserved(PathBuf::from("./.baproto-manifest.json"))
+        );
+    }
+
+    /* ------------------------ Tests: write_if_changed ---------------------- */
+
+    #[test]
+    fn test_write_if_changed_creates_and_replaces_files() {
+        // Given: A path within a missing directory.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a/b.rs");
+
+        // When: Writing the file twice with different contents.
+        // Then: Both writes modify the file.
+        assert!(write_if_changed(&path, "one").unwrap());
+        assert!(write_if_changed(&path, "two").unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "two");
+
+        // Then: No temporary files are left behind.
+        assert_eq!(std::fs::read_dir(dir.path().join("a")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_write_if_changed_skips_unchanged_files() {
+        // Given: An existing file.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.rs");
+        std::fs::write(&path, "same").unwrap();
+
+        // When: Writing the same contents.
+        let written = write_if_changed(&path, "same").unwrap();
+
+        // Then: The file isn't written.
+        assert!(!written);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_if_changed_uses_default_permissions_for_new_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Given: A file created with the default permissions.
+        let dir = TempDir::new().unwrap();
+        let reference = dir.path().join("reference.rs");
+        std::fs::write(&reference, "").unwrap();
+
+        // When: Writing a new file.
+        let path = dir.path().join("a.rs");
+        write_if_changed(&path, "contents").unwrap();
+
+        // Then: The file has the same (umask-restricted) permissions.
+        let mode = |p: &Path| std::fs::metadata(p).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&path), mode(&reference));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_if_changed_preserves_permissions_of_replaced_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Given: An existing file with custom permissions.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.rs");
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640)).unwrap();
+
+        // When: Replacing the file's contents.
+        write_if_changed(&path, "new").unwrap();
+
+        // Then: The file's permissions are unchanged.
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o640);
+    }
+
+    /* ------------------------- Tests: resolves_within ---------------------- */
+
+    #[test]
+    fn test_resolves_within_accepts_missing_directories() {
+        // Given: An empty output directory.
+        let dir = TempDir::new().unwrap();
+
+        // When: Checking a path within missing subdirectories.
+        let within = resolves_within(dir.path(), Path::new("a/b/c.rs")).unwrap();
+
+        // Then: The path is within the output directory.
+        assert!(within);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolves_within_rejects_symlinked_directories() {
+        // Given: An output directory containing a link to another directory.
+        let dir = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        std::os::unix::fs::symlink(outside.path(), dir.path().join("link")).unwrap();
+
+        // When: Checking a path within the linked directory.
+        let within = resolves_within(dir.path(), Path::new("link/a/b.rs")).unwrap();
+
+        // Then: The path escapes the output directory.
+        assert!(!within);
+    }
+
+    /* -------------------------- Tests: unified_diff ------------------------- */
+
+    #[test]
+    fn test_unified_diff_describes_changed_lines() {
+        // Given: Two versions of a file differing by a single line.
+        let old = "a\nb\nc\n";
+        let new = "a\nB\nc\n";
+
+        // When: Diffing the versions.
+        let diff = unified_diff("x.rs", old, new);
+
+        // Then: A unified diff is returned.
+        assert_eq!(
+            diff,
+            "--- a/x.rs\n+++ b/x.rs\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n"
+        );
+    }
+
+    /* ------------------------------ Fn: output ----------------------------- */
+
+    fn output(paths: &[&str]) -> GeneratorOutput {
+        let mut output = GeneratorOutput::default();
+        for path in paths {
+            output.add(*path, "");
+        }
+        output
+    }
+}
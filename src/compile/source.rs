@@ -1,6 +1,10 @@
 use std::collections::HashMap;
+use std::io::Read;
 use std::rc::Rc;
+use std::sync::OnceLock;
 
+use crate::core::FileSystem;
+use crate::core::OsFileSystem;
 use crate::core::SchemaImport;
 
 /* -------------------------------------------------------------------------- */
@@ -8,29 +12,82 @@ use crate::core::SchemaImport;
 /* -------------------------------------------------------------------------- */
 
 /// `SourceCache` maintains a cache of source file contents. Files are loaded
-/// on-demand when first accessed and cached for subsequent requests.
-#[derive(Clone, Default)]
+/// on-demand from its [`FileSystem`] when first accessed and cached for
+/// subsequent requests.
+#[derive(Clone)]
 pub struct SourceCache {
     cache: HashMap<SchemaImport, Rc<String>>,
+    fs: Rc<dyn FileSystem>,
 }
 
 /* --------------------------- Impl: SourceCache ---------------------------- */
 
 impl SourceCache {
+    /// `new` creates an empty [`SourceCache`] which loads files from `fs`.
+    pub fn new(fs: Rc<dyn FileSystem>) -> Self {
+        Self {
+            cache: HashMap::new(),
+            fs,
+        }
+    }
+
+    /// `fs` returns the file system from which source files are loaded.
+    pub fn fs(&self) -> &dyn FileSystem {
+        self.fs.as_ref()
+    }
+
     /// `read` retrieves the source contents for a file, if it exists.
     pub fn read(&self, file: &SchemaImport) -> Option<Rc<String>> {
         self.cache.get(file).cloned()
     }
 
-    /// `insert` adds the source contents of a file into the cache.
+    /// `files` returns the imports of all cached source files.
+    pub fn files(&self) -> impl Iterator<Item = &SchemaImport> {
+        self.cache.keys()
+    }
+
+    /// `insert` adds the source contents of a file into the cache. The
+    /// contents of [`SchemaImport::stdin`] are read from the standard input
+    /// (see [`read_stdin`]).
     pub fn insert(&mut self, import: &SchemaImport) -> Result<Rc<String>, std::io::Error> {
         match self.cache.get(import) {
             Some(c) => Ok(c.clone()),
             None => {
-                let contents = Rc::new(std::fs::read_to_string(import.as_path())?);
+                let contents = match import.is_stdin() {
+                    true => read_stdin()?,
+                    false => self.fs.read_to_string(import.as_path())?,
+                };
+
+                let contents = Rc::new(contents);
                 self.cache.insert(import.clone(), contents.clone());
                 Ok(contents)
             }
         }
     }
 }
+
+/* ------------------------------ Impl: Default ----------------------------- */
+
+impl Default for SourceCache {
+    fn default() -> Self {
+        Self::new(Rc::new(OsFileSystem))
+    }
+}
+
+/* ----------------------------- Fn: read_stdin ----------------------------- */
+
+/// `read_stdin` returns the contents of the standard input. It's only read
+/// once, so that every [`SourceCache`] (e.g. one per compilation) sees the
+/// same contents.
+fn read_stdin() -> std::io::Result<String> {
+    static STDIN: OnceLock<String> = OnceLock::new();
+
+    if let Some(contents) = STDIN.get() {
+        return Ok(contents.clone());
+    }
+
+    let mut contents = String::new();
+    std::io::stdin().read_to_string(&mut contents)?;
+
+    Ok(STDIN.get_or_init(|| contents).clone())
+}
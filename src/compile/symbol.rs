@@ -31,6 +31,12 @@ impl<T: Clone> Symbols<T> {
         self.types.get(desc).cloned()
     }
 
+    /// `iter` returns an iterator over all registered [`Descriptor`]s and
+    /// their associated data.
+    pub fn iter(&self) -> impl Iterator<Item = (&Descriptor, &T)> {
+        self.types.iter()
+    }
+
     /// `insert` records data by its [`Descriptor`], returning any existing
     /// value previously registered for `descriptor`.
     pub fn insert(&mut self, descriptor: Descriptor, value: T) -> Option<T> {
@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use crate::generate::Generator;
+
+use super::CompileOptions;
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: GeneratorTarget                          */
+/* -------------------------------------------------------------------------- */
+
+/// `GeneratorTarget` pairs a [`Generator`] with the directory into which its
+/// output is written.
+pub struct GeneratorTarget {
+    /// `generator` produces the target language bindings.
+    pub generator: Box<dyn Generator>,
+    /// `out` is the output directory; defaults to the current directory.
+    pub out: Option<PathBuf>,
+}
+
+/* -------------------------- Impl: GeneratorTarget ------------------------- */
+
+impl GeneratorTarget {
+    /// `new` creates a new [`GeneratorTarget`] which writes the output of
+    /// `generator` into `out`.
+    pub fn new<G: Generator + 'static>(generator: G, out: Option<PathBuf>) -> Self {
+        Self {
+            generator: Box::new(generator),
+            out,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: TargetGroup                            */
+/* -------------------------------------------------------------------------- */
+
+/// `TargetGroup` is a set of [`GeneratorTarget`]s which share the same
+/// [`CompileOptions`].
+pub struct TargetGroup {
+    /// `options` configures the lints enforced for the group's targets.
+    pub options: CompileOptions,
+    /// `targets` contains the generators run with `options`.
+    pub targets: Vec<GeneratorTarget>,
+}
+
+/* ---------------------------- Impl: TargetGroup --------------------------- */
+
+impl TargetGroup {
+    /// `new` creates a new [`TargetGroup`] which runs each of the `targets`
+    /// with `options`.
+    pub fn new(options: CompileOptions, targets: Vec<GeneratorTarget>) -> Self {
+        Self { options, targets }
+    }
+}
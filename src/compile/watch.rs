@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Watcher                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Watcher` detects changes to a set of files by polling their modification
+/// times. Creating or deleting a watched file also counts as a change.
+///
+/// NOTE: Polling is used (rather than an OS-specific notification backend) so
+/// that watching behaves identically on all platforms.
+pub struct Watcher {
+    /// `debounce` is how long the watched files must remain unchanged before a
+    /// burst of changes is considered complete.
+    debounce: Duration,
+    /// `interval` is how often the watched files are polled.
+    interval: Duration,
+    /// `mtimes` contains the last observed modification time of each watched
+    /// file (or `None` if it didn't exist).
+    mtimes: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+/* ------------------------------ Impl: Watcher ----------------------------- */
+
+impl Watcher {
+    /// `new` creates a new [`Watcher`] for `paths`. Files modified after
+    /// `since` (e.g. while they were being compiled) are considered changed.
+    pub fn new<T: IntoIterator<Item = PathBuf>>(paths: T, since: SystemTime) -> Self {
+        let mut watcher = Self {
+            debounce: DEBOUNCE,
+            interval: POLL_INTERVAL,
+            mtimes: paths.into_iter().map(|path| (path, None)).collect(),
+        };
+
+        // Recording a newer file as missing guarantees it's seen as changed.
+        watcher.mtimes = watcher
+            .snapshot()
+            .into_iter()
+            .map(|(path, mtime)| (path, mtime.filter(|mtime| *mtime <= since)))
+            .collect();
+
+        watcher
+    }
+
+    /// `has_changed` returns whether any watched file changed since the last
+    /// observation.
+    pub fn has_changed(&self) -> bool {
+        self.snapshot() != self.mtimes
+    }
+
+    /// `wait` blocks until a watched file changes and no further changes occur
+    /// for the debounce period, so that a burst of saves is handled once.
+    pub fn wait(&mut self) {
+        while !self.has_changed() {
+            std::thread::sleep(self.interval);
+        }
+
+        let mut latest = self.snapshot();
+
+        loop {
+            std::thread::sleep(self.debounce);
+
+            let next = self.snapshot();
+            if next == latest {
+                break;
+            }
+
+            latest = next;
+        }
+
+        self.mtimes = latest;
+    }
+
+    /// `snapshot` reads the current modification time of each watched file.
+    fn snapshot(&self) -> HashMap<PathBuf, Option<SystemTime>> {
+        self.mtimes
+            .keys()
+            .map(|path| {
+                let mtime = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+                (path.clone(), mtime)
+            })
+            .collect()
+    }
+}
+
+/* --------------------------- Const: POLL_INTERVAL ------------------------- */
+
+/// `POLL_INTERVAL` is the default interval at which watched files are polled.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/* ----------------------------- Const: DEBOUNCE ---------------------------- */
+
+/// `DEBOUNCE` is the default period of inactivity which ends a burst of
+/// changes.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /* --------------------------- Tests: Watcher --------------------------- */
+
+    #[test]
+    fn test_watcher_unchanged_files() {
+        // Given: A watcher over an existing file.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.baproto");
+        std::fs::write(&path, "package a;").unwrap();
+
+        let watcher = Watcher::new([path], SystemTime::now());
+
+        // When: Checking for changes.
+        // Then: Nothing has changed.
+        assert!(!watcher.has_changed());
+    }
+
+    #[test]
+    fn test_watcher_detects_modified_file() {
+        // Given: A watcher over an existing file.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.baproto");
+        std::fs::write(&path, "package a;").unwrap();
+
+        let watcher = Watcher::new([path.clone()], SystemTime::now());
+
+        // When: The file's modification time changes.
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+
+        // Then: The change is detected.
+        assert!(watcher.has_changed());
+    }
+
+    #[test]
+    fn test_watcher_detects_created_and_deleted_files() {
+        // Given: A watcher over an existing and a missing file.
+        let dir = TempDir::new().unwrap();
+        let existing = dir.path().join("a.baproto");
+        let missing = dir.path().join("b.baproto");
+        std::fs::write(&existing, "package a;").unwrap();
+
+        let mut watcher = Watcher::new([existing.clone(), missing.clone()], SystemTime::now());
+        watcher.debounce = Duration::ZERO;
+        watcher.interval = Duration::ZERO;
+
+        // When: The missing file is created.
+        std::fs::write(&missing, "package b;").unwrap();
+
+        // Then: The change is detected and then observed.
+        assert!(watcher.has_changed());
+        watcher.wait();
+        assert!(!watcher.has_changed());
+
+        // When: The existing file is deleted.
+        std::fs::remove_file(&existing).unwrap();
+
+        // Then: The change is detected.
+        assert!(watcher.has_changed());
+    }
+
+    #[test]
+    fn test_watcher_detects_files_modified_since() {
+        // Given: A file modified after the provided time.
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("a.baproto");
+        std::fs::write(&path, "package a;").unwrap();
+
+        let since = SystemTime::now() - Duration::from_secs(10);
+
+        // When: Creating a watcher for the file.
+        let watcher = Watcher::new([path], since);
+
+        // Then: The file is considered changed.
+        assert!(watcher.has_changed());
+    }
+}
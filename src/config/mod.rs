@@ -0,0 +1,4 @@
+/* ------------------------------ Mod: Project ------------------------------ */
+
+mod project;
+pub use project::*;
@@ -0,0 +1,496 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+use thiserror::Error;
+
+use crate::analyze::Case;
+use crate::analyze::Level;
+use crate::analyze::Lint;
+use crate::compile::CompileOptions;
+
+/* -------------------------------------------------------------------------- */
+/*                            Enum: ProjectError                              */
+/* -------------------------------------------------------------------------- */
+
+/// Errors that can occur while loading a project configuration file.
+#[derive(Error, Debug)]
+pub enum ProjectError {
+    /// The configuration file could not be read.
+    #[error("failed to read '{path}': {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The configuration file is not valid.
+    #[error("invalid project file '{path}': {source}")]
+    Invalid {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+
+    /// An input pattern is not a valid glob.
+    #[error("invalid input pattern '{pattern}': {source}")]
+    InvalidPattern {
+        pattern: String,
+        #[source]
+        source: glob::PatternError,
+    },
+
+    /// A generator target doesn't select exactly one generator.
+    #[error("target #{0} must specify exactly one of 'rust', 'plugin' or 'gen'")]
+    InvalidTarget(usize),
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Project                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Project` is the contents of a project configuration file (i.e.
+/// `baproto.toml`), which declares the inputs to compile and the targets to
+/// generate. All relative paths are resolved against the file's directory.
+///
+/// ### Example
+///
+/// ```toml
+/// inputs = ["schemas/**/*.baproto"]
+/// import_roots = ["schemas"]
+///
+/// [options]
+/// deny = ["naming"]
+///
+/// [[targets]]
+/// rust = true
+/// out = "src/generated"
+///
+/// [[targets]]
+/// plugin = "tools/baproto-gen-cs"
+/// out = "client/Generated"
+/// options = { allow = ["unused"] }
+///
+/// [[targets]]
+/// gen = "cpp" # i.e. 'baproto-gen-cpp' in 'plugins_dir' or on 'PATH'
+/// out = "native/generated"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Project {
+    /// `import_roots` are the directories searched for included schemas.
+    pub import_roots: Vec<PathBuf>,
+    /// `inputs` are glob patterns matching the schemas to compile.
+    pub inputs: Vec<String>,
+    /// `options` configures compilation of all targets.
+    pub options: ProjectOptions,
+    /// `plugins_dir` is searched for plugins selected by name before `PATH`.
+    pub plugins_dir: Option<PathBuf>,
+    /// `targets` are the generators to run, each with its own output directory.
+    pub targets: Vec<Target>,
+}
+
+/* ------------------------------ Impl: Project ----------------------------- */
+
+impl Project {
+    /// `discover` loads the project file within `dir`, if one exists.
+    pub fn discover<P: AsRef<Path>>(dir: P) -> Result<Option<Self>, ProjectError> {
+        let path = dir.as_ref().join(PROJECT_FILE_NAME);
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        Self::load(path).map(Some)
+    }
+
+    /// `load` reads and validates the project file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ProjectError> {
+        let path = path.as_ref();
+
+        let contents = std::fs::read_to_string(path).map_err(|source| ProjectError::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+
+        let mut project: Project =
+            toml::from_str(&contents).map_err(|source| ProjectError::Invalid {
+                path: path.to_owned(),
+                source,
+            })?;
+
+        for (i, target) in project.targets.iter().enumerate() {
+            let selected = [
+                target.rust,
+                target.plugin.is_some(),
+                target.generator.is_some(),
+            ];
+
+            if selected.iter().filter(|s| **s).count() != 1 {
+                return Err(ProjectError::InvalidTarget(i + 1));
+            }
+        }
+
+        if let Some(dir) = path.parent() {
+            project.resolve(dir);
+        }
+
+        Ok(project)
+    }
+
+    /// `files` expands the input patterns into a sorted list of schema files.
+    pub fn files(&self) -> Result<Vec<PathBuf>, ProjectError> {
+        let mut files = Vec::new();
+
+        for pattern in &self.inputs {
+            let paths = glob::glob(pattern).map_err(|source| ProjectError::InvalidPattern {
+                pattern: pattern.clone(),
+                source,
+            })?;
+
+            files.extend(paths.filter_map(Result::ok).filter(|path| path.is_file()));
+        }
+
+        files.sort();
+        files.dedup();
+
+        Ok(files)
+    }
+
+    /// `resolve` makes all relative paths absolute using `dir` as the base.
+    fn resolve(&mut self, dir: &Path) {
+        for root in &mut self.import_roots {
+            *root = dir.join(&root);
+        }
+
+        for pattern in &mut self.inputs {
+            *pattern = dir.join(&pattern).to_string_lossy().into_owned();
+        }
+
+        if let Some(plugins_dir) = self.plugins_dir.as_mut() {
+            *plugins_dir = dir.join(&plugins_dir);
+        }
+
+        for target in &mut self.targets {
+            target.out = dir.join(&target.out);
+
+            if let Some(plugin) = target.plugin.as_mut() {
+                *plugin = dir.join(&plugin);
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: ProjectOptions                           */
+/* -------------------------------------------------------------------------- */
+
+/// `ProjectOptions` configures compilation from a project file. Unset values
+/// leave the corresponding [`CompileOptions`] unchanged.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectOptions {
+    /// `allow` suppresses warnings selected by each lint.
+    pub allow: Vec<Lint>,
+    /// `deny` reports warnings selected by each lint as errors.
+    pub deny: Vec<Lint>,
+    /// `deny_warnings` reports all warnings as errors.
+    pub deny_warnings: bool,
+    /// `naming` configures the required naming conventions.
+    pub naming: NamingOptions,
+    /// `warn` reports warnings selected by each lint as warnings.
+    pub warn: Vec<Lint>,
+    /// `warn_unused_types` enables warnings for unreferenced types.
+    pub warn_unused_types: Option<bool>,
+}
+
+/* -------------------------- Impl: ProjectOptions -------------------------- */
+
+impl ProjectOptions {
+    /// `apply` layers these options on top of `options`.
+    pub fn apply(&self, options: &mut CompileOptions) {
+        for lint in &self.allow {
+            options.lints.set(lint.clone(), Level::Allow);
+        }
+
+        for lint in &self.warn {
+            options.lints.set(lint.clone(), Level::Warn);
+        }
+
+        for lint in &self.deny {
+            options.lints.set(lint.clone(), Level::Deny);
+        }
+
+        if self.deny_warnings {
+            options.lints.set(Lint::Warnings, Level::Deny);
+        }
+
+        if let Some(case) = self.naming.field_case {
+            options.naming.fields = case;
+        }
+
+        if let Some(case) = self.naming.type_case {
+            options.naming.types = case;
+        }
+
+        if let Some(case) = self.naming.variant_case {
+            options.naming.variants = case;
+        }
+
+        if let Some(warn_unused_types) = self.warn_unused_types {
+            options.warn_unused_types = warn_unused_types;
+        }
+    }
+}
+
+/* ---------------------------- Struct: NamingOptions ----------------------- */
+
+/// `NamingOptions` overrides the naming convention of each identifier category.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingOptions {
+    pub field_case: Option<Case>,
+    pub type_case: Option<Case>,
+    pub variant_case: Option<Case>,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Target                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Target` declares a generator to run along with where its output is written.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Target {
+    /// `generator` is the name of a plugin to search for (i.e. `gen = "foo"`
+    /// selects the `baproto-gen-foo` executable).
+    #[serde(default, rename = "gen")]
+    pub generator: Option<String>,
+    /// `legacy_protocol` writes the bare schema to the plugin instead of the
+    /// versioned request envelope.
+    #[serde(default)]
+    pub legacy_protocol: bool,
+    /// `options` configures compilation for this target, taking precedence
+    /// over the project's options.
+    #[serde(default)]
+    pub options: ProjectOptions,
+    /// `out` is the directory in which to write generated files.
+    pub out: PathBuf,
+    /// `parameters` contains the options passed through to the plugin.
+    #[serde(default)]
+    pub parameters: BTreeMap<String, String>,
+    /// `plugin` is a path to an external generator binary.
+    #[serde(default)]
+    pub plugin: Option<PathBuf>,
+    /// `rust` selects the built-in Rust generator.
+    #[serde(default)]
+    pub rust: bool,
+}
+
+/* ------------------------ Const: PROJECT_FILE_NAME ------------------------ */
+
+/// `PROJECT_FILE_NAME` is the name of a project configuration file.
+pub const PROJECT_FILE_NAME: &str = "baproto.toml";
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use crate::analyze::Code;
+
+    use super::*;
+
+    /* --------------------------- Tests: Project --------------------------- */
+
+    #[test]
+    fn test_project_load_resolves_relative_paths() {
+        // Given: A project file declaring inputs, roots and targets.
+        let dir = TempDir::new().unwrap();
+        let path = write_project(
+            &dir,
+            r#"
+            inputs = ["schemas/*.baproto"]
+            import_roots = ["schemas"]
+
+            [[targets]]
+            rust = true
+            out = "gen"
+
+            [[targets]]
+            plugin = "bin/plugin"
+            out = "/abs/out"
+            "#,
+        );
+
+        // When: The project is loaded.
+        let project = Project::load(path).unwrap();
+
+        // Then: Relative paths are resolved against the project directory.
+        assert_eq!(project.import_roots, vec![dir.path().join("schemas")]);
+        assert_eq!(
+            project.inputs,
+            vec![dir.path().join("schemas/*.baproto").display().to_string()]
+        );
+        assert_eq!(project.targets[0].out, dir.path().join("gen"));
+        assert_eq!(
+            project.targets[1].plugin,
+            Some(dir.path().join("bin/plugin"))
+        );
+        assert_eq!(project.targets[1].out, PathBuf::from("/abs/out"));
+    }
+
+    #[test]
+    fn test_project_load_plugin_parameters() {
+        // Given: A project file declaring a plugin target with parameters.
+        let dir = TempDir::new().unwrap();
+        let path = write_project(
+            &dir,
+            r#"
+            [[targets]]
+            plugin = "bin/plugin"
+            out = "gen"
+            legacy_protocol = true
+            parameters = { prefix = "Foo", runtime = "godot" }
+            "#,
+        );
+
+        // When: The project is loaded.
+        let project = Project::load(path).unwrap();
+
+        // Then: The parameters and protocol are recorded on the target.
+        let target = &project.targets[0];
+        assert!(target.legacy_protocol);
+        assert_eq!(target.parameters["prefix"], "Foo");
+        assert_eq!(target.parameters["runtime"], "godot");
+    }
+
+    #[test]
+    fn test_project_load_rejects_ambiguous_target() {
+        // Given: A target which selects two generators.
+        let dir = TempDir::new().unwrap();
+        let path = write_project(
+            &dir,
+            "[[targets]]\nrust = true\nplugin = \"x\"\nout = \"gen\"\n",
+        );
+
+        // When: The project is loaded.
+        let err = Project::load(path).unwrap_err();
+
+        // Then: The target is rejected.
+        assert!(matches!(err, ProjectError::InvalidTarget(1)));
+    }
+
+    #[test]
+    fn test_project_load_named_plugin_target() {
+        // Given: A project file declaring a plugin target by name.
+        let dir = TempDir::new().unwrap();
+        let path = write_project(
+            &dir,
+            r#"
+            plugins_dir = "tools"
+
+            [[targets]]
+            gen = "cpp"
+            out = "gen"
+            "#,
+        );
+
+        // When: The project is loaded.
+        let project = Project::load(path).unwrap();
+
+        // Then: The plugin name and resolved plugins directory are recorded.
+        assert_eq!(project.plugins_dir, Some(dir.path().join("tools")));
+        assert_eq!(project.targets[0].generator.as_deref(), Some("cpp"));
+        assert_eq!(project.targets[0].plugin, None);
+    }
+
+    #[test]
+    fn test_project_load_rejects_unknown_keys() {
+        // Given: A project file with a misspelled key.
+        let dir = TempDir::new().unwrap();
+        let path = write_project(&dir, "input = [\"*.baproto\"]\n");
+
+        // When: The project is loaded.
+        let err = Project::load(path).unwrap_err();
+
+        // Then: The file is reported as invalid.
+        assert!(matches!(err, ProjectError::Invalid { .. }));
+    }
+
+    #[test]
+    fn test_project_discover_without_file() {
+        // Given: A directory without a project file.
+        let dir = TempDir::new().unwrap();
+
+        // When: Discovering the project.
+        let project = Project::discover(dir.path()).unwrap();
+
+        // Then: No project is found.
+        assert!(project.is_none());
+    }
+
+    #[test]
+    fn test_project_files_expands_patterns() {
+        // Given: A project whose patterns overlap.
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        for name in ["b.baproto", "a.baproto", "nested/c.baproto", "d.txt"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        let path = write_project(&dir, "inputs = [\"**/*.baproto\", \"a.baproto\"]\n");
+
+        // When: The input files are listed.
+        let files = Project::load(path).unwrap().files().unwrap();
+
+        // Then: Each matching schema is listed once, in order.
+        assert_eq!(
+            files,
+            vec![
+                dir.path().join("a.baproto"),
+                dir.path().join("b.baproto"),
+                dir.path().join("nested/c.baproto"),
+            ]
+        );
+    }
+
+    /* ------------------------ Tests: ProjectOptions ----------------------- */
+
+    #[test]
+    fn test_project_options_apply_overrides() {
+        // Given: Options which adjust lints and naming conventions.
+        let options: ProjectOptions = toml::from_str(
+            r#"
+            allow = ["unused"]
+            deny = ["W0002"]
+            warn_unused_types = true
+            naming = { field_case = "camel" }
+            "#,
+        )
+        .unwrap();
+
+        // When: The options are applied to the defaults.
+        let mut got = CompileOptions::default();
+        options.apply(&mut got);
+
+        // Then: Only the configured values are changed.
+        assert_eq!(got.lints.level(Some(Code::UnusedType)), Level::Allow);
+        assert_eq!(got.lints.level(Some(Code::NamingConvention)), Level::Deny);
+        assert_eq!(got.lints.level(Some(Code::DeprecatedUsage)), Level::Warn);
+        assert_eq!(got.naming.fields, Case::Camel);
+        assert_eq!(got.naming.types, Case::Pascal);
+        assert!(got.warn_unused_types);
+    }
+
+    /* ------------------------- Fn: write_project -------------------------- */
+
+    fn write_project(dir: &TempDir, contents: &str) -> PathBuf {
+        let path = dir.path().join(PROJECT_FILE_NAME);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/* -------------------------------------------------------------------------- */
+/*                             Trait: FileSystem                              */
+/* -------------------------------------------------------------------------- */
+
+/// `FileSystem` abstracts the file system from which schemas are read, so that
+/// schemas can be compiled from sources other than the local disk (e.g. from
+/// memory within an editor integration).
+pub trait FileSystem {
+    /// `canonicalize` returns the canonical, absolute form of `path`.
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf>;
+
+    /// `current_dir` returns the directory against which relative paths are
+    /// resolved.
+    fn current_dir(&self) -> std::io::Result<PathBuf>;
+
+    /// `exists` returns whether `path` refers to a file or directory.
+    fn exists(&self, path: &Path) -> bool {
+        self.is_file(path) || self.is_dir(path)
+    }
+
+    /// `is_dir` returns whether `path` refers to a directory.
+    fn is_dir(&self, path: &Path) -> bool;
+
+    /// `is_file` returns whether `path` refers to a file.
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// `read_to_string` returns the contents of the file at `path`.
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String>;
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: OsFileSystem                            */
+/* -------------------------------------------------------------------------- */
+
+/// `OsFileSystem` is the [`FileSystem`] of the local disk.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsFileSystem;
+
+/* ---------------------------- Impl: FileSystem ---------------------------- */
+
+impl FileSystem for OsFileSystem {
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf> {
+        path.canonicalize()
+    }
+
+    fn current_dir(&self) -> std::io::Result<PathBuf> {
+        std::env::current_dir()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                          Struct: MemoryFileSystem                          */
+/* -------------------------------------------------------------------------- */
+
+/// `MemoryFileSystem` is a [`FileSystem`] whose files are held in memory.
+///
+/// Paths are resolved lexically against the root directory (`/`), which is
+/// also the current directory; directories exist implicitly for each file.
+#[allow(unused)]
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFileSystem {
+    files: BTreeMap<PathBuf, String>,
+}
+
+/* ------------------------- Impl: MemoryFileSystem ------------------------- */
+
+#[allow(unused)]
+impl MemoryFileSystem {
+    /// `insert` adds a file with the provided `contents` at `path`, replacing
+    /// any existing file.
+    pub fn insert<P: AsRef<Path>, S: Into<String>>(&mut self, path: P, contents: S) {
+        self.files.insert(resolve(path.as_ref()), contents.into());
+    }
+
+    /// `with_file` adds a file with the provided `contents` at `path`.
+    pub fn with_file<P: AsRef<Path>, S: Into<String>>(mut self, path: P, contents: S) -> Self {
+        self.insert(path, contents);
+        self
+    }
+}
+
+/* ---------------------------- Impl: FileSystem ---------------------------- */
+
+impl FileSystem for MemoryFileSystem {
+    fn canonicalize(&self, path: &Path) -> std::io::Result<PathBuf> {
+        let path = resolve(path);
+
+        if !self.exists(&path) {
+            return Err(std::io::ErrorKind::NotFound.into());
+        }
+
+        Ok(path)
+    }
+
+    fn current_dir(&self) -> std::io::Result<PathBuf> {
+        Ok(PathBuf::from("/"))
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        let path = resolve(path);
+
+        self.files
+            .keys()
+            .any(|file| file != &path && file.starts_with(&path))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&resolve(path))
+    }
+
+    fn read_to_string(&self, path: &Path) -> std::io::Result<String> {
+        self.files
+            .get(&resolve(path))
+            .cloned()
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+    }
+}
+
+/* ------------------------ Impl: FromIterator<(P, S)> ---------------------- */
+
+impl<P: AsRef<Path>, S: Into<String>> FromIterator<(P, S)> for MemoryFileSystem {
+    fn from_iter<T: IntoIterator<Item = (P, S)>>(iter: T) -> Self {
+        let mut fs = Self::default();
+        for (path, contents) in iter {
+            fs.insert(path, contents);
+        }
+        fs
+    }
+}
+
+/* ------------------------------- Fn: resolve ------------------------------ */
+
+/// `resolve` lexically resolves `path` against the root directory of a
+/// [`MemoryFileSystem`].
+#[allow(unused)]
+fn resolve(path: &Path) -> PathBuf {
+    let mut resolved = PathBuf::from("/");
+
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) | Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::Normal(name) => resolved.push(name),
+        }
+    }
+
+    resolved
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ------------------------ Tests: MemoryFileSystem ---------------------- */
+
+    #[test]
+    fn test_memory_file_system_resolves_relative_paths() {
+        // Given: An in-memory file system with a nested file.
+        let fs = MemoryFileSystem::default().with_file("a/b.baproto", "contents");
+
+        // When: Looking up the file via equivalent paths.
+        // Then: Each path refers to the same file.
+        assert_eq!(
+            fs.read_to_string(Path::new("/a/b.baproto")).unwrap(),
+            "contents"
+        );
+        assert_eq!(
+            fs.read_to_string(Path::new("./a/../a/b.baproto")).unwrap(),
+            "contents"
+        );
+        assert_eq!(
+            fs.canonicalize(Path::new("a/./b.baproto")).unwrap(),
+            PathBuf::from("/a/b.baproto")
+        );
+    }
+
+    #[test]
+    fn test_memory_file_system_directories_exist_implicitly() {
+        // Given: An in-memory file system with a nested file.
+        let fs = MemoryFileSystem::default().with_file("a/b/c.baproto", "");
+
+        // When: Checking the kind of each path.
+        // Then: Ancestors of the file are directories.
+        assert!(fs.is_dir(Path::new("/")));
+        assert!(fs.is_dir(Path::new("a/b")));
+        assert!(!fs.is_file(Path::new("a/b")));
+        assert!(fs.is_file(Path::new("a/b/c.baproto")));
+        assert!(!fs.exists(Path::new("a/c")));
+    }
+}
@@ -4,6 +4,9 @@ use std::path::PathBuf;
 use std::rc::Rc;
 use thiserror::Error;
 
+use super::FileSystem;
+use super::OsFileSystem;
+
 /* -------------------------------------------------------------------------- */
 /*                          Enum: PathValidationError                         */
 /* -------------------------------------------------------------------------- */
@@ -74,15 +77,60 @@ impl ImportRoot {
         self.0.exists()
     }
 
+    /// `new_in` validates and canonicalizes the directory `path` within the
+    /// file system `fs`.
+    pub fn new_in<T>(fs: &dyn FileSystem, path: T) -> Result<Self, PathValidationError>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        if !fs.exists(path) {
+            return Err(PathValidationError::DoesNotExist {
+                path: path.to_path_buf(),
+            });
+        }
+
+        if !fs.is_dir(path) {
+            return Err(PathValidationError::NotADirectory {
+                path: path.to_path_buf(),
+            });
+        }
+
+        let canonical = fs
+            .canonicalize(path)
+            .map_err(|e| PathValidationError::InvalidPath {
+                path: path.to_path_buf(),
+                source: e,
+            })?;
+
+        Ok(Self(canonical))
+    }
+
     /// `resolve_schema_import` resolves the provided relative path within this
     /// import root to a validated schema import.
+    #[allow(unused)]
     pub fn resolve_schema_import<T>(&self, path: T) -> Result<SchemaImport, PathValidationError>
+    where
+        T: AsRef<Path>,
+    {
+        self.resolve_schema_import_in(&OsFileSystem, path)
+    }
+
+    /// `resolve_schema_import_in` resolves the provided relative path within
+    /// this import root to a validated schema import within the file system
+    /// `fs`.
+    pub fn resolve_schema_import_in<T>(
+        &self,
+        fs: &dyn FileSystem,
+        path: T,
+    ) -> Result<SchemaImport, PathValidationError>
     where
         T: AsRef<Path>,
     {
         let path = self.0.join(path);
 
-        let import = SchemaImport::try_from(path)?;
+        let import = SchemaImport::new_in(fs, path)?;
         let path = import.as_path();
 
         // Ensure the canonical path doesn't escape the import root. This
@@ -105,26 +153,7 @@ impl TryFrom<&Path> for ImportRoot {
     type Error = PathValidationError;
 
     fn try_from(path: &Path) -> Result<Self, Self::Error> {
-        if !path.exists() {
-            return Err(PathValidationError::DoesNotExist {
-                path: path.to_path_buf(),
-            });
-        }
-
-        if !path.is_dir() {
-            return Err(PathValidationError::NotADirectory {
-                path: path.to_path_buf(),
-            });
-        }
-
-        let canonical = path
-            .canonicalize()
-            .map_err(|e| PathValidationError::InvalidPath {
-                path: path.to_path_buf(),
-                source: e,
-            })?;
-
-        Ok(Self(canonical))
+        Self::new_in(&OsFileSystem, path)
     }
 }
 
@@ -189,28 +218,21 @@ impl SchemaImport {
         self.0.exists()
     }
 
-    /// Creates an anonymous schema import for testing/ephemeral parsing.
-    ///
-    /// This allows lexing and parsing code that isn't from a real file.
-    #[cfg(test)]
-    pub fn anonymous() -> Self {
-        Self(Rc::new(PathBuf::from("<anonymous>")))
-    }
-}
-
-/* -------------------------- Impl: TryFrom<&Path> -------------------------- */
-
-impl TryFrom<&Path> for SchemaImport {
-    type Error = PathValidationError;
+    /// `new_in` validates and canonicalizes the schema file `path` within the
+    /// file system `fs`.
+    pub fn new_in<T>(fs: &dyn FileSystem, path: T) -> Result<Self, PathValidationError>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
 
-    fn try_from(path: &Path) -> Result<Self, Self::Error> {
-        if !path.exists() {
+        if !fs.exists(path) {
             return Err(PathValidationError::DoesNotExist {
                 path: path.to_path_buf(),
             });
         }
 
-        if !path.is_file() {
+        if !fs.is_file(path) {
             return Err(PathValidationError::NotAFile {
                 path: path.to_path_buf(),
             });
@@ -222,8 +244,8 @@ impl TryFrom<&Path> for SchemaImport {
             });
         }
 
-        let canonical = path
-            .canonicalize()
+        let canonical = fs
+            .canonicalize(path)
             .map_err(|e| PathValidationError::InvalidPath {
                 path: path.to_path_buf(),
                 source: e,
@@ -231,6 +253,41 @@ impl TryFrom<&Path> for SchemaImport {
 
         Ok(Self(Rc::new(canonical)))
     }
+
+    /// `stdin` returns the schema import whose contents are read from the
+    /// standard input.
+    pub fn stdin() -> Self {
+        Self(Rc::new(PathBuf::from(STDIN_PATH)))
+    }
+
+    /// `is_stdin` returns whether the schema is read from the standard input.
+    pub fn is_stdin(&self) -> bool {
+        self.0.as_os_str() == STDIN_PATH
+    }
+
+    /// Creates an anonymous schema import for testing/ephemeral parsing.
+    ///
+    /// This allows lexing and parsing code that isn't from a real file.
+    #[cfg(test)]
+    pub fn anonymous() -> Self {
+        Self(Rc::new(PathBuf::from("<anonymous>")))
+    }
+
+    /// Creates a named schema import for testing without a backing file.
+    #[cfg(test)]
+    pub fn named(name: &str) -> Self {
+        Self(Rc::new(PathBuf::from(name)))
+    }
+}
+
+/* -------------------------- Impl: TryFrom<&Path> -------------------------- */
+
+impl TryFrom<&Path> for SchemaImport {
+    type Error = PathValidationError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        Self::new_in(&OsFileSystem, path)
+    }
 }
 
 /* ------------------------- Impl: TryFrom<PathBuf> ------------------------- */
@@ -261,6 +318,12 @@ impl AsRef<Path> for SchemaImport {
     }
 }
 
+/* ---------------------------- Const: STDIN_PATH -------------------------- */
+
+/// `STDIN_PATH` is the path under which a schema read from the standard input
+/// is reported.
+pub const STDIN_PATH: &str = "<stdin>";
+
 /* -------------------------------------------------------------------------- */
 /*                                 Mod: tests                                 */
 /* -------------------------------------------------------------------------- */
@@ -268,6 +331,7 @@ impl AsRef<Path> for SchemaImport {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::MemoryFileSystem;
     use std::fs;
     use tempfile::TempDir;
 
@@ -506,6 +570,23 @@ mod tests {
         assert_eq!(schema.as_path(), canonical);
     }
 
+    #[test]
+    fn test_schema_import_new_in_memory_file_system() {
+        // Given: An in-memory file system containing a schema.
+        let fs = MemoryFileSystem::default().with_file("a/schema.baproto", "");
+        let root = ImportRoot::new_in(&fs, "a").unwrap();
+
+        // When: Resolving the schema within the import root.
+        let schema = root.resolve_schema_import_in(&fs, "schema.baproto");
+
+        // Then: The schema is resolved without touching the disk.
+        assert_eq!(schema.unwrap().as_path(), Path::new("/a/schema.baproto"));
+        assert!(matches!(
+            root.resolve_schema_import_in(&fs, "../a/missing.baproto"),
+            Err(PathValidationError::DoesNotExist { .. })
+        ));
+    }
+
     #[test]
     fn test_schema_import_exists() {
         // Given: A valid SchemaImport for an existing file.
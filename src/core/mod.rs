@@ -8,6 +8,11 @@ pub use descriptor::*;
 mod package;
 pub use package::*;
 
+/* --------------------------------- Mod: Fs -------------------------------- */
+
+mod fs;
+pub use fs::*;
+
 /* ------------------------------- Mod: Import ------------------------------ */
 
 mod import;
@@ -107,6 +107,20 @@ where
     }
 }
 
+/* ------------------------------ Impl: FromStr ----------------------------- */
+
+impl std::str::FromStr for PackageName {
+    type Err = PackageNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(PackageNameError::Empty);
+        }
+
+        PackageName::try_from(s.split('.').collect::<Vec<_>>())
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 /*                                 Mod: Tests                                 */
 /* -------------------------------------------------------------------------- */
@@ -186,4 +200,25 @@ mod tests {
         let pkg = PackageName::try_from(vec!["foo", "bar"]);
         assert_eq!(pkg, Ok(PackageName(vec!["foo".into(), "bar".into()])));
     }
+
+    #[test]
+    fn test_package_name_from_str() {
+        let pkg = "foo.bar".parse::<PackageName>();
+        assert_eq!(pkg, Ok(PackageName(vec!["foo".into(), "bar".into()])));
+    }
+
+    #[test]
+    fn test_package_name_from_str_rejects_missing_segment() {
+        let result = "foo..bar".parse::<PackageName>();
+        assert_eq!(
+            result.unwrap_err(),
+            PackageNameError::MissingSegment("foo..bar".to_string())
+        );
+    }
+
+    #[test]
+    fn test_package_name_from_str_rejects_empty() {
+        let result = "".parse::<PackageName>();
+        assert_eq!(result.unwrap_err(), PackageNameError::Empty);
+    }
 }
@@ -0,0 +1,89 @@
+use anyhow::anyhow;
+use std::path::Path;
+
+use crate::analyze::Diagnostic;
+use crate::analyze::DiagnosticsFormat;
+use crate::compile::SourceCache;
+use crate::compile::parse_schema;
+use crate::core::SchemaImport;
+
+/* ------------------------------ Mod: Printer ------------------------------ */
+
+mod printer;
+pub use printer::*;
+
+/* -------------------------------------------------------------------------- */
+/*                                 Fn: format                                 */
+/* -------------------------------------------------------------------------- */
+
+/// `format` rewrites each of the provided schema `files` in the canonical
+/// `.baproto` style. If `check` is set, no files are modified; instead, the
+/// paths of all files which aren't formatted are printed and an error is
+/// returned if there are any.
+///
+/// NOTE: Files containing syntax errors are never modified.
+pub fn format<P: AsRef<Path>>(files: Vec<P>, check: bool) -> anyhow::Result<()> {
+    let mut diagnostics = Vec::new();
+    let mut sources = SourceCache::default();
+    let mut unformatted = Vec::new();
+
+    for path in files {
+        let path = path.as_ref();
+
+        let import = SchemaImport::try_from(path).map_err(|e| anyhow!(e))?;
+        let contents = sources.insert(&import)?;
+
+        let formatted = match format_source(&contents, &import) {
+            Ok(formatted) => formatted,
+            Err(errors) => {
+                diagnostics.extend(errors);
+                continue;
+            }
+        };
+
+        if formatted == *contents {
+            continue;
+        }
+
+        if check {
+            unformatted.push(path.to_owned());
+        } else {
+            std::fs::write(path, formatted)?;
+        }
+    }
+
+    if !diagnostics.is_empty() {
+        DiagnosticsFormat::Human
+            .reporter(&sources)
+            .report(&diagnostics)?;
+
+        return Err(anyhow!(
+            "Formatting failed with {} error(s).",
+            diagnostics.len()
+        ));
+    }
+
+    if !unformatted.is_empty() {
+        for path in &unformatted {
+            println!("{}", path.display());
+        }
+
+        return Err(anyhow!(
+            "{} file(s) would be reformatted.",
+            unformatted.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/* ---------------------------- Fn: format_source --------------------------- */
+
+/// `format_source` formats the `contents` of the schema `import`, returning
+/// diagnostics describing any syntax errors instead.
+pub fn format_source(contents: &str, import: &SchemaImport) -> Result<String, Vec<Diagnostic>> {
+    match parse_schema(contents, import) {
+        (Some(ast), errors) if errors.is_empty() => Ok(Printer::new(contents).print(&ast)),
+        (_, errors) => Err(errors),
+    }
+}
@@ -0,0 +1,618 @@
+use itertools::Itertools;
+
+use crate::ast;
+use crate::generate::{CodeWriter, CodeWriterBuilder, StringWriter};
+use crate::lex::Span;
+
+/* -------------------------------------------------------------------------- */
+/*                               Struct: Printer                              */
+/* -------------------------------------------------------------------------- */
+
+/// `Printer` re-prints an [`ast::Schema`] in the canonical `.baproto` style:
+///
+/// - Declarations are indented by four spaces, with one statement per line.
+/// - The `package` declaration comes first, followed by `include` statements
+///   sorted by path, and then all other declarations.
+/// - Field and variant indices are aligned within each block.
+/// - Comments are retained; blank lines separate top-level declarations and
+///   are otherwise preserved (though collapsed) from the original source.
+pub struct Printer<'a> {
+    code: CodeWriter,
+    out: StringWriter,
+    source: &'a str,
+}
+
+/* ------------------------------ Impl: Printer ----------------------------- */
+
+impl<'a> Printer<'a> {
+    /// `new` creates a new [`Printer`] for a schema parsed from `source`.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            code: CodeWriterBuilder::default()
+                .comment_token("//".to_owned())
+                .indent_token("    ".to_owned())
+                .newline_token("\n".to_owned())
+                .build()
+                .unwrap(),
+            out: StringWriter::default(),
+            source,
+        }
+    }
+
+    /// `print` formats `schema`, consuming the [`Printer`].
+    pub fn print(mut self, schema: &ast::Schema) -> String {
+        self.schema(schema)
+            .expect("writing to a 'StringWriter' is infallible");
+
+        self.out.into_content()
+    }
+
+    /* ----------------------------- Fn: Items ------------------------------ */
+
+    fn schema(&mut self, schema: &ast::Schema) -> anyhow::Result<()> {
+        let units = self.group(&schema.items);
+
+        let is_package = |u: &&Unit<ast::SchemaItem>| matches!(u.item, ast::SchemaItem::Package(_));
+        let include_path = |u: &Unit<ast::SchemaItem>| match u.item {
+            ast::SchemaItem::Include(include) => Some(path_to_string(&include.path)),
+            _ => None,
+        };
+
+        // Comments preceding the package declaration form the file's header.
+        let header = match units.iter().position(|u| is_package(&u)) {
+            Some(index) => &units[..index],
+            None => &[][..],
+        };
+
+        let package = units.iter().filter(is_package);
+        let includes = units
+            .iter()
+            .filter(|u| include_path(u).is_some())
+            .sorted_by_key(|u| include_path(u));
+        let body = units[header.len()..]
+            .iter()
+            .filter(|u| !is_package(u) && include_path(u).is_none());
+
+        let mut is_first = true;
+        let mut previous_was_include = false;
+
+        for unit in header.iter().chain(package).chain(includes).chain(body) {
+            let is_include = include_path(unit).is_some();
+
+            // Consecutive includes are grouped without separating blank lines.
+            let is_grouped = is_include && previous_was_include;
+
+            if !is_first && !is_grouped {
+                self.code.blank_line(&mut self.out)?;
+            }
+
+            self.unit(unit, Self::schema_item)?;
+
+            is_first = false;
+            previous_was_include = is_include;
+        }
+
+        Ok(())
+    }
+
+    fn schema_item(&mut self, item: &ast::SchemaItem, _: usize) -> anyhow::Result<()> {
+        match item {
+            ast::SchemaItem::CommentBlock(comment) => self.comment_block(comment),
+            ast::SchemaItem::Enum(enum_) => self.enumeration(enum_),
+            ast::SchemaItem::Include(include) => {
+                let line = format!("include \"{}\";", path_to_string(&include.path));
+                self.line(line, include.inline_comment.as_ref())
+            }
+            ast::SchemaItem::Message(msg) => self.message(msg),
+            ast::SchemaItem::Package(package) => {
+                self.comment_opt(package.comment.as_ref())?;
+
+                let line = format!("package {};", package.components.iter().join("."));
+                self.line(line, package.inline_comment.as_ref())
+            }
+        }
+    }
+
+    fn message_item(&mut self, item: &ast::MessageItem, width: usize) -> anyhow::Result<()> {
+        match item {
+            ast::MessageItem::CommentBlock(comment) => self.comment_block(comment),
+            ast::MessageItem::Enum(enum_) => self.enumeration(enum_),
+            ast::MessageItem::Field(field) => self.field(field, width),
+            ast::MessageItem::Message(msg) => self.message(msg),
+            ast::MessageItem::Reserved(reserved) => self.reserved(reserved),
+        }
+    }
+
+    fn enum_item(&mut self, item: &ast::EnumItem, width: usize) -> anyhow::Result<()> {
+        match item {
+            ast::EnumItem::CommentBlock(comment) => self.comment_block(comment),
+            ast::EnumItem::FieldVariant(field) => self.field(field, width),
+            ast::EnumItem::Reserved(reserved) => self.reserved(reserved),
+            ast::EnumItem::UnitVariant(variant) => {
+                self.comment_opt(variant.comment.as_ref())?;
+                self.annotations(&variant.annotations)?;
+
+                let line = format!("{}{};", index(variant.index.as_ref(), width), variant.name);
+                self.line(line, variant.inline_comment.as_ref())
+            }
+        }
+    }
+
+    /* -------------------------- Fn: Declarations -------------------------- */
+
+    fn message(&mut self, msg: &ast::Message) -> anyhow::Result<()> {
+        self.comment_opt(msg.comment.as_ref())?;
+        self.annotations(&msg.annotations)?;
+
+        let header = format!("message {}", msg.name);
+        let width = index_width(msg.items.iter().filter_map(|item| match item {
+            ast::MessageItem::Field(field) => field.index.as_ref(),
+            _ => None,
+        }));
+
+        self.block(&header, &msg.items, width, Self::message_item)
+    }
+
+    fn enumeration(&mut self, enum_: &ast::Enum) -> anyhow::Result<()> {
+        self.comment_opt(enum_.comment.as_ref())?;
+        self.annotations(&enum_.annotations)?;
+
+        let header = format!("enum {}", enum_.name);
+        let width = index_width(enum_.items.iter().filter_map(|item| match item {
+            ast::EnumItem::FieldVariant(field) => field.index.as_ref(),
+            ast::EnumItem::UnitVariant(variant) => variant.index.as_ref(),
+            _ => None,
+        }));
+
+        self.block(&header, &enum_.items, width, Self::enum_item)
+    }
+
+    fn field(&mut self, field: &ast::Field, width: usize) -> anyhow::Result<()> {
+        self.comment_opt(field.comment.as_ref())?;
+        self.annotations(&field.annotations)?;
+
+        let encoding = match field.encoding.as_ref().map(|e| e.encodings.as_slice()) {
+            None | Some([]) => String::new(),
+            Some([kind]) => format!(" = {}", encoding_kind(kind)),
+            Some(kinds) => format!(" = [{}]", kinds.iter().map(encoding_kind).join(", ")),
+        };
+
+        let line = format!(
+            "{}{} {}{};",
+            index(field.index.as_ref(), width),
+            typ(&field.kind),
+            field.name,
+            encoding,
+        );
+
+        self.line(line, field.inline_comment.as_ref())
+    }
+
+    fn reserved(&mut self, reserved: &ast::Reserved) -> anyhow::Result<()> {
+        self.comment_opt(reserved.comment.as_ref())?;
+        self.line(format!("{};", reserved), reserved.inline_comment.as_ref())
+    }
+
+    /* ----------------------------- Fn: Blocks ----------------------------- */
+
+    /// `block` writes a braced block containing `items`, preserving blank
+    /// lines which separated them in the original source.
+    fn block<T: Item>(
+        &mut self,
+        header: &str,
+        items: &[T],
+        width: usize,
+        f: fn(&mut Self, &T, usize) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        if items.is_empty() {
+            return self
+                .code
+                .writeln(&mut self.out, &format!("{} {{}}", header));
+        }
+
+        self.code
+            .writeln(&mut self.out, &format!("{} {{", header))?;
+        self.code.indent();
+
+        for (i, unit) in self.group(items).iter().enumerate() {
+            if i > 0 && unit.is_separated {
+                self.code.blank_line(&mut self.out)?;
+            }
+
+            self.unit(unit, |p, item, _| f(p, item, width))?;
+        }
+
+        self.code.outdent();
+        self.code.writeln(&mut self.out, "}")
+    }
+
+    /// `group` splits `items` into [`Unit`]s, attaching each freestanding
+    /// comment to the item which immediately follows it.
+    fn group<'i, T: Item>(&self, items: &'i [T]) -> Vec<Unit<'i, T>> {
+        let mut units = Vec::new();
+        let mut pending: Vec<&T> = Vec::new();
+
+        for item in items {
+            let is_separated = self.is_separated(item.span());
+
+            if is_separated {
+                for comment in pending.drain(..) {
+                    units.push(Unit::new(Vec::new(), comment, self));
+                }
+            }
+
+            if item.is_comment() {
+                pending.push(item);
+            } else {
+                units.push(Unit::new(std::mem::take(&mut pending), item, self));
+            }
+        }
+
+        for comment in pending {
+            units.push(Unit::new(Vec::new(), comment, self));
+        }
+
+        units
+    }
+
+    /// `unit` writes the attached comments of `unit` followed by its item.
+    fn unit<T: Item>(
+        &mut self,
+        unit: &Unit<T>,
+        f: impl Fn(&mut Self, &T, usize) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        for comment in &unit.comments {
+            f(self, comment, 0)?;
+        }
+
+        f(self, unit.item, 0)
+    }
+
+    /// `is_separated` returns whether the line preceding the one containing
+    /// the start of `span` is blank in the original source.
+    fn is_separated(&self, span: &Span) -> bool {
+        let prefix = &self.source[..span.start.min(self.source.len())];
+
+        let Some(line_start) = prefix.rfind('\n') else {
+            return false;
+        };
+
+        let previous = &prefix[..line_start];
+        let previous = &previous[previous.rfind('\n').map(|i| i + 1).unwrap_or(0)..];
+
+        previous.trim().is_empty()
+    }
+
+    /// `is_followed_by_blank_line` returns whether a blank line separates the
+    /// line containing the end of `span` from the next non-blank line.
+    fn is_followed_by_blank_line(&self, span: &Span) -> bool {
+        let suffix = &self.source[span.end.min(self.source.len())..];
+        let gap = &suffix[..suffix.find(|c: char| !c.is_whitespace()).unwrap_or(0)];
+
+        gap.matches('\n').count() > 1
+    }
+
+    /* ---------------------------- Fn: Comments ---------------------------- */
+
+    fn annotations(&mut self, annotations: &[ast::Annotation]) -> anyhow::Result<()> {
+        for annotation in annotations {
+            self.code.writeln(&mut self.out, &annotation.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// `comment_block` writes each comment in `comment`, preserving blank lines
+    /// between them (which the lexer doesn't distinguish from line breaks).
+    fn comment_block(&mut self, comment: &ast::CommentBlock) -> anyhow::Result<()> {
+        for (i, line) in comment.comments.iter().enumerate() {
+            if i > 0 && self.is_separated(&line.span) {
+                self.code.blank_line(&mut self.out)?;
+            }
+
+            self.code
+                .writeln(&mut self.out, &comment_text(line.content.trim_end()))?;
+        }
+
+        Ok(())
+    }
+
+    /// `comment_opt` writes the doc comment of a declaration, if any. A blank
+    /// line between the comment and the declaration is preserved.
+    fn comment_opt(&mut self, comment: Option<&ast::CommentBlock>) -> anyhow::Result<()> {
+        let Some(comment) = comment else {
+            return Ok(());
+        };
+
+        self.comment_block(comment)?;
+
+        match comment.comments.last() {
+            Some(last) if self.is_followed_by_blank_line(&last.span) => {
+                self.code.blank_line(&mut self.out)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// `line` writes a single statement, followed by its inline comment.
+    fn line(&mut self, text: String, comment: Option<&ast::Comment>) -> anyhow::Result<()> {
+        match comment.map(|c| c.content.trim_end()) {
+            Some(content) => self.code.writeln(
+                &mut self.out,
+                &format!("{} {}", text, comment_text(content)),
+            ),
+            None => self.code.writeln(&mut self.out, &text),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Trait: Item                                */
+/* -------------------------------------------------------------------------- */
+
+/// `Item` abstracts over the items of a schema, message, or enum.
+trait Item {
+    /// `is_comment` returns whether the item is a freestanding comment.
+    fn is_comment(&self) -> bool;
+
+    /// `span` returns the source location of the item.
+    fn span(&self) -> &Span;
+}
+
+/* ---------------------------- Impl: SchemaItem ---------------------------- */
+
+impl Item for ast::SchemaItem {
+    fn is_comment(&self) -> bool {
+        matches!(self, ast::SchemaItem::CommentBlock(_))
+    }
+
+    fn span(&self) -> &Span {
+        match self {
+            ast::SchemaItem::CommentBlock(comment) => &comment.span,
+            ast::SchemaItem::Enum(enum_) => &enum_.span,
+            ast::SchemaItem::Include(include) => &include.span,
+            ast::SchemaItem::Message(msg) => &msg.span,
+            ast::SchemaItem::Package(package) => &package.span,
+        }
+    }
+}
+
+/* ---------------------------- Impl: MessageItem --------------------------- */
+
+impl Item for ast::MessageItem {
+    fn is_comment(&self) -> bool {
+        matches!(self, ast::MessageItem::CommentBlock(_))
+    }
+
+    fn span(&self) -> &Span {
+        match self {
+            ast::MessageItem::CommentBlock(comment) => &comment.span,
+            ast::MessageItem::Enum(enum_) => &enum_.span,
+            ast::MessageItem::Field(field) => &field.span,
+            ast::MessageItem::Message(msg) => &msg.span,
+            ast::MessageItem::Reserved(reserved) => &reserved.span,
+        }
+    }
+}
+
+/* ----------------------------- Impl: EnumItem ----------------------------- */
+
+impl Item for ast::EnumItem {
+    fn is_comment(&self) -> bool {
+        matches!(self, ast::EnumItem::CommentBlock(_))
+    }
+
+    fn span(&self) -> &Span {
+        match self {
+            ast::EnumItem::CommentBlock(comment) => &comment.span,
+            ast::EnumItem::FieldVariant(field) => &field.span,
+            ast::EnumItem::Reserved(reserved) => &reserved.span,
+            ast::EnumItem::UnitVariant(variant) => &variant.span,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Struct: Unit                               */
+/* -------------------------------------------------------------------------- */
+
+/// `Unit` is an item along with the freestanding comments directly preceding
+/// it, which are kept together when printing.
+struct Unit<'i, T> {
+    comments: Vec<&'i T>,
+    /// `is_separated` is whether a blank line preceded the unit.
+    is_separated: bool,
+    item: &'i T,
+}
+
+/* ------------------------------- Impl: Unit ------------------------------- */
+
+impl<'i, T: Item> Unit<'i, T> {
+    fn new(comments: Vec<&'i T>, item: &'i T, printer: &Printer) -> Self {
+        let first = comments.first().copied().unwrap_or(item);
+
+        Self {
+            is_separated: printer.is_separated(first.span()),
+            comments,
+            item,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Fn: Helpers                                */
+/* -------------------------------------------------------------------------- */
+
+/* ---------------------------- Fn: comment_text ---------------------------- */
+
+/// `comment_text` formats a comment with the provided `content`. No space is
+/// inserted after the `//` if the content is empty or starts with a `/` (e.g.
+/// `///` doc comments and banners), so that such comments are left unchanged.
+fn comment_text(content: &str) -> String {
+    match content.is_empty() || content.starts_with('/') {
+        true => format!("//{}", content),
+        false => format!("// {}", content),
+    }
+}
+
+/* --------------------------- Fn: encoding_kind ---------------------------- */
+
+fn encoding_kind(kind: &ast::EncodingKind) -> String {
+    match kind {
+        ast::EncodingKind::Bits(n) => format!("bits({})", n),
+        ast::EncodingKind::BitsVariable(n) => format!("bits(var({}))", n),
+        ast::EncodingKind::Delta => "delta".to_owned(),
+        ast::EncodingKind::FixedPoint(i, f) => format!("fixed_point({}, {})", i, f),
+        ast::EncodingKind::Pad(n) => format!("pad({})", n),
+        ast::EncodingKind::ZigZag => "zigzag".to_owned(),
+    }
+}
+
+/* ------------------------------- Fn: index -------------------------------- */
+
+/// `index` formats a field or variant index, padded to `width` so that the
+/// declarations following it are aligned.
+fn index(index: Option<&ast::FieldIndex>, width: usize) -> String {
+    match index {
+        Some(index) => format!("{:<width$} ", format!("{}:", index.value)),
+        None => String::new(),
+    }
+}
+
+/* ---------------------------- Fn: index_width ----------------------------- */
+
+/// `index_width` returns the width of the widest formatted index.
+fn index_width<'i>(indices: impl Iterator<Item = &'i ast::FieldIndex>) -> usize {
+    indices
+        .map(|index| format!("{}:", index.value).len())
+        .max()
+        .unwrap_or(0)
+}
+
+/* --------------------------- Fn: path_to_string --------------------------- */
+
+/// `path_to_string` formats an include path using `/` separators.
+fn path_to_string(path: &std::path::Path) -> String {
+    path.iter().map(|c| c.to_string_lossy()).join("/")
+}
+
+/* -------------------------------- Fn: typ --------------------------------- */
+
+fn typ(kind: &ast::Type) -> String {
+    match kind {
+        ast::Type::Array(array) => format!(
+            "[{}]{}",
+            array
+                .size
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            typ(&array.element)
+        ),
+        ast::Type::Map(map) => format!("[{}]{}", typ(&map.key), typ(&map.value)),
+        ast::Type::Reference(reference) => reference.to_string(),
+        ast::Type::Scalar(scalar) => scalar.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::compile::parse_schema;
+    use crate::core::SchemaImport;
+
+    use super::*;
+
+    /* --------------------------- Tests: Printer --------------------------- */
+
+    #[test]
+    fn test_printer_normalizes_indentation_and_aligns_indices() {
+        // Given: A message with irregular indentation and layout.
+        let input = "package a;\nmessage M {\n  0: u8 a;\n      10: [4]f32 b = [bits(3), delta];\n  1: [string]u8 c = zigzag;\n}\nenum E { 0: A; 1: B; }\n";
+
+        // When: The schema is formatted.
+        let got = format(input);
+
+        // Then: Declarations are indented and indices aligned.
+        assert_eq!(
+            got,
+            "package a;\n\nmessage M {\n    0:  u8 a;\n    10: [4]f32 b = [bits(3), delta];\n    1:  [string]u8 c = zigzag;\n}\n\nenum E {\n    0: A;\n    1: B;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_printer_sorts_includes_after_package() {
+        // Given: A schema with unsorted includes and no blank lines.
+        let input = "// Header.\n\npackage a.b;\ninclude \"z.baproto\";\ninclude \"a/b.baproto\";\nmessage M {}\n";
+
+        // When: The schema is formatted.
+        let got = format(input);
+
+        // Then: The header, package, includes and body are separated.
+        assert_eq!(
+            got,
+            "// Header.\n\npackage a.b;\n\ninclude \"a/b.baproto\";\ninclude \"z.baproto\";\n\nmessage M {}\n"
+        );
+    }
+
+    #[test]
+    fn test_printer_keeps_comments_attached() {
+        // Given: A message with doc, inline, and freestanding comments.
+        let input = "package a;\n// Doc.\nmessage M {\n    // Field doc.\n    0: u8 a;   // Inline.\n\n    // Freestanding.\n\n    reserved 1 to 3;\n}\n";
+
+        // When: The schema is formatted.
+        let got = format(input);
+
+        // Then: Comments and blank lines are retained.
+        assert_eq!(
+            got,
+            "package a;\n\n// Doc.\nmessage M {\n    // Field doc.\n    0: u8 a; // Inline.\n\n    // Freestanding.\n\n    reserved 1 to 3;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_printer_preserves_triple_slash_and_banner_comments() {
+        // Given: A schema with '///' doc comments and banner comments.
+        let input = "package a;\n\n////////////////////\n//\n/// Doc.\nmessage M {\n    0: u8 a; /// Inline.\n}\n";
+
+        // When: The schema is formatted.
+        let got = format(input);
+
+        // Then: The comments are unchanged.
+        assert_eq!(got, input);
+    }
+
+    #[test]
+    fn test_printer_is_idempotent() {
+        // Given: Each schema in the test data directory.
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/testdata");
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "baproto")
+                || path.ends_with("syntax_errors.baproto")
+            {
+                continue;
+            }
+
+            // When: The schema is formatted twice.
+            let once = format(&std::fs::read_to_string(&path).unwrap());
+            let twice = format(&once);
+
+            // Then: Formatting the output again changes nothing.
+            assert_eq!(once, twice, "not idempotent: {}", path.display());
+        }
+    }
+
+    /* ----------------------------- Fn: format ----------------------------- */
+
+    fn format(input: &str) -> String {
+        let (ast, errors) = parse_schema(input, &SchemaImport::default());
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+
+        Printer::new(input).print(&ast.expect("should have output"))
+    }
+}
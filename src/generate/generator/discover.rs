@@ -0,0 +1,221 @@
+use is_executable::IsExecutable;
+use std::path::{Path, PathBuf};
+
+/* -------------------------------------------------------------------------- */
+/*                          Struct: PluginSearchPath                          */
+/* -------------------------------------------------------------------------- */
+
+/// `PluginSearchPath` locates external generators by name. By convention, the
+/// plugin `foo` is an executable named `baproto-gen-foo`.
+#[derive(Clone, Debug, Default)]
+pub struct PluginSearchPath {
+    /// `dirs` contains the directories to search, in order of precedence.
+    dirs: Vec<PathBuf>,
+}
+
+/* ------------------------- Impl: PluginSearchPath ------------------------- */
+
+impl PluginSearchPath {
+    /// `new` creates a [`PluginSearchPath`] which searches, in order, the
+    /// `plugins_dir` (if any), each directory on `PATH` and the directory
+    /// containing the running `baproto` binary.
+    pub fn new(plugins_dir: Option<&Path>) -> Self {
+        let mut dirs = Vec::new();
+
+        dirs.extend(plugins_dir.map(Path::to_owned));
+
+        if let Some(path) = std::env::var_os("PATH") {
+            dirs.extend(std::env::split_paths(&path).filter(|dir| !dir.as_os_str().is_empty()));
+        }
+
+        if let Some(dir) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_owned))
+        {
+            dirs.push(dir);
+        }
+
+        Self::from_dirs(dirs)
+    }
+
+    /// `from_dirs` creates a [`PluginSearchPath`] which searches exactly the
+    /// provided `dirs`, in order.
+    pub fn from_dirs<T: IntoIterator<Item = PathBuf>>(dirs: T) -> Self {
+        let mut unique: Vec<PathBuf> = Vec::new();
+
+        for dir in dirs {
+            if !unique.contains(&dir) {
+                unique.push(dir);
+            }
+        }
+
+        Self { dirs: unique }
+    }
+
+    /// `find` returns the path to the executable implementing the plugin
+    /// `name`, if one exists within the search path.
+    pub fn find(&self, name: &str) -> Option<PathBuf> {
+        let binary = plugin_binary_name(name);
+
+        self.dirs
+            .iter()
+            .map(|dir| dir.join(&binary))
+            .find(|path| path.is_file() && path.is_executable())
+    }
+
+    /// `list` returns all plugins within the search path, sorted by name. If a
+    /// plugin is found in multiple directories, only the one which takes
+    /// precedence is returned.
+    pub fn list(&self) -> Vec<(String, PathBuf)> {
+        let mut plugins: Vec<(String, PathBuf)> = Vec::new();
+
+        for dir in &self.dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+
+            let mut found = entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file() && path.is_executable())
+                .filter_map(|path| Some((plugin_name(&path)?, path)))
+                .collect::<Vec<_>>();
+
+            found.sort();
+
+            for (name, path) in found {
+                if !plugins.iter().any(|(n, _)| *n == name) {
+                    plugins.push((name, path));
+                }
+            }
+        }
+
+        plugins.sort_by(|a, b| a.0.cmp(&b.0));
+        plugins
+    }
+}
+
+/* ------------------------------ Impl: Display ----------------------------- */
+
+impl std::fmt::Display for PluginSearchPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let dirs = self
+            .dirs
+            .iter()
+            .map(|dir| format!("'{}'", dir.display()))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", dirs.join(", "))
+    }
+}
+
+/* ------------------------- Fn: plugin_binary_name ------------------------- */
+
+/// `plugin_binary_name` returns the file name of the executable implementing
+/// the plugin `name`.
+pub fn plugin_binary_name(name: &str) -> String {
+    format!("{}{}{}", PLUGIN_PREFIX, name, std::env::consts::EXE_SUFFIX)
+}
+
+/* ---------------------------- Fn: plugin_name ----------------------------- */
+
+/// `plugin_name` returns the name of the plugin implemented by the executable
+/// at `path`, if it follows the plugin naming convention.
+fn plugin_name(path: &Path) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let file_name = file_name
+        .strip_suffix(std::env::consts::EXE_SUFFIX)
+        .unwrap_or(file_name);
+
+    file_name
+        .strip_prefix(PLUGIN_PREFIX)
+        .filter(|name| !name.is_empty())
+        .map(str::to_owned)
+}
+
+/* -------------------------- Const: PLUGIN_PREFIX -------------------------- */
+
+/// `PLUGIN_PREFIX` is the prefix of the executable name of every plugin.
+pub const PLUGIN_PREFIX: &str = "baproto-gen-";
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /* ------------------------ Tests: PluginSearchPath ---------------------- */
+
+    #[test]
+    fn test_plugin_search_path_find_respects_precedence() {
+        // Given: Two directories which both contain the same plugin.
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        create_executable(first.path(), "baproto-gen-foo");
+        create_executable(second.path(), "baproto-gen-foo");
+
+        let search =
+            PluginSearchPath::from_dirs([first.path().to_owned(), second.path().to_owned()]);
+
+        // When: Finding the plugin.
+        let path = search.find("foo");
+
+        // Then: The plugin in the first directory is returned.
+        assert_eq!(path, Some(first.path().join("baproto-gen-foo")));
+        assert_eq!(search.find("bar"), None);
+    }
+
+    #[test]
+    fn test_plugin_search_path_find_skips_non_executables() {
+        // Given: A directory containing a non-executable plugin file.
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("baproto-gen-foo"), "").unwrap();
+
+        let search = PluginSearchPath::from_dirs([dir.path().to_owned()]);
+
+        // When: Finding the plugin.
+        // Then: It isn't found.
+        assert_eq!(search.find("foo"), None);
+    }
+
+    #[test]
+    fn test_plugin_search_path_list_returns_shadowing_plugins() {
+        // Given: Two directories containing plugins and other executables.
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        create_executable(first.path(), "baproto-gen-foo");
+        create_executable(first.path(), "protoc");
+        create_executable(second.path(), "baproto-gen-foo");
+        create_executable(second.path(), "baproto-gen-bar");
+        create_executable(second.path(), "baproto-gen-");
+
+        let search =
+            PluginSearchPath::from_dirs([first.path().to_owned(), second.path().to_owned()]);
+
+        // When: Listing the plugins.
+        let plugins = search.list();
+
+        // Then: Each plugin is listed once, using its first location.
+        assert_eq!(
+            plugins,
+            vec![
+                ("bar".to_owned(), second.path().join("baproto-gen-bar")),
+                ("foo".to_owned(), first.path().join("baproto-gen-foo")),
+            ]
+        );
+    }
+
+    /* ------------------------ Fn: create_executable ----------------------- */
+
+    fn create_executable(dir: &Path, name: &str) {
+        let path = dir.join(name);
+        std::fs::write(&path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+}
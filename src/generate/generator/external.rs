@@ -1,11 +1,11 @@
 use is_executable::IsExecutable;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 
-use crate::ir;
-
-use super::{Generator, GeneratorError, GeneratorOutput};
+use super::{Generator, GeneratorError, GeneratorOutput, GeneratorRequest, PluginRequest};
 
 /* -------------------------------------------------------------------------- */
 /*                           Struct: ExternalGenerator                        */
@@ -17,20 +17,46 @@ use super::{Generator, GeneratorError, GeneratorOutput};
 ///
 /// External generators follow a simple stdin/stdout JSON protocol:
 ///
-/// **Input (stdin):** JSON-serialized `ir::Schema`
+/// **Input (stdin):** JSON-serialized [`PluginRequest`], which wraps the
+/// `ir::Schema` alongside the protocol and compiler versions, the plugin's
+/// parameters and the explicitly requested files and packages:
+/// ```json
+/// {"protocol_version": 1, "compiler_version": "0.1.0", "parameters": {},
+///  "files": ["foo/bar.baproto"], "packages": [["foo", "bar"]], "schema": {...}}
+/// ```
 ///
-/// **Output (stdout):** JSON object with "files" key:
+/// Plugins written against the original protocol, which received the bare
+/// JSON-serialized `ir::Schema`, are supported via
+/// [`ExternalGenerator::with_legacy_protocol`].
+///
+/// **Output (stdout):** JSON object with "files" key and, optionally, any
+/// [`super::GeneratorDiagnostic`]s under the "diagnostics" key:
 /// ```json
-/// {"files": {"path/to/file.ext": "file contents..."}}
+/// {"files": {"path/to/file.ext": "file contents..."},
+///  "diagnostics": [{"severity": "warning", "message": "...",
+///                   "descriptor": {"package": ["foo"], "path": ["Bar"]},
+///                   "field": "baz"}]}
 /// ```
 ///
 /// **Exit codes:**
 /// - `0` = success
 /// - non-zero = failure (stderr contains error message)
+///
+/// ## Describe
+///
+/// When invoked with a single `--describe` argument, a plugin should instead
+/// print a [`PluginDescription`] to stdout:
+/// ```json
+/// {"name": "baproto-gen-cpp", "version": "1.2.0"}
+/// ```
 #[allow(unused)]
 pub struct ExternalGenerator {
     binary_path: PathBuf,
+    /// `legacy` selects the original protocol, i.e. writing the bare schema.
+    legacy: bool,
     name: String,
+    /// `parameters` contains the options passed through to the plugin.
+    parameters: BTreeMap<String, String>,
 }
 
 /* ------------------------- Impl: ExternalGenerator ------------------------ */
@@ -55,10 +81,71 @@ impl ExternalGenerator {
             .unwrap_or("external")
             .to_string();
 
-        Ok(Self { binary_path, name })
+        Ok(Self {
+            binary_path,
+            legacy: false,
+            name,
+            parameters: BTreeMap::default(),
+        })
+    }
+
+    /// `describe` asks the plugin to report its name and version via the
+    /// `--describe` handshake.
+    pub fn describe(&self) -> Result<PluginDescription, GeneratorError> {
+        let output = Command::new(&self.binary_path)
+            .arg("--describe")
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| {
+                GeneratorError::Process(format!(
+                    "failed to spawn {}: {}",
+                    self.binary_path.display(),
+                    e
+                ))
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GeneratorError::Process(format!(
+                "generator exited with status {}: {}",
+                output.status,
+                stderr.trim()
+            )));
+        }
+
+        serde_json::from_slice(&output.stdout).map_err(|e| {
+            GeneratorError::InvalidOutput(format!("failed to parse plugin description: {}", e))
+        })
+    }
+
+    /// `with_legacy_protocol` configures whether the bare `ir::Schema` is
+    /// written to the plugin instead of a [`PluginRequest`]. Parameters aren't
+    /// passed to plugins using the legacy protocol.
+    pub fn with_legacy_protocol(mut self, legacy: bool) -> Self {
+        self.legacy = legacy;
+        self
+    }
+
+    /// `with_parameters` sets the options passed through to the plugin.
+    pub fn with_parameters(mut self, parameters: BTreeMap<String, String>) -> Self {
+        self.parameters = parameters;
+        self
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                          Struct: PluginDescription                         */
+/* -------------------------------------------------------------------------- */
+
+/// `PluginDescription` is the self-reported identity of an external generator.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PluginDescription {
+    /// `name` is the plugin's name.
+    pub name: String,
+    /// `version` is the plugin's version.
+    pub version: String,
+}
+
 /* ----------------------------- Impl: Generator ---------------------------- */
 
 impl Generator for ExternalGenerator {
@@ -66,10 +153,13 @@ impl Generator for ExternalGenerator {
         &self.name
     }
 
-    fn generate(&self, schema: &ir::Schema) -> Result<GeneratorOutput, GeneratorError> {
-        // Serialize schema to JSON
-        let input = serde_json::to_string(schema)
-            .map_err(|e| GeneratorError::Serialization(e.to_string()))?;
+    fn generate(&self, request: &GeneratorRequest) -> Result<GeneratorOutput, GeneratorError> {
+        // Serialize request to JSON
+        let input = match self.legacy {
+            true => serde_json::to_string(&request.schema),
+            false => serde_json::to_string(&PluginRequest::new(request, &self.parameters)),
+        }
+        .map_err(|e| GeneratorError::Serialization(e.to_string()))?;
 
         // Spawn the generator process
         let mut child = Command::new(&self.binary_path)
@@ -3,13 +3,24 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use thiserror::Error;
 
-use crate::ir;
+use crate::analyze::Severity;
+use crate::core::Descriptor;
+
+/* ----------------------------- Mod: Discover ------------------------------ */
+
+mod discover;
+pub use discover::*;
 
 /* ----------------------------- Mod: External ------------------------------ */
 
 mod external;
 pub use external::*;
 
+/* ------------------------------ Mod: Request ------------------------------ */
+
+mod request;
+pub use request::*;
+
 /* -------------------------------- Mod: Rust ------------------------------- */
 
 mod rust;
@@ -25,7 +36,12 @@ pub use rust::*;
 /// The paths are relative to the output directory specified by the user.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GeneratorOutput {
+    /// `diagnostics` contains the warnings and errors reported by the
+    /// generator. The output's files are discarded if any errors are reported.
+    #[serde(default)]
+    pub diagnostics: Vec<GeneratorDiagnostic>,
     /// `files` is a map of relative file paths to their generated contents.
+    #[serde(default)]
     pub files: HashMap<PathBuf, String>,
 }
 
@@ -38,6 +54,30 @@ impl GeneratorOutput {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                          Struct: GeneratorDiagnostic                       */
+/* -------------------------------------------------------------------------- */
+
+/// `GeneratorDiagnostic` is a warning or error reported by a generator, e.g.
+/// about a declaration which it can't represent in the target language.
+///
+/// Diagnostics which reference a declaration are reported at its location in
+/// the source schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratorDiagnostic {
+    /// `descriptor` identifies the message or enum the diagnostic refers to.
+    #[serde(default)]
+    pub descriptor: Option<Descriptor>,
+    /// `field` names the field or variant within `descriptor` the diagnostic
+    /// refers to.
+    #[serde(default)]
+    pub field: Option<String>,
+    /// `message` describes the problem.
+    pub message: String,
+    /// `severity` is either `"error"` or `"warning"`.
+    pub severity: Severity,
+}
+
 /* -------------------------------------------------------------------------- */
 /*                            Enum: GeneratorError                            */
 /* -------------------------------------------------------------------------- */
@@ -89,9 +129,17 @@ pub trait Generator: Send + Sync {
     #[allow(unused)]
     fn name(&self) -> &str;
 
-    /// Generates code from the IR schema.
+    /// Returns the reserved keywords of the generator's target language.
+    ///
+    /// Schema declarations whose names collide with one of these keywords are
+    /// reported during compilation.
+    fn keywords(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Generates code from the IR schema contained in the request.
     ///
     /// Returns a map of relative file paths to their contents.
     /// The caller is responsible for writing these files to disk.
-    fn generate(&self, schema: &ir::Schema) -> Result<GeneratorOutput, GeneratorError>;
+    fn generate(&self, request: &GeneratorRequest) -> Result<GeneratorOutput, GeneratorError>;
 }
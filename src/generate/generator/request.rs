@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::core::PackageName;
+use crate::ir;
+
+/* -------------------------------------------------------------------------- */
+/*                           Struct: GeneratorRequest                         */
+/* -------------------------------------------------------------------------- */
+
+/// `GeneratorRequest` describes a single invocation of a [`super::Generator`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GeneratorRequest {
+    /// `files` contains the schema files explicitly requested for compilation
+    /// (i.e. excluding those which were only included). Paths are relative to
+    /// the import root containing them, if any.
+    pub files: Vec<PathBuf>,
+    /// `packages` contains the packages declared by the requested `files`.
+    pub packages: Vec<PackageName>,
+    /// `schema` is the compiled schema, including all included packages.
+    pub schema: ir::Schema,
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Struct: PluginRequest                           */
+/* -------------------------------------------------------------------------- */
+
+/// `PluginRequest` is the versioned envelope written to the stdin of an
+/// external generator.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PluginRequest {
+    /// `protocol_version` is the version of the plugin protocol; it's
+    /// incremented whenever the envelope changes incompatibly.
+    pub protocol_version: u32,
+    /// `compiler_version` is the version of the `baproto` compiler.
+    pub compiler_version: String,
+    /// `parameters` contains the plugin options specified by the user.
+    pub parameters: BTreeMap<String, String>,
+    /// `files` contains the schema files explicitly requested for compilation.
+    pub files: Vec<PathBuf>,
+    /// `packages` contains the packages declared by the requested `files`.
+    pub packages: Vec<PackageName>,
+    /// `schema` is the compiled schema, including all included packages.
+    pub schema: ir::Schema,
+}
+
+/* --------------------------- Impl: PluginRequest -------------------------- */
+
+impl PluginRequest {
+    /// `new` creates a new [`PluginRequest`] for the generator `request`,
+    /// passing the provided `parameters` through to the plugin.
+    pub fn new(request: &GeneratorRequest, parameters: &BTreeMap<String, String>) -> Self {
+        Self {
+            protocol_version: PROTOCOL_VERSION,
+            compiler_version: env!("CARGO_PKG_VERSION").to_owned(),
+            parameters: parameters.clone(),
+            files: request.files.clone(),
+            packages: request.packages.clone(),
+            schema: request.schema.clone(),
+        }
+    }
+}
+
+/* ------------------------- Const: PROTOCOL_VERSION ------------------------ */
+
+/// `PROTOCOL_VERSION` is the current version of the plugin protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* ------------------------ Tests: PluginRequest ------------------------ */
+
+    #[test]
+    fn test_plugin_request_serializes_envelope() {
+        // Given: A generator request and plugin parameters.
+        let request = GeneratorRequest {
+            files: vec![PathBuf::from("foo/bar.baproto")],
+            packages: vec!["foo.bar".parse().unwrap()],
+            schema: ir::Schema::default(),
+        };
+        let parameters = BTreeMap::from([("prefix".to_owned(), "Foo".to_owned())]);
+
+        // When: Serializing the plugin request.
+        let value = serde_json::to_value(PluginRequest::new(&request, &parameters)).unwrap();
+
+        // Then: The envelope contains the versions, parameters and request.
+        assert_eq!(value["protocol_version"], PROTOCOL_VERSION);
+        assert_eq!(value["compiler_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(value["parameters"]["prefix"], "Foo");
+        assert_eq!(value["files"][0], "foo/bar.baproto");
+        assert_eq!(value["packages"][0], serde_json::json!(["foo", "bar"]));
+        assert_eq!(value["schema"]["packages"], serde_json::json!([]));
+    }
+}
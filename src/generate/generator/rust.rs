@@ -1,10 +1,9 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use crate::generate::{Generator, GeneratorError, GeneratorOutput};
+use crate::generate::{Generator, GeneratorError, GeneratorOutput, GeneratorRequest};
 use crate::generate::{Language, rust};
 use crate::generate::{StringWriter, Writer};
-use crate::ir;
 
 /* -------------------------------------------------------------------------- */
 /*                            Struct: RustGenerator                           */
@@ -17,6 +16,17 @@ use crate::ir;
 #[allow(unused)]
 pub struct RustGenerator;
 
+/* ----------------------------- Const: KEYWORDS ---------------------------- */
+
+/// `KEYWORDS` contains Rust's strict, reserved, and weak keywords.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "union", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
 /* ----------------------------- Impl: Generator ---------------------------- */
 
 impl Generator for RustGenerator {
@@ -24,7 +34,12 @@ impl Generator for RustGenerator {
         "rust"
     }
 
-    fn generate(&self, schema: &ir::Schema) -> Result<GeneratorOutput, GeneratorError> {
+    fn keywords(&self) -> Vec<String> {
+        KEYWORDS.iter().map(|&k| k.to_owned()).collect()
+    }
+
+    fn generate(&self, request: &GeneratorRequest) -> Result<GeneratorOutput, GeneratorError> {
+        let schema = &request.schema;
         let mut rust_gen = rust::<StringWriter>();
         let mut writers = HashMap::<PathBuf, StringWriter>::new();
 
@@ -40,6 +40,7 @@ where
         &mut self,
         schema: &ir::Schema,
         pkg: &ir::Package,
+        current_pkg: &PackageName,
         w: &mut W,
     ) -> anyhow::Result<()>;
 
@@ -88,7 +89,7 @@ where
 
         // Generate includes for cross-package references
         for dep_pkg in find_package_dependencies(schema, pkg) {
-            self.gen_include(schema, dep_pkg, w)?;
+            self.gen_include(schema, dep_pkg, &pkg.name, w)?;
         }
 
         // Generate top-level enums first
@@ -73,14 +73,15 @@ impl<W: Writer> Language<W> for Rust {
 
     fn pkg_begin(&mut self, _: &ir::Schema, pkg: &ir::Package, w: &mut W) -> anyhow::Result<()> {
         // File header
-        self.0.writeln(
-            w,
-            &format!("//! Generated code for package `{}`.", pkg.name),
-        )?;
-        self.0.writeln(w, "//!")?;
+        //
+        // NOTE: Inner attributes and doc comments (i.e. `//!`) aren't allowed
+        // in files which are `include!`-ed, so only plain comments are used.
         self.0
-            .writeln(w, "//! This file was automatically generated by baproto.")?;
-        self.0.writeln(w, "//! Do not edit manually.")?;
+            .writeln(w, &format!("// Generated code for package `{}`.", pkg.name))?;
+        self.0.writeln(w, "//")?;
+        self.0
+            .writeln(w, "// This file was automatically generated by baproto.")?;
+        self.0.writeln(w, "// Do not edit manually.")?;
         self.0.blank_line(w)?;
 
         // Imports
@@ -100,22 +101,37 @@ impl<W: Writer> Language<W> for Rust {
         &mut self,
         _: &ir::Schema,
         dep_pkg: &ir::Package,
+        current_pkg: &PackageName,
         w: &mut W,
     ) -> anyhow::Result<()> {
         // Convert package path to Rust use statement
-        let rust_path = dep_pkg.name.to_string().replace('.', "::");
-        self.0.writeln(w, &format!("use crate::{};", rust_path))?;
+        let rust_path = format!(
+            "{}{}",
+            self.root_path(current_pkg),
+            dep_pkg.name.to_string().replace('.', "::")
+        );
+        self.0.writeln(w, &format!("use {};", rust_path))?;
         Ok(())
     }
 
     fn gen_msg_begin(
         &mut self,
-        _: &ir::Schema,
+        schema: &ir::Schema,
         msg: &ir::Message,
         w: &mut W,
     ) -> anyhow::Result<()> {
         // Doc comment
         self.0.comment_opt(w, msg.doc.as_deref())?;
+        self.deprecated(w, msg.deprecated.as_ref())?;
+
+        // Fields may refer to deprecated types.
+        if msg
+            .fields
+            .iter()
+            .any(|f| uses_deprecated(schema, &f.encoding.native))
+        {
+            self.0.writeln(w, "#[allow(deprecated)]")?;
+        }
 
         // Struct definition
         self.0.writeln(w, "#[derive(Debug, Clone, PartialEq)]")?;
@@ -131,7 +147,12 @@ impl<W: Writer> Language<W> for Rust {
         Ok(())
     }
 
-    fn gen_msg_end(&mut self, _: &ir::Schema, msg: &ir::Message, w: &mut W) -> anyhow::Result<()> {
+    fn gen_msg_end(
+        &mut self,
+        schema: &ir::Schema,
+        msg: &ir::Message,
+        w: &mut W,
+    ) -> anyhow::Result<()> {
         // Compute defaults before borrowing to avoid borrow conflicts
         let defaults: Vec<_> = msg
             .fields
@@ -144,7 +165,17 @@ impl<W: Writer> Language<W> for Rust {
         self.0.writeln(w, "}")?;
         self.0.blank_line(w)?;
 
+        // Deprecated items are still used by the generated implementations.
+        let allow_deprecated = msg.deprecated.is_some()
+            || msg
+                .fields
+                .iter()
+                .any(|f| f.deprecated.is_some() || uses_deprecated(schema, &f.encoding.native));
+
         // Impl block
+        if allow_deprecated {
+            self.0.writeln(w, "#[allow(deprecated)]")?;
+        }
         self.0.writeln(
             w,
             &format!(
@@ -203,6 +234,9 @@ impl<W: Writer> Language<W> for Rust {
         self.0.blank_line(w)?;
 
         // Default impl
+        if allow_deprecated {
+            self.0.writeln(w, "#[allow(deprecated)]")?;
+        }
         self.0.writeln(
             w,
             &format!(
@@ -223,9 +257,23 @@ impl<W: Writer> Language<W> for Rust {
         Ok(())
     }
 
-    fn gen_enum_begin(&mut self, _: &ir::Schema, e: &ir::Enum, w: &mut W) -> anyhow::Result<()> {
+    fn gen_enum_begin(
+        &mut self,
+        schema: &ir::Schema,
+        e: &ir::Enum,
+        w: &mut W,
+    ) -> anyhow::Result<()> {
         // Doc comment
         self.0.comment_opt(w, e.doc.as_deref())?;
+        self.deprecated(w, e.deprecated.as_ref())?;
+
+        // Variants may refer to deprecated types.
+        if e.variants.iter().any(|v| match v {
+            ir::Variant::Field { field, .. } => uses_deprecated(schema, &field.encoding.native),
+            ir::Variant::Unit { .. } => false,
+        }) {
+            self.0.writeln(w, "#[allow(deprecated)]")?;
+        }
 
         // Enum definition
         self.0
@@ -260,6 +308,7 @@ impl<W: Writer> Language<W> for Rust {
     ) -> anyhow::Result<()> {
         // Doc comment
         self.0.comment_opt(w, field.doc.as_deref())?;
+        self.deprecated(w, field.deprecated.as_ref())?;
 
         // Field declaration
         let rust_type = self.type_name(&field.encoding.native, current_pkg);
@@ -277,14 +326,25 @@ impl<W: Writer> Language<W> for Rust {
         w: &mut W,
     ) -> anyhow::Result<()> {
         match variant {
-            ir::Variant::Unit { name, doc, .. } => {
+            ir::Variant::Unit {
+                name,
+                doc,
+                deprecated,
+                ..
+            } => {
                 self.0.comment_opt(w, doc.as_deref())?;
+                self.deprecated(w, deprecated.as_ref())?;
                 self.0.writeln(w, &format!("{},", name))?;
             }
             ir::Variant::Field {
-                name, field, doc, ..
+                name,
+                field,
+                doc,
+                deprecated,
+                ..
             } => {
                 self.0.comment_opt(w, doc.as_deref())?;
+                self.deprecated(w, deprecated.as_ref())?;
                 let rust_type = self.type_name(&field.encoding.native, current_pkg);
                 self.0.writeln(w, &format!("{}({}),", name, rust_type))?;
             }
@@ -297,6 +357,21 @@ impl<W: Writer> Language<W> for Rust {
 /* ------------------------------- Impl: Rust ------------------------------- */
 
 impl Rust {
+    /// Writes a `#[deprecated]` attribute if the item has been deprecated.
+    fn deprecated<W: Writer>(
+        &self,
+        w: &mut W,
+        deprecation: Option<&ir::Deprecation>,
+    ) -> anyhow::Result<()> {
+        match deprecation {
+            Some(ir::Deprecation { note: Some(note) }) => self
+                .0
+                .writeln(w, &format!("#[deprecated(note = {:?})]", note)),
+            Some(ir::Deprecation { note: None }) => self.0.writeln(w, "#[deprecated]"),
+            None => Ok(()),
+        }
+    }
+
     /// Converts a descriptor string to a Rust type name.
     fn descriptor_to_rust_type(
         &self,
@@ -313,11 +388,20 @@ impl Rust {
             // Same package - use simple name (nested types are inlined)
             descriptor.name().unwrap().to_owned()
         } else {
-            // Cross-package reference - use crate:: qualified path
-            format!("crate::{}", parts.join("::"))
+            // Cross-package reference - use a path relative to the root module
+            format!("{}{}", self.root_path(current_package), parts.join("::"))
         }
     }
 
+    /// Returns the path prefix which refers to the module containing all
+    /// packages from within `current_package`.
+    ///
+    /// NOTE: Relative paths (rather than `crate::`) allow the generated modules
+    /// to be mounted anywhere, e.g. via `include!` within a nested module.
+    fn root_path(&self, current_package: &PackageName) -> String {
+        "super::".repeat(current_package.as_ref().len())
+    }
+
     /// Converts an IR NativeType to a Rust type string.
     pub fn type_name(&self, native: &ir::NativeType, current_package: &PackageName) -> String {
         match native {
@@ -367,6 +451,38 @@ impl Rust {
     }
 }
 
+/* -------------------------------------------------------------------------- */
+/*                          Function: uses_deprecated                         */
+/* -------------------------------------------------------------------------- */
+
+/// `uses_deprecated` returns whether `native` refers to a message or enum
+/// which has been deprecated within `schema`.
+fn uses_deprecated(schema: &ir::Schema, native: &ir::NativeType) -> bool {
+    match native {
+        ir::NativeType::Array { element } => uses_deprecated(schema, &element.native),
+        ir::NativeType::Map { key, value } => {
+            uses_deprecated(schema, &key.native) || uses_deprecated(schema, &value.native)
+        }
+        ir::NativeType::Message { descriptor } | ir::NativeType::Enum { descriptor } => schema
+            .packages
+            .iter()
+            .any(|pkg| is_deprecated(&pkg.messages, &pkg.enums, descriptor)),
+        _ => false,
+    }
+}
+
+/// `is_deprecated` returns whether the type identified by `descriptor` is
+/// one of the (possibly nested) `messages` or `enums` and has been deprecated.
+fn is_deprecated(messages: &[ir::Message], enums: &[ir::Enum], descriptor: &Descriptor) -> bool {
+    enums
+        .iter()
+        .any(|e| &e.descriptor == descriptor && e.deprecated.is_some())
+        || messages.iter().any(|msg| {
+            (&msg.descriptor == descriptor && msg.deprecated.is_some())
+                || is_deprecated(&msg.messages, &msg.enums, descriptor)
+        })
+}
+
 /* -------------------------------------------------------------------------- */
 /*                               Function: rust                               */
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/* -------------------------------------------------------------------------- */
+/*                             Struct: Deprecation                            */
+/* -------------------------------------------------------------------------- */
+
+/// `Deprecation` indicates that a declaration has been marked as deprecated.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deprecation {
+    /// Optional note describing what to use instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::Descriptor;
 
-use super::{Encoding, Field};
+use super::{Deprecation, Encoding, Field};
 
 /* -------------------------------------------------------------------------- */
 /*                                Struct: Enum                                */
@@ -11,6 +11,9 @@ use super::{Encoding, Field};
 /// `Enum` represents a fully resolved enum type.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enum {
+    /// `deprecated` is set if the [`Enum`] has been marked as deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
     /// `descriptor` uniquely identifies the [`Enum`].
     pub descriptor: Descriptor,
     /// `discriminant` describes the [`Enum`]'s discriminant encoding.
@@ -46,6 +49,8 @@ pub enum Variant {
         index: u32,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         doc: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deprecated: Option<Deprecation>,
     },
     /// Field variant with an associated field.
     Field {
@@ -54,5 +59,7 @@ pub enum Variant {
         field: Field,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         doc: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        deprecated: Option<Deprecation>,
     },
 }
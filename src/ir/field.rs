@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::Encoding;
+use super::{Deprecation, Encoding};
 
 /* -------------------------------------------------------------------------- */
 /*                               Struct: Field                                */
@@ -18,4 +18,7 @@ pub struct Field {
     /// Documentation comment.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub doc: Option<String>,
+    /// Deprecation status, if the field has been marked as deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
 }
@@ -0,0 +1,74 @@
+use super::TypeKind;
+
+use crate::ast;
+use crate::ir::Deprecation;
+
+use super::{Lower, LowerContext, TypeResolver};
+
+/* -------------------------------------------------------------------------- */
+/*                                 Impl: Lower                                */
+/* -------------------------------------------------------------------------- */
+
+impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Deprecation, LowerContext<'a, R>>
+    for Vec<ast::Annotation>
+{
+    fn lower(&'a self, _ctx: &'a LowerContext<'a, R>) -> Option<Deprecation> {
+        ast::Annotation::find_deprecated(self).map(|annotation| Deprecation {
+            note: annotation.note().map(str::to_owned),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::ast;
+    use crate::ir::lower;
+    use crate::lex::Span;
+
+    use super::*;
+
+    /* ------------------------- Tests: annotations ------------------------- */
+
+    #[test]
+    fn test_annotations_without_deprecated() {
+        // Given: No annotations.
+        let annotations: Vec<ast::Annotation> = vec![];
+
+        // When: Lowering the annotations.
+        let resolver = lower::MockResolver::new();
+        let ctx = lower::make_context(&resolver);
+        let result: Option<Deprecation> = annotations.lower(&ctx);
+
+        // Then: No deprecation is produced.
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_annotations_deprecated_with_note() {
+        // Given: A deprecated annotation with a note.
+        let annotations = vec![ast::Annotation {
+            kind: ast::AnnotationKind::Deprecated(Some(ast::Text {
+                content: "use pos_v2".to_string(),
+                span: Span::default(),
+            })),
+            span: Span::default(),
+        }];
+
+        // When: Lowering the annotations.
+        let resolver = lower::MockResolver::new();
+        let ctx = lower::make_context(&resolver);
+        let result: Option<Deprecation> = annotations.lower(&ctx);
+
+        // Then: The deprecation carries the note.
+        assert_eq!(
+            result,
+            Some(Deprecation {
+                note: Some("use pos_v2".to_string())
+            })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use super::TypeKind;
 
+use crate::analyze::DIRECTIVE_PREFIX;
 use crate::ast;
 
 use super::{Lower, LowerContext, TypeResolver};
@@ -14,6 +15,7 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, String, LowerContext<'a, R>> for a
             .comments
             .iter()
             .map(|c| c.content.trim())
+            .filter(|c| !c.starts_with(DIRECTIVE_PREFIX))
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -163,4 +165,30 @@ mod tests {
         // Then: Empty lines should become empty string lines in output.
         assert_eq!(result, Some("Content line\n\nAnother line".to_string()));
     }
+
+    #[test]
+    fn test_comment_omits_directives() {
+        // Given: A comment block containing a suppression directive.
+        let comment = ast::CommentBlock {
+            comments: vec![
+                ast::Comment {
+                    content: "baproto: allow(naming-convention)".to_string(),
+                    span: Span::default(),
+                },
+                ast::Comment {
+                    content: "  Documentation  ".to_string(),
+                    span: Span::default(),
+                },
+            ],
+            span: Span::default(),
+        };
+
+        // When: Lowering the comment.
+        let resolver = lower::MockResolver::new();
+        let ctx = lower::make_context(&resolver);
+        let result = comment.lower(&ctx);
+
+        // Then: Only the documentation is retained.
+        assert_eq!(result, Some("Documentation".to_string()));
+    }
 }
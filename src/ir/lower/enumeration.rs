@@ -57,8 +57,10 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Enum, LowerContext<'a, R>> for ast
         };
 
         let doc = self.comment.as_ref().and_then(|c| c.lower(ctx));
+        let deprecated = self.annotations.lower(ctx);
 
         Some(Enum {
+            deprecated,
             descriptor: child_ctx.scope,
             discriminant,
             doc,
@@ -73,11 +75,13 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Variant, LowerContext<'a, R>> for
     fn lower(&'a self, ctx: &'a LowerContext<'a, R>) -> Option<Variant> {
         let index = self.index.as_ref()?.value.value as u32;
         let doc = self.comment.as_ref().and_then(|c| c.lower(ctx));
+        let deprecated = self.annotations.lower(ctx);
 
         Some(Variant::Unit {
             name: self.name.name.clone(),
             index,
             doc,
+            deprecated,
         })
     }
 }
@@ -91,12 +95,14 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Variant, FieldVariantContext<'a, R
         // Lower the field normally
         let ir_field: Field = Lower::lower(self, ctx.0)?;
         let doc = self.comment.as_ref().and_then(|c| c.lower(ctx.0));
+        let deprecated = self.annotations.lower(ctx.0);
 
         Some(Variant::Field {
             name: self.name.name.clone(),
             index: ir_field.index,
             field: ir_field,
             doc,
+            deprecated,
         })
     }
 }
@@ -254,7 +260,9 @@ mod tests {
     fn test_unit_variant_basic() {
         // Given: A simple unit variant with index.
         let variant = ast::UnitVariant {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "Option1".to_string(),
                 span: Span::default(),
@@ -282,7 +290,8 @@ mod tests {
             Variant::Unit {
                 name: _,
                 index: 0,
-                doc: None
+                doc: None,
+                deprecated: None
             }
         ));
     }
@@ -291,7 +300,9 @@ mod tests {
     fn test_unit_variant_missing_index() {
         // Given: A unit variant without an index.
         let variant = ast::UnitVariant {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "NoIndex".to_string(),
                 span: Span::default(),
@@ -313,6 +324,7 @@ mod tests {
     fn test_unit_variant_with_doc_comment() {
         // Given: A unit variant with documentation.
         let variant = ast::UnitVariant {
+            annotations: vec![],
             comment: Some(ast::CommentBlock {
                 comments: vec![ast::Comment {
                     content: "Primary option".to_string(),
@@ -320,6 +332,7 @@ mod tests {
                 }],
                 span: Span::default(),
             }),
+            inline_comment: None,
             name: ast::Ident {
                 name: "Primary".to_string(),
                 span: Span::default(),
@@ -397,6 +410,7 @@ mod tests {
     fn test_enum_with_doc_comment() {
         // Given: An enum with documentation.
         let enum_ast = ast::Enum {
+            annotations: vec![],
             comment: Some(ast::CommentBlock {
                 comments: vec![ast::Comment {
                     content: "Represents status codes".to_string(),
@@ -409,7 +423,9 @@ mod tests {
                 span: Span::default(),
             },
             items: vec![ast::EnumItem::UnitVariant(ast::UnitVariant {
+                annotations: vec![],
                 comment: None,
+                inline_comment: None,
                 name: ast::Ident {
                     name: "Ok".to_string(),
                     span: Span::default(),
@@ -441,6 +457,7 @@ mod tests {
     fn test_enum_empty_variants() {
         // Given: An enum with no variants.
         let enum_ast = ast::Enum {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Empty".to_string(),
@@ -473,7 +490,9 @@ mod tests {
             .enumerate()
             .map(|(i, v)| {
                 ast::EnumItem::UnitVariant(ast::UnitVariant {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: v.as_ref().to_string(),
                         span: Span::default(),
@@ -491,6 +510,7 @@ mod tests {
             .collect();
 
         ast::Enum {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: name.to_string(),
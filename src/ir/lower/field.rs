@@ -21,12 +21,14 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Field, LowerContext<'a, R>> for as
         })?;
 
         let doc = self.comment.as_ref().and_then(|c| c.lower(ctx));
+        let deprecated = self.annotations.lower(ctx);
 
         Some(Field {
             name: self.name.name.clone(),
             index,
             encoding,
             doc,
+            deprecated,
         })
     }
 }
@@ -61,7 +63,9 @@ mod tests {
     fn test_field_basic_lowering() {
         // Given: A simple field with index and scalar type.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "health".to_string(),
                 span: Span::default(),
@@ -101,7 +105,9 @@ mod tests {
     fn test_field_missing_index() {
         // Given: A field without an index.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "score".to_string(),
                 span: Span::default(),
@@ -128,7 +134,9 @@ mod tests {
     fn test_field_with_encoding_annotation() {
         // Given: A field with custom encoding annotation.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "compressed".to_string(),
                 span: Span::default(),
@@ -181,6 +189,7 @@ mod tests {
     fn test_field_with_documentation() {
         // Given: A field with documentation comment.
         let field = ast::Field {
+            annotations: vec![],
             comment: Some(ast::CommentBlock {
                 comments: vec![ast::Comment {
                     content: "Player health points".to_string(),
@@ -188,6 +197,7 @@ mod tests {
                 }],
                 span: Span::default(),
             }),
+            inline_comment: None,
             name: ast::Ident {
                 name: "hp".to_string(),
                 span: Span::default(),
@@ -222,7 +232,9 @@ mod tests {
     fn test_field_array_type() {
         // Given: A field with array type.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "scores".to_string(),
                 span: Span::default(),
@@ -266,7 +278,9 @@ mod tests {
     fn test_field_string_type() {
         // Given: A field with string type.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "message".to_string(),
                 span: Span::default(),
@@ -305,7 +319,9 @@ mod tests {
     fn test_field_bool_type() {
         // Given: A field with bool type.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "active".to_string(),
                 span: Span::default(),
@@ -344,7 +360,9 @@ mod tests {
     fn test_field_with_padding() {
         // Given: A field with padding annotation.
         let field = ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: "padded".to_string(),
                 span: Span::default(),
@@ -40,8 +40,10 @@ impl<'a, R: TypeResolver<TypeKind>> Lower<'a, Message, LowerContext<'a, R>> for
         }
 
         let doc = self.comment.as_ref().and_then(|c| c.lower(ctx));
+        let deprecated = self.annotations.lower(ctx);
 
         Some(Message {
+            deprecated,
             descriptor: child_ctx.scope,
             fields,
             messages,
@@ -69,6 +71,7 @@ mod tests {
     fn test_message_empty() {
         // Given: A message with no fields or nested types.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Empty".to_string(),
@@ -96,6 +99,7 @@ mod tests {
     fn test_message_with_fields() {
         // Given: A message with multiple fields.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Player".to_string(),
@@ -103,7 +107,9 @@ mod tests {
             },
             items: vec![
                 ast::MessageItem::Field(ast::Field {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: "id".to_string(),
                         span: Span::default(),
@@ -123,7 +129,9 @@ mod tests {
                     span: Span::default(),
                 }),
                 ast::MessageItem::Field(ast::Field {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: "name".to_string(),
                         span: Span::default(),
@@ -163,6 +171,7 @@ mod tests {
     fn test_message_with_documentation() {
         // Given: A message with documentation.
         let message = ast::Message {
+            annotations: vec![],
             comment: Some(ast::CommentBlock {
                 comments: vec![ast::Comment {
                     content: "Represents a player in the game".to_string(),
@@ -196,12 +205,14 @@ mod tests {
     fn test_message_nested_message() {
         // Given: A message with a nested message.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Outer".to_string(),
                 span: Span::default(),
             },
             items: vec![ast::MessageItem::Message(ast::Message {
+                annotations: vec![],
                 comment: None,
                 name: ast::Ident {
                     name: "Inner".to_string(),
@@ -229,19 +240,23 @@ mod tests {
     fn test_message_nested_enum() {
         // Given: A message with a nested enum.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Config".to_string(),
                 span: Span::default(),
             },
             items: vec![ast::MessageItem::Enum(ast::Enum {
+                annotations: vec![],
                 comment: None,
                 name: ast::Ident {
                     name: "Mode".to_string(),
                     span: Span::default(),
                 },
                 items: vec![ast::EnumItem::UnitVariant(ast::UnitVariant {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: "Fast".to_string(),
                         span: Span::default(),
@@ -276,6 +291,7 @@ mod tests {
     fn test_message_mixed_items() {
         // Given: A message with fields, nested message, and nested enum.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Complex".to_string(),
@@ -283,7 +299,9 @@ mod tests {
             },
             items: vec![
                 ast::MessageItem::Field(ast::Field {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: "id".to_string(),
                         span: Span::default(),
@@ -303,6 +321,7 @@ mod tests {
                     span: Span::default(),
                 }),
                 ast::MessageItem::Message(ast::Message {
+                    annotations: vec![],
                     comment: None,
                     name: ast::Ident {
                         name: "Nested".to_string(),
@@ -312,6 +331,7 @@ mod tests {
                     span: Span::default(),
                 }),
                 ast::MessageItem::Enum(ast::Enum {
+                    annotations: vec![],
                     comment: None,
                     name: ast::Ident {
                         name: "Status".to_string(),
@@ -341,6 +361,7 @@ mod tests {
     fn test_message_field_ordering_preserved() {
         // Given: A message with fields in specific order.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Ordered".to_string(),
@@ -372,6 +393,7 @@ mod tests {
     fn test_message_skips_fields_without_index() {
         // Given: A message with a field missing an index.
         let message = ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: "Partial".to_string(),
@@ -380,7 +402,9 @@ mod tests {
             items: vec![
                 ast::MessageItem::Field(make_field("good", 0)),
                 ast::MessageItem::Field(ast::Field {
+                    annotations: vec![],
                     comment: None,
+                    inline_comment: None,
                     name: ast::Ident {
                         name: "bad".to_string(),
                         span: Span::default(),
@@ -413,7 +437,9 @@ mod tests {
 
     fn make_field(name: &str, index: u64) -> ast::Field {
         ast::Field {
+            annotations: vec![],
             comment: None,
+            inline_comment: None,
             name: ast::Ident {
                 name: name.to_string(),
                 span: Span::default(),
@@ -1,3 +1,4 @@
+mod annotation;
 mod comment;
 mod encoding;
 mod enumeration;
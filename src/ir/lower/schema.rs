@@ -73,6 +73,7 @@ mod tests {
         let schema = ast::Schema {
             items: vec![ast::SchemaItem::Package(ast::Package {
                 comment: None,
+                inline_comment: None,
                 components: vec![ast::Ident {
                     name: "mypackage".to_string(),
                     span: Span::default(),
@@ -105,6 +106,7 @@ mod tests {
             items: vec![
                 ast::SchemaItem::Package(ast::Package {
                     comment: None,
+                    inline_comment: None,
                     components: vec![ast::Ident {
                         name: "test".to_string(),
                         span: Span::default(),
@@ -112,6 +114,7 @@ mod tests {
                     span: Span::default(),
                 }),
                 ast::SchemaItem::Message(ast::Message {
+                    annotations: vec![],
                     comment: None,
                     name: ast::Ident {
                         name: "Player".to_string(),
@@ -143,6 +146,7 @@ mod tests {
             items: vec![
                 ast::SchemaItem::Package(ast::Package {
                     comment: None,
+                    inline_comment: None,
                     components: vec![ast::Ident {
                         name: "test".to_string(),
                         span: Span::default(),
@@ -150,6 +154,7 @@ mod tests {
                     span: Span::default(),
                 }),
                 ast::SchemaItem::Enum(ast::Enum {
+                    annotations: vec![],
                     comment: None,
                     name: ast::Ident {
                         name: "Status".to_string(),
@@ -181,6 +186,7 @@ mod tests {
             items: vec![
                 ast::SchemaItem::Package(ast::Package {
                     comment: None,
+                    inline_comment: None,
                     components: vec![ast::Ident {
                         name: "game".to_string(),
                         span: Span::default(),
@@ -215,6 +221,7 @@ mod tests {
             items: vec![
                 ast::SchemaItem::Package(ast::Package {
                     comment: None,
+                    inline_comment: None,
                     components: vec![ast::Ident {
                         name: "mixed".to_string(),
                         span: Span::default(),
@@ -249,6 +256,7 @@ mod tests {
         let schema = ast::Schema {
             items: vec![ast::SchemaItem::Package(ast::Package {
                 comment: None,
+                inline_comment: None,
                 components: vec![
                     ast::Ident {
                         name: "com".to_string(),
@@ -306,6 +314,7 @@ mod tests {
             items: vec![
                 ast::SchemaItem::Package(ast::Package {
                     comment: None,
+                    inline_comment: None,
                     components: vec![ast::Ident {
                         name: "ordered".to_string(),
                         span: Span::default(),
@@ -338,6 +347,7 @@ mod tests {
 
     fn make_enum(name: &str) -> ast::Enum {
         ast::Enum {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: name.to_string(),
@@ -352,6 +362,7 @@ mod tests {
 
     fn make_message(name: &str) -> ast::Message {
         ast::Message {
+            annotations: vec![],
             comment: None,
             name: ast::Ident {
                 name: name.to_string(),
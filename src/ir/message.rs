@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::Descriptor;
 
-use super::{Enum, Field};
+use super::{Deprecation, Enum, Field};
 
 /* -------------------------------------------------------------------------- */
 /*                              Struct: Message                               */
@@ -11,6 +11,9 @@ use super::{Enum, Field};
 /// `Message` represents a fully resolved message type with nested types inline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
+    /// `deprecated` is set if the [`Message`] has been marked as deprecated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
     /// `descriptor` uniquely identifies the [`Message`].
     pub descriptor: Descriptor,
     /// `doc` is a doc comment for the [`Message`].
@@ -15,6 +15,7 @@
 
 use serde::{Deserialize, Serialize};
 
+mod deprecation;
 mod encoding;
 mod enumeration;
 mod field;
@@ -22,6 +23,7 @@ pub mod lower;
 mod message;
 mod package;
 
+pub use deprecation::Deprecation;
 pub use encoding::{Encoding, NativeType, Transform, WireFormat};
 pub use enumeration::{Enum, Variant};
 pub use field::Field;
@@ -23,6 +23,7 @@ pub(super) fn keyword<'src>()
         chumsky::text::keyword("include").map(|_| Token::Keyword(Keyword::Include)),
         chumsky::text::keyword("message").map(|_| Token::Keyword(Keyword::Message)),
         chumsky::text::keyword("package").map(|_| Token::Keyword(Keyword::Package)),
+        chumsky::text::keyword("reserved").map(|_| Token::Keyword(Keyword::Reserved)),
     ))
     .map_with(spanned)
     .labelled("keyword")
@@ -64,6 +65,7 @@ pub(super) fn operator<'src>()
 pub(super) fn punctuation<'src>()
 -> impl Parser<'src, WithContext<Span, &'src str>, Spanned<Token<'src>, Span>, LexError<'src>> {
     choice((
+        just('@').map(|_| Token::At),
         just(',').map(|_| Token::Comma),
         just('.').map(|_| Token::Dot),
         just(';').map(|_| Token::Semicolon),
@@ -150,6 +152,18 @@ mod tests {
         assert_parses_to(result, Token::Keyword(Keyword::Package));
     }
 
+    #[test]
+    fn test_keyword_parses_reserved() {
+        // Given: The "reserved" keyword.
+        let input = "reserved";
+
+        // When: Parsing the keyword.
+        let result = parse_single(keyword(), input);
+
+        // Then: The keyword token is created successfully.
+        assert_parses_to(result, Token::Keyword(Keyword::Reserved));
+    }
+
     #[test]
     fn test_keyword_rejects_partial_match() {
         // Given: A string that starts like a keyword but isn't complete.
@@ -324,6 +338,18 @@ mod tests {
 
     /* -------------------------- Tests: punctuation ------------------------ */
 
+    #[test]
+    fn test_punctuation_parses_at() {
+        // Given: An at sign character.
+        let input = "@";
+
+        // When: Parsing the punctuation.
+        let result = parse_single(punctuation(), input);
+
+        // Then: The punctuation token is created successfully.
+        assert_parses_to(result, Token::At);
+    }
+
     #[test]
     fn test_punctuation_parses_comma() {
         // Given: A comma character.
@@ -10,6 +10,7 @@ pub enum Token<'src> {
     Invalid(&'src str),
 
     // Syntax
+    At,
     BlockClose,
     BlockOpen,
     Colon,
@@ -46,4 +47,5 @@ pub enum Keyword {
     Include,
     Message,
     Package,
+    Reserved,
 }
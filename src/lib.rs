@@ -1,27 +1,52 @@
-mod analyze;
 mod ast;
 mod cmd;
 mod lex;
 mod parse;
 mod visit;
 
+/* ------------------------------ Mod: Analyze ------------------------------ */
+
+mod analyze;
+pub use analyze::DiagnosticsFormat;
+pub use analyze::{Case, NamingRules, NamingRulesBuilder, NamingRulesBuilderError};
+pub use analyze::{Code, Level, Lint, LintLevels, Severity};
+pub use analyze::{Location, Position};
+
 /* ------------------------------ Mod: Compile ------------------------------ */
 
 mod compile;
-pub use compile::compile;
+pub use compile::{Codegen, CodegenBuilder, CodegenBuilderError};
+pub use compile::{CodegenDiagnostic, CodegenError, CodegenOutput};
+pub use compile::{CompileOptions, CompileOptionsBuilder, CompileOptionsBuilderError};
+pub use compile::{Depfile, GeneratorTarget, check, compile, lower, verify};
+pub use compile::{check_in, compile_in, lower_in};
+
+/* ------------------------------- Mod: Config ------------------------------ */
+
+mod config;
+pub use config::{NamingOptions, PROJECT_FILE_NAME, Project, ProjectError, ProjectOptions, Target};
 
 /* -------------------------------- Mod: Core ------------------------------- */
 
 mod core;
 pub use core::*;
 
+/* ------------------------------- Mod: Format ------------------------------ */
+
+mod format;
+pub use format::{format, format_source};
+
 /* ------------------------------ Mod: Generate ----------------------------- */
 
 mod generate;
 pub use generate::Language;
 pub use generate::{CodeWriter, CodeWriterBuilder, CodeWriterBuilderError};
+pub use generate::{ExternalGenerator, PluginDescription, PluginSearchPath, RustGenerator};
 pub use generate::{FileWriter, StringWriter, Writer};
-pub use generate::{Generator, GeneratorError, GeneratorOutput};
+pub use generate::{
+    Generator, GeneratorDiagnostic, GeneratorError, GeneratorOutput, GeneratorRequest,
+};
+pub use generate::{PLUGIN_PREFIX, PROTOCOL_VERSION, PluginRequest, plugin_binary_name};
 
 /* --------------------------------- Mod: IR -------------------------------- */
 
@@ -2,7 +2,9 @@ mod analyze;
 mod ast;
 mod cmd;
 mod compile;
+mod config;
 mod core;
+mod format;
 mod generate;
 mod ir;
 mod lex;
@@ -34,7 +36,22 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
+        /* ------------------------- Category: Check ------------------------ */
+        Commands::Check(args) => cmd::check::handle(args),
+
         /* ------------------------ Category: Compile ----------------------- */
         Commands::Compile(args) => cmd::compile::handle(args),
+
+        /* ------------------------ Category: Explain ----------------------- */
+        Commands::Explain(args) => cmd::explain::handle(args),
+
+        /* -------------------------- Category: Fmt ------------------------- */
+        Commands::Fmt(args) => cmd::fmt::handle(args),
+
+        /* --------------------------- Category: Ir ------------------------- */
+        Commands::Ir(args) => cmd::ir::handle(args),
+
+        /* ------------------------ Category: Plugins ----------------------- */
+        Commands::Plugins(args) => cmd::plugins::handle(args),
     }
 }
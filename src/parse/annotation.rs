@@ -0,0 +1,158 @@
+use chumsky::Parser;
+use chumsky::input::ValueInput;
+use chumsky::prelude::*;
+
+use crate::ast;
+use crate::lex::Span;
+use crate::lex::Token;
+use crate::parse;
+
+/* -------------------------------------------------------------------------- */
+/*                               Fn: annotations                              */
+/* -------------------------------------------------------------------------- */
+
+/// `annotations` creates a new [`Parser`] that parses zero or more annotations
+/// preceding a declaration. Each annotation may optionally be followed by a
+/// line break.
+pub(super) fn annotations<'src, I>()
+-> impl Parser<'src, I, Vec<ast::Annotation>, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    annotation()
+        .then_ignore(just(Token::Newline).repeated())
+        .repeated()
+        .collect::<Vec<_>>()
+}
+
+/* ------------------------------ Fn: annotation ----------------------------- */
+
+/// `annotation` creates a new [`Parser`] that parses a single `@`-prefixed
+/// annotation into an [`ast::Annotation`].
+pub(super) fn annotation<'src, I>()
+-> impl Parser<'src, I, ast::Annotation, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    just(Token::At)
+        .ignore_then(choice((deprecated(), root())))
+        .map_with(|kind, e| ast::Annotation {
+            kind,
+            span: e.span(),
+        })
+        .labelled("annotation")
+        .boxed()
+}
+
+/* ------------------------------ Fn: deprecated ----------------------------- */
+
+fn deprecated<'src, I>()
+-> impl Parser<'src, I, ast::AnnotationKind, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    just(Token::Ident("deprecated"))
+        .ignore_then(
+            parse::text()
+                .delimited_by(just(Token::FnOpen), just(Token::FnClose))
+                .or_not(),
+        )
+        .map(ast::AnnotationKind::Deprecated)
+}
+
+/* --------------------------------- Fn: root -------------------------------- */
+
+fn root<'src, I>()
+-> impl Parser<'src, I, ast::AnnotationKind, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    just(Token::Ident("root")).to(ast::AnnotationKind::Root)
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::tests::*;
+
+    /* -------------------------- Tests: annotation ------------------------- */
+
+    #[test]
+    fn test_annotation_deprecated_succeeds() {
+        // Given: A deprecated annotation without a note.
+        let input = "@deprecated";
+
+        // When: The input is parsed.
+        let annotation = assert_parse_succeeds(parse_single(input, annotation()));
+
+        // Then: The annotation has no note.
+        assert_eq!(annotation.kind, ast::AnnotationKind::Deprecated(None));
+        assert_eq!(annotation.note(), None);
+    }
+
+    #[test]
+    fn test_annotation_deprecated_with_note_succeeds() {
+        // Given: A deprecated annotation with a note.
+        let input = "@deprecated(\"use pos_v2\")";
+
+        // When: The input is parsed.
+        let annotation = assert_parse_succeeds(parse_single(input, annotation()));
+
+        // Then: The note is captured.
+        assert_eq!(annotation.note(), Some("use pos_v2"));
+    }
+
+    #[test]
+    fn test_annotation_root_succeeds() {
+        // Given: A root annotation.
+        let input = "@root";
+
+        // When: The input is parsed.
+        let annotation = assert_parse_succeeds(parse_single(input, annotation()));
+
+        // Then: The annotation marks a root type.
+        assert_eq!(annotation.kind, ast::AnnotationKind::Root);
+    }
+
+    #[test]
+    fn test_annotation_unknown_name_fails() {
+        // Given: An unsupported annotation.
+        let input = "@unknown";
+
+        // When: The input is parsed.
+        let result = parse_single(input, annotation());
+
+        // Then: Parsing fails.
+        assert_parse_fails(result);
+    }
+
+    #[test]
+    fn test_annotation_missing_name_fails() {
+        // Given: An annotation marker without a name.
+        let input = "@(\"note\")";
+
+        // When: The input is parsed.
+        let result = parse_single(input, annotation());
+
+        // Then: Parsing fails.
+        assert_parse_fails(result);
+    }
+
+    /* ------------------------- Tests: annotations ------------------------- */
+
+    #[test]
+    fn test_annotations_across_lines_succeeds() {
+        // Given: Multiple annotations separated by line breaks.
+        let input = "@deprecated\n@deprecated(\"again\")\n";
+
+        // When: The input is parsed.
+        let annotations = assert_parse_succeeds(parse_single(input, annotations()));
+
+        // Then: Both annotations are captured.
+        assert_eq!(annotations.len(), 2);
+    }
+}
@@ -40,19 +40,17 @@ where
 /*                             Fn: inline_comment                             */
 /* -------------------------------------------------------------------------- */
 
-#[allow(unused)] // TODO(#60): Handle inline comments during parsing.
 /// `inline_comment` creates a new [`Parser`] that parses an inline comment
 /// (i.e. a comment that comes after [`Token`]s within a line).
+///
+/// NOTE: Because line breaks are tokenized, a comment which immediately
+/// follows another token is necessarily on the same line as it.
 pub(super) fn inline_comment<'src, I>()
 -> impl Parser<'src, I, ast::Comment, chumsky::extra::Err<parse::ParseError<'src>>>
 where
     I: ValueInput<'src, Token = Token<'src>, Span = Span>,
 {
-    just(Token::Newline)
-        .not()
-        .ignore_then(comment())
-        .then_ignore(just(Token::Newline))
-        .labelled("inline comment")
+    comment().labelled("inline comment")
 }
 
 /* -------------------------------------------------------------------------- */
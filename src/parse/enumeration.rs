@@ -21,22 +21,29 @@ where
 {
     parse::comment_block()
         .or_not()
+        .then(parse::annotations())
         .then(just(Token::Keyword(Keyword::Enum)).ignore_then(parse::ident()))
         .then(
             choice((
+                parse::reserved().map(ast::EnumItem::Reserved),
                 parse::field().map(ast::EnumItem::FieldVariant),
                 unit_variant().map(ast::EnumItem::UnitVariant),
                 parse::comment_block().map(ast::EnumItem::CommentBlock),
             ))
+            .map(Some)
+            // Skip malformed items so that later errors are reported too.
+            .recover_with(via_parser(parse::skip_statement().to(None)))
             // FIXME: Handle newlines before, between, and after.
             .separated_by(just(Token::Newline).repeated())
             .allow_leading()
             .allow_trailing()
-            .collect::<Vec<ast::EnumItem>>()
+            .collect::<Vec<Option<ast::EnumItem>>>()
+            .map(|items| items.into_iter().flatten().collect())
             .delimited_by(just(Token::BlockOpen), just(Token::BlockClose)),
         )
         .then_ignore(just(Token::Newline).repeated())
-        .map_with(|((comment, name), items), e| ast::Enum {
+        .map_with(|(((comment, annotations), name), items), e| ast::Enum {
+            annotations,
             comment,
             items,
             name,
@@ -57,14 +64,24 @@ where
 {
     parse::comment_block()
         .or_not()
+        .then(parse::annotations())
         .then(parse::field_index().or_not())
         .then(parse::ident())
         .then_ignore(just(Token::Semicolon))
-        .map_with(|((comment, index), name), e| ast::UnitVariant {
-            comment,
-            index,
-            name,
-            span: e.span(),
+        .map_with(
+            |(((comment, annotations), index), name), e| ast::UnitVariant {
+                annotations,
+                comment,
+                index,
+                inline_comment: None,
+                name,
+                span: e.span(),
+            },
+        )
+        .then(parse::inline_comment().or_not())
+        .map(|(variant, inline_comment)| ast::UnitVariant {
+            inline_comment,
+            ..variant
         })
         .labelled("unit variant")
         .boxed()
@@ -382,6 +399,24 @@ mod tests {
         assert_eq!(field.name.name, "error_code");
     }
 
+    #[test]
+    fn test_enumeration_with_reserved_succeeds() {
+        // Given: An enum with a reserved statement.
+        let input = "enum Status {\nreserved 1, \"PENDING\";\n0: OK;\n}";
+
+        // When: The input is parsed.
+        let enumeration = assert_parse_succeeds(parse_single(input, enumeration()));
+
+        // Then: The reserved statement precedes the variant.
+        assert_eq!(enumeration.items.len(), 2);
+
+        let ast::EnumItem::Reserved(reserved) = &enumeration.items[0] else {
+            panic!("expected reserved statement");
+        };
+        assert!(reserved.items[0].contains_index(1));
+        assert!(reserved.items[1].contains_name("PENDING"));
+    }
+
     #[test]
     fn test_enumeration_mixed_item_types_succeeds() {
         // Given: An enum with mixed item types.
@@ -446,4 +481,25 @@ mod tests {
         // Then: Leading newlines don't create extra items.
         assert_eq!(enumeration.items.len(), 1);
     }
+
+    /* -------------------------- Tests: recovery --------------------------- */
+
+    #[test]
+    fn test_enumeration_recovers_from_malformed_variant() {
+        // Given: An enum with a malformed variant between valid ones.
+        let input = "enum Status {\n0: OK;\n1: = Err;\n2: Unknown;\n}";
+
+        // When: The input is parsed.
+        let (enumeration, errors): (Option<ast::Enum>, _) = parse_single(input, enumeration());
+
+        // Then: The error is reported and the valid variants are retained.
+        assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+        assert_eq!(
+            enumeration
+                .expect("should have recovered output")
+                .items
+                .len(),
+            2
+        );
+    }
 }
@@ -27,23 +27,30 @@ where
     recursive(move |msg| {
         parse::comment_block()
             .or_not()
+            .then(parse::annotations())
             .then(just(Token::Keyword(Keyword::Message)).ignore_then(parse::ident()))
             .then(
                 choice((
                     msg.map(ast::MessageItem::Message),
                     parse::enumeration().map(ast::MessageItem::Enum),
+                    parse::reserved().map(ast::MessageItem::Reserved),
                     field().map(ast::MessageItem::Field),
                     parse::comment_block().map(ast::MessageItem::CommentBlock),
                 ))
+                .map(Some)
+                // Skip malformed items so that later errors are reported too.
+                .recover_with(via_parser(parse::skip_statement().to(None)))
                 // FIXME: Handle newlines before, between, and after.
                 .separated_by(just(Token::Newline).repeated())
                 .allow_leading()
                 .allow_trailing()
-                .collect::<Vec<ast::MessageItem>>()
+                .collect::<Vec<Option<ast::MessageItem>>>()
+                .map(|items| items.into_iter().flatten().collect())
                 .delimited_by(just(Token::BlockOpen), just(Token::BlockClose)),
             )
             .then_ignore(just(Token::Newline).repeated())
-            .map_with(|((comment, name), items), e| ast::Message {
+            .map_with(|(((comment, annotations), name), items), e| ast::Message {
+                annotations,
                 comment,
                 items,
                 name,
@@ -77,21 +84,29 @@ where
 {
     parse::comment_block()
         .or_not()
+        .then(parse::annotations())
         .then(field_index().or_not())
         .then(parse::typ())
         .then(parse::ident())
         .then(just(Token::Equal).ignore_then(parse::encoding()).or_not())
         .then_ignore(just(Token::Semicolon))
         .map_with(
-            |((((comment, index), typ), name), encoding), e| ast::Field {
+            |(((((comment, annotations), index), typ), name), encoding), e| ast::Field {
+                annotations,
                 comment,
                 encoding,
                 index,
+                inline_comment: None,
                 kind: typ,
                 name,
                 span: e.span(),
             },
         )
+        .then(parse::inline_comment().or_not())
+        .map(|(field, inline_comment)| ast::Field {
+            inline_comment,
+            ..field
+        })
         .labelled("field")
         .boxed()
 }
@@ -467,6 +482,35 @@ mod tests {
         assert_eq!(comment.comments[0].content, "Current health");
     }
 
+    #[test]
+    fn test_message_field_with_inline_comment_succeeds() {
+        // Given: A message with fields followed by a same-line comment.
+        let input = "message Player {\nu8 health; // Current health\nu8 armor;\n}";
+
+        // When: The input is parsed.
+        let (msg, errors): (Option<ast::Message>, _) =
+            parse_single(input, message(parse::MAX_RECURSION_DEPTH));
+
+        // Then: Parsing succeeds.
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        let msg = msg.expect("should have output");
+
+        // Then: The comment trails the first field rather than documenting
+        // the second.
+        let [
+            ast::MessageItem::Field(first),
+            ast::MessageItem::Field(second),
+        ] = &msg.items[..]
+        else {
+            panic!("expected two fields");
+        };
+        let inline = first.inline_comment.as_ref().expect("should have comment");
+        assert_eq!(inline.content, "Current health");
+        assert!(first.comment.is_none());
+        assert!(second.comment.is_none());
+        assert!(second.inline_comment.is_none());
+    }
+
     #[test]
     fn test_message_with_nested_message_succeeds() {
         // Given: A message with a nested message.
@@ -537,6 +581,52 @@ mod tests {
         assert!(matches!(msg.items[3], ast::MessageItem::Field(_)));
     }
 
+    #[test]
+    fn test_message_with_reserved_succeeds() {
+        // Given: A message with a reserved statement.
+        let input = "message Player {\nreserved 2, 4 to 6, \"score\";\n0: u8 id;\n}";
+
+        // When: The input is parsed.
+        let (msg, errors): (Option<ast::Message>, _) =
+            parse_single(input, message(parse::MAX_RECURSION_DEPTH));
+
+        // Then: Parsing succeeds.
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        let msg = msg.expect("should have output");
+
+        // Then: The reserved statement precedes the field.
+        assert_eq!(msg.items.len(), 2);
+
+        let ast::MessageItem::Reserved(reserved) = &msg.items[0] else {
+            panic!("expected reserved statement");
+        };
+        assert_eq!(reserved.items.len(), 3);
+        assert!(matches!(msg.items[1], ast::MessageItem::Field(_)));
+    }
+
+    #[test]
+    fn test_message_with_annotations_succeeds() {
+        // Given: A deprecated message with a deprecated field.
+        let input = "@deprecated\nmessage Old {\n@deprecated(\"use b\")\n0: u8 a;\n}";
+
+        // When: The input is parsed.
+        let (msg, errors): (Option<ast::Message>, _) =
+            parse_single(input, message(parse::MAX_RECURSION_DEPTH));
+
+        // Then: Parsing succeeds.
+        assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+        let msg = msg.expect("should have output");
+
+        // Then: Both the message and field carry annotations.
+        assert!(ast::Annotation::find_deprecated(&msg.annotations).is_some());
+
+        let ast::MessageItem::Field(field) = &msg.items[0] else {
+            panic!("expected field");
+        };
+        let annotation = ast::Annotation::find_deprecated(&field.annotations);
+        assert_eq!(annotation.and_then(|a| a.note()), Some("use b"));
+    }
+
     #[test]
     fn test_message_deeply_nested_succeeds() {
         // Given: Messages nested to depth 3.
@@ -653,4 +743,45 @@ mod tests {
         // When: The input is parsed.
         assert_parse_fails(parse_single(input, field_index()));
     }
+
+    /* -------------------------- Tests: recovery --------------------------- */
+
+    #[test]
+    fn test_message_recovers_from_malformed_fields() {
+        // Given: A message with two malformed fields between valid ones.
+        let input = "message Foo {\n0: u32 = a;\n1: u32 b;\n2: [u8 c;\n3: u32 d;\n}";
+
+        // When: The input is parsed.
+        let (msg, errors): (Option<ast::Message>, _) =
+            parse_single(input, message(parse::MAX_RECURSION_DEPTH));
+
+        // Then: Both errors are reported.
+        assert_eq!(errors.len(), 2, "unexpected errors: {:?}", errors);
+
+        // Then: The valid fields are retained.
+        let msg = msg.expect("should have recovered output");
+        let names = msg
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ast::MessageItem::Field(f) => Some(f.name.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["b", "d"]);
+    }
+
+    #[test]
+    fn test_message_recovers_from_malformed_nested_message() {
+        // Given: A message with a malformed nested message.
+        let input = "message Foo {\nmessage {\nu8 a;\n}\n0: u32 b;\n}";
+
+        // When: The input is parsed.
+        let (msg, errors): (Option<ast::Message>, _) =
+            parse_single(input, message(parse::MAX_RECURSION_DEPTH));
+
+        // Then: The error is reported and the valid field is retained.
+        assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+        assert_eq!(msg.expect("should have recovered output").items.len(), 1);
+    }
 }
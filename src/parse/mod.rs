@@ -1,3 +1,8 @@
+/* ----------------------------- Mod: Annotation ---------------------------- */
+
+mod annotation;
+use annotation::*;
+
 /* ------------------------------ Mod: Comment ------------------------------ */
 
 mod comment;
@@ -23,6 +28,16 @@ use message::*;
 mod package;
 use package::*;
 
+/* ------------------------------ Mod: Recovery ----------------------------- */
+
+mod recovery;
+use recovery::*;
+
+/* ------------------------------ Mod: Reserved ----------------------------- */
+
+mod reserved;
+use reserved::*;
+
 /* ------------------------------- Mod: Schema ------------------------------ */
 
 mod schema;
@@ -37,8 +37,14 @@ where
         .map_with(|(comment, components), e| ast::Package {
             comment,
             components,
+            inline_comment: None,
             span: e.span(),
         })
+        .then(parse::inline_comment().or_not())
+        .map(|(package, inline_comment)| ast::Package {
+            inline_comment,
+            ..package
+        })
         .labelled("package")
         .boxed()
 }
@@ -60,7 +66,11 @@ where
         .map_with(|t, e| (t, e.span()))
         .validate(
             |(t, span), _, emitter| match import_path().parse(&t.content).into_result() {
-                Ok(path) => ast::Include { path, span },
+                Ok(path) => ast::Include {
+                    inline_comment: None,
+                    path,
+                    span,
+                },
                 Err(errs) => {
                     for err in errs {
                         let msg = format!("{}", err.reason());
@@ -68,12 +78,18 @@ where
                     }
 
                     ast::Include {
+                        inline_comment: None,
                         path: PathBuf::default(),
                         span,
                     }
                 }
             },
         )
+        .then(parse::inline_comment().or_not())
+        .map(|(include, inline_comment)| ast::Include {
+            inline_comment,
+            ..include
+        })
         .labelled("include")
         .boxed()
 }
@@ -214,6 +230,19 @@ mod tests {
         assert_eq!(import.path.to_str().unwrap(), "foo/bar.baproto");
     }
 
+    #[test]
+    fn test_import_with_inline_comment_succeeds() {
+        // Given: An import statement followed by a same-line comment.
+        let input = "include \"foo/bar.baproto\"; // Shared types";
+
+        // When: The input is parsed.
+        let import = assert_parse_succeeds(parse_single(input, import()));
+
+        // Then: The comment is captured.
+        let comment = import.inline_comment.expect("should have comment");
+        assert_eq!(comment.content, "Shared types");
+    }
+
     #[test]
     fn test_import_rejects_dot_prefix() {
         // Given: An import with a ./ prefixed path.
@@ -0,0 +1,88 @@
+use chumsky::Parser;
+use chumsky::input::ValueInput;
+use chumsky::prelude::*;
+
+use crate::lex::Span;
+use crate::lex::Token;
+use crate::parse;
+
+/* -------------------------------------------------------------------------- */
+/*                             Fn: skip_statement                             */
+/* -------------------------------------------------------------------------- */
+
+/// `skip_statement` creates a recovery [`Parser`] which skips over a malformed
+/// statement: everything up to and including the next `;`, or the next
+/// balanced `{ ... }` block. It never consumes the `}` which closes an
+/// enclosing block, allowing parsing to resume with the next statement.
+pub(super) fn skip_statement<'src, I>()
+-> impl Parser<'src, I, (), chumsky::extra::Err<parse::ParseError<'src>>> + Clone
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    let stop = [Token::Semicolon, Token::BlockOpen, Token::BlockClose];
+
+    let terminator = choice((
+        just(Token::Semicolon).ignored(),
+        nested_delimiters(Token::BlockOpen, Token::BlockClose, [], |_| ()),
+    ));
+
+    choice((
+        none_of(stop)
+            .repeated()
+            .at_least(1)
+            .then(terminator.clone().or_not())
+            .ignored(),
+        terminator,
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::tests::*;
+
+    /* ------------------------ Tests: skip_statement ----------------------- */
+
+    #[test]
+    fn test_skip_statement_through_semicolon_succeeds() {
+        // Given: A malformed statement terminated by a semicolon.
+        let input = "0: u32 = ;";
+
+        // When: The input is parsed.
+        assert_parse_succeeds(parse_single(input, skip_statement()));
+    }
+
+    #[test]
+    fn test_skip_statement_through_nested_block_succeeds() {
+        // Given: A malformed declaration with a nested block.
+        let input = "message { message Inner { u8 a; } }";
+
+        // When: The input is parsed.
+        assert_parse_succeeds(parse_single(input, skip_statement()));
+    }
+
+    #[test]
+    fn test_skip_statement_stops_before_closing_brace() {
+        // Given: A malformed statement followed by the end of a block.
+        let input = "0: u32 x\n}";
+
+        // When: The input is parsed, followed by the closing brace.
+        let parser = skip_statement().then(just(Token::BlockClose));
+
+        // Then: The closing brace isn't consumed by recovery.
+        assert_parse_succeeds(parse_single(input, parser));
+    }
+
+    #[test]
+    fn test_skip_statement_at_closing_brace_fails() {
+        // Given: Only the end of a block.
+        let input = "}";
+
+        // When: The input is parsed.
+        assert_parse_fails(parse_single(input, skip_statement()));
+    }
+}
@@ -0,0 +1,201 @@
+use chumsky::Parser;
+use chumsky::input::ValueInput;
+use chumsky::prelude::*;
+
+use crate::ast;
+use crate::lex::Keyword;
+use crate::lex::Span;
+use crate::lex::Token;
+use crate::parse;
+
+/* -------------------------------------------------------------------------- */
+/*                                Fn: reserved                                */
+/* -------------------------------------------------------------------------- */
+
+/// `reserved` creates a new [`Parser`] that parses a `reserved` statement into
+/// an [`ast::Reserved`].
+pub(super) fn reserved<'src, I>()
+-> impl Parser<'src, I, ast::Reserved, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    parse::comment_block()
+        .or_not()
+        .then(
+            just(Token::Keyword(Keyword::Reserved)).ignore_then(
+                reserved_item()
+                    .separated_by(just(Token::Comma))
+                    .at_least(1)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+        .then_ignore(just(Token::Semicolon))
+        .map_with(|(comment, items), e| ast::Reserved {
+            comment,
+            inline_comment: None,
+            items,
+            span: e.span(),
+        })
+        .then(parse::inline_comment().or_not())
+        .map(|(reserved, inline_comment)| ast::Reserved {
+            inline_comment,
+            ..reserved
+        })
+        .labelled("reserved")
+        .boxed()
+}
+
+/* ---------------------------- Fn: reserved_item --------------------------- */
+
+/// `reserved_item` creates a new [`Parser`] that parses a single reserved index,
+/// index range, or name into an [`ast::ReservedItem`].
+fn reserved_item<'src, I>()
+-> impl Parser<'src, I, ast::ReservedItem, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    choice((
+        reserved_range().map(ast::ReservedItem::Range),
+        parse::uint().map(ast::ReservedItem::Index),
+        parse::text().map(ast::ReservedItem::Name),
+    ))
+}
+
+/* --------------------------- Fn: reserved_range --------------------------- */
+
+/// `reserved_range` creates a new [`Parser`] that parses an inclusive range of
+/// indices (e.g. `5 to 8`) into an [`ast::ReservedRange`].
+fn reserved_range<'src, I>()
+-> impl Parser<'src, I, ast::ReservedRange, chumsky::extra::Err<parse::ParseError<'src>>>
+where
+    I: ValueInput<'src, Token = Token<'src>, Span = Span>,
+{
+    parse::uint()
+        .then_ignore(just(Token::Ident("to")))
+        .then(parse::uint())
+        .map_with(|(start, end), e| ast::ReservedRange {
+            end,
+            span: e.span(),
+            start,
+        })
+        .validate(|range, _, emitter| {
+            if range.start.value > range.end.value {
+                let msg = format!(
+                    "invalid reserved range: start {} exceeds end {}",
+                    range.start.value, range.end.value
+                );
+                emitter.emit(Rich::custom(range.span.clone(), msg));
+            }
+
+            range
+        })
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Mod: Tests                                 */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::tests::*;
+
+    /* --------------------------- Tests: reserved -------------------------- */
+
+    #[test]
+    fn test_reserved_single_index_succeeds() {
+        // Given: A reserved statement with a single index.
+        let input = "reserved 3;";
+
+        // When: The input is parsed.
+        let reserved = assert_parse_succeeds(parse_single(input, reserved()));
+
+        // Then: The statement contains the reserved index.
+        assert_eq!(reserved.items.len(), 1);
+        assert!(matches!(&reserved.items[0], ast::ReservedItem::Index(n) if n.value == 3));
+    }
+
+    #[test]
+    fn test_reserved_mixed_items_succeeds() {
+        // Given: A reserved statement with an index, a range, and a name.
+        let input = "reserved 3, 5 to 8, \"old_name\";";
+
+        // When: The input is parsed.
+        let reserved = assert_parse_succeeds(parse_single(input, reserved()));
+
+        // Then: All items are parsed in order.
+        assert_eq!(reserved.items.len(), 3);
+        assert!(reserved.items[0].contains_index(3));
+        assert!(matches!(
+            &reserved.items[1],
+            ast::ReservedItem::Range(r) if r.start.value == 5 && r.end.value == 8
+        ));
+        assert!(reserved.items[2].contains_name("old_name"));
+    }
+
+    #[test]
+    fn test_reserved_with_comment_succeeds() {
+        // Given: A reserved statement preceded by a comment.
+        let input = "// Removed in v2.\nreserved 1;";
+
+        // When: The input is parsed.
+        let reserved = assert_parse_succeeds(parse_single(input, reserved()));
+
+        // Then: The comment is attached to the statement.
+        assert!(reserved.comment.is_some());
+    }
+
+    #[test]
+    fn test_reserved_empty_fails() {
+        // Given: A reserved statement without any items.
+        let input = "reserved;";
+
+        // When: The input is parsed.
+        let result = parse_single(input, reserved());
+
+        // Then: Parsing fails.
+        assert_parse_fails(result);
+    }
+
+    #[test]
+    fn test_reserved_missing_semicolon_fails() {
+        // Given: A reserved statement without a trailing semicolon.
+        let input = "reserved 1, 2";
+
+        // When: The input is parsed.
+        let result = parse_single(input, reserved());
+
+        // Then: Parsing fails.
+        assert_parse_fails(result);
+    }
+
+    #[test]
+    fn test_reserved_inverted_range_fails() {
+        // Given: A reserved range whose start exceeds its end.
+        let input = "reserved 8 to 5;";
+
+        // When: The input is parsed.
+        let result = parse_single(input, reserved());
+
+        // Then: Parsing fails.
+        assert_parse_fails(result);
+    }
+
+    /* ------------------------ Tests: ReservedItem ------------------------- */
+
+    #[test]
+    fn test_reserved_range_contains_bounds() {
+        // Given: A reserved range.
+        let input = "reserved 5 to 8;";
+
+        // When: The input is parsed.
+        let reserved = assert_parse_succeeds(parse_single(input, reserved()));
+
+        // Then: The range is inclusive of both bounds.
+        let item = &reserved.items[0];
+        assert!(!item.contains_index(4));
+        assert!(item.contains_index(5));
+        assert!(item.contains_index(8));
+        assert!(!item.contains_index(9));
+    }
+}
@@ -33,11 +33,18 @@ where
             parse::message(depth_limit).map(ast::SchemaItem::Message),
             parse::comment_block().map(ast::SchemaItem::CommentBlock),
         ))
+        .map(Some)
+        // Skip malformed declarations so that later errors are reported too. As
+        // a last resort, stray tokens (e.g. an unmatched '}') are skipped
+        // individually.
+        .recover_with(via_parser(
+            parse::skip_statement().or(any().ignored()).to(None),
+        ))
         .separated_by(just(Token::Newline).repeated())
         .allow_leading()
         .allow_trailing(),
         |mut a, b| {
-            a.push(b);
+            a.extend(b);
             a
         },
     )
@@ -442,4 +449,25 @@ mod tests {
         // Then: Parsing fails.
         assert!(!errors.is_empty(), "expected parsing to fail");
     }
+
+    /* --------------------------- Tests: recovery -------------------------- */
+
+    #[test]
+    fn test_schema_recovers_from_malformed_declarations() {
+        // Given: A schema with malformed declarations around a valid message.
+        let input = "package foo;\nmessage {\nu8 a;\n}\nmessage Bar {\nu8 b;\n}\ninclude;\n}";
+
+        // When: The input is parsed.
+        let (schema, errors): (Option<ast::Schema>, _) =
+            parse_single(input, schema(parse::MAX_RECURSION_DEPTH));
+
+        // Then: Every error is reported.
+        assert_eq!(errors.len(), 3, "unexpected errors: {:?}", errors);
+
+        // Then: The valid declarations are retained.
+        let schema = schema.expect("should have recovered output");
+        assert_eq!(schema.items.len(), 2);
+        assert!(matches!(schema.items[0], ast::SchemaItem::Package(_)));
+        assert!(matches!(schema.items[1], ast::SchemaItem::Message(_)));
+    }
 }
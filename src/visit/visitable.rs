@@ -67,6 +67,7 @@ macro_rules! impl_visitable_struct {
 /* ----------------------- Impl: impl_visitable_struct ---------------------- */
 
 impl_visitable_struct! {
+    Annotation => visit_annotation,
     Array => visit_array,
     Comment => visit_comment,
     CommentBlock => visit_comment_block,
@@ -80,6 +81,8 @@ impl_visitable_struct! {
     Message => visit_message,
     Package => visit_package,
     Reference => visit_reference,
+    Reserved => visit_reserved,
+    ReservedRange => visit_reserved_range,
     Scalar => visit_scalar,
     Schema => visit_schema,
     Text => visit_text,
@@ -112,6 +115,7 @@ macro_rules! impl_visitable_enum {
 impl_visitable_enum!(EnumItem {
     CommentBlock,
     FieldVariant,
+    Reserved,
     UnitVariant
 });
 
@@ -119,9 +123,12 @@ impl_visitable_enum!(MessageItem {
     CommentBlock,
     Enum,
     Field,
-    Message
+    Message,
+    Reserved
 });
 
+impl_visitable_enum!(ReservedItem { Index, Name, Range });
+
 impl_visitable_enum!(SchemaItem {
     CommentBlock,
     Enum,
@@ -42,6 +42,7 @@ macro_rules! define_visitor {
 /* -------------------------------------------------------------------------- */
 
 define_visitor! {
+    fn visit_annotation(ast::Annotation);
     fn visit_array(ast::Array) => walk_array;
     fn visit_comment_block(ast::CommentBlock) => walk_comment_block;
     fn visit_comment(ast::Comment);
@@ -55,6 +56,8 @@ define_visitor! {
     fn visit_message(ast::Message) => walk_message;
     fn visit_package(ast::Package) => walk_package;
     fn visit_reference(ast::Reference);
+    fn visit_reserved(ast::Reserved) => walk_reserved;
+    fn visit_reserved_range(ast::ReservedRange) => walk_reserved_range;
     fn visit_scalar(ast::Scalar);
     fn visit_schema(ast::Schema) => walk_schema;
     fn visit_text(ast::Text);
@@ -17,9 +17,10 @@ pub fn walk_schema<'ast, V: Visitor<'ast>>(visitor: &mut V, schema: &'ast ast::S
 
 /* ----------------------------- Struct: Message ---------------------------- */
 
-/// Walks a [`ast::Message`], visiting comment, name, and all items.
+/// Walks a [`ast::Message`], visiting annotations, comment, name, and all items.
 #[inline]
 pub fn walk_message<'ast, V: Visitor<'ast>>(visitor: &mut V, message: &'ast ast::Message) {
+    message.annotations.visit(visitor);
     message.comment.visit(visitor);
     message.name.visit(visitor);
     message.items.visit(visitor);
@@ -27,9 +28,10 @@ pub fn walk_message<'ast, V: Visitor<'ast>>(visitor: &mut V, message: &'ast ast:
 
 /* ------------------------------ Struct: Enum ------------------------------ */
 
-/// Walks a [`ast::Enum`], visiting comment, name, and all items.
+/// Walks a [`ast::Enum`], visiting annotations, comment, name, and all items.
 #[inline]
 pub fn walk_enum<'ast, V: Visitor<'ast>>(visitor: &mut V, enum_: &'ast ast::Enum) {
+    enum_.annotations.visit(visitor);
     enum_.comment.visit(visitor);
     enum_.name.visit(visitor);
     enum_.items.visit(visitor);
@@ -37,9 +39,11 @@ pub fn walk_enum<'ast, V: Visitor<'ast>>(visitor: &mut V, enum_: &'ast ast::Enum
 
 /* ------------------------------ Struct: Field ----------------------------- */
 
-/// Walks a [`ast::Field`], visiting comment, name, type, encoding, and index.
+/// Walks a [`ast::Field`], visiting annotations, comment, name, type, encoding,
+/// and index.
 #[inline]
 pub fn walk_field<'ast, V: Visitor<'ast>>(visitor: &mut V, field: &'ast ast::Field) {
+    field.annotations.visit(visitor);
     field.comment.visit(visitor);
     field.index.visit(visitor);
     field.kind.visit(visitor);
@@ -49,14 +53,36 @@ pub fn walk_field<'ast, V: Visitor<'ast>>(visitor: &mut V, field: &'ast ast::Fie
 
 /* --------------------------- Struct: UnitVariant -------------------------- */
 
-/// Walks a [`ast::UnitVariant`], visiting comment, name, and index.
+/// Walks a [`ast::UnitVariant`], visiting annotations, comment, name, and index.
 #[inline]
 pub fn walk_unit_variant<'ast, V: Visitor<'ast>>(visitor: &mut V, variant: &'ast ast::UnitVariant) {
+    variant.annotations.visit(visitor);
     variant.comment.visit(visitor);
     variant.index.visit(visitor);
     variant.name.visit(visitor);
 }
 
+/* ---------------------------- Struct: Reserved ---------------------------- */
+
+/// Walks a [`ast::Reserved`], visiting comment and all reserved items.
+#[inline]
+pub fn walk_reserved<'ast, V: Visitor<'ast>>(visitor: &mut V, reserved: &'ast ast::Reserved) {
+    reserved.comment.visit(visitor);
+    reserved.items.visit(visitor);
+}
+
+/* ------------------------- Struct: ReservedRange -------------------------- */
+
+/// Walks a [`ast::ReservedRange`], visiting the start and end bounds.
+#[inline]
+pub fn walk_reserved_range<'ast, V: Visitor<'ast>>(
+    visitor: &mut V,
+    range: &'ast ast::ReservedRange,
+) {
+    range.start.visit(visitor);
+    range.end.visit(visitor);
+}
+
 /* ------------------------------- Enum: Type ------------------------------- */
 
 /// Walks a [`ast::Type`], dispatching to the appropriate variant.
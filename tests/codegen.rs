@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use tempfile::TempDir;
+
+const TESTDATA: &str = "tests/testdata";
+
+/* -------------------------------------------------------------------------- */
+/*                               Tests: Codegen                               */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_codegen_writes_bindings_and_include_file() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A schema which includes another schema.
+    let out = TempDir::new()?;
+
+    // When: Generating bindings with an include file.
+    let output = baproto::CodegenBuilder::default()
+        .input(Path::new(TESTDATA).join("imports_dependent.baproto"))
+        .import_root(TESTDATA)
+        .out_dir(out.path())
+        .include_file("baproto.rs")
+        .emit_rerun_if_changed(false)
+        .build()?
+        .run()?;
+
+    // Then: Both schemas were read and the package's bindings were written.
+    assert!(output.diagnostics.is_empty());
+    assert_eq!(output.inputs.len(), 2);
+    assert!(output.outputs.iter().any(|p| p.ends_with("test/multi.rs")));
+    assert!(out.path().join("test/multi.rs").is_file());
+
+    // Then: The include file declares the package's modules.
+    let contents = std::fs::read_to_string(out.path().join("baproto.rs"))?;
+    assert!(contents.contains("pub mod test {"));
+    assert!(contents.contains("pub mod multi {"));
+    assert!(contents.contains("include!(\"test/multi.rs\");"));
+    syn::parse_file(&contents)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_codegen_returns_compilation_errors() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A schema with duplicate field indices.
+    let out = TempDir::new()?;
+
+    // When: Generating bindings.
+    let result = baproto::CodegenBuilder::default()
+        .input(Path::new(TESTDATA).join("duplicate_indices.baproto"))
+        .import_root(TESTDATA)
+        .out_dir(out.path())
+        .emit_rerun_if_changed(false)
+        .build()?
+        .run();
+
+    // Then: The error diagnostics are returned with their locations.
+    let Err(err @ baproto::CodegenError::Compilation { .. }) = result else {
+        panic!("expected a compilation error");
+    };
+
+    let diagnostic = &err.diagnostics()[0];
+    assert_eq!(diagnostic.code, Some(baproto::Code::DuplicateIndex));
+    assert!(diagnostic.location.is_some());
+    assert!(err.to_string().contains("error[E0003]"));
+
+    // Then: No bindings were written.
+    assert_eq!(std::fs::read_dir(out.path())?.count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_codegen_returns_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A schema with an unused include.
+    let out = TempDir::new()?;
+
+    // When: Generating bindings.
+    let output = baproto::CodegenBuilder::default()
+        .input(Path::new(TESTDATA).join("unused_include.baproto"))
+        .import_root(TESTDATA)
+        .out_dir(out.path())
+        .emit_rerun_if_changed(false)
+        .build()?
+        .run()?;
+
+    // Then: The bindings were written and the warning is returned.
+    assert!(!output.outputs.is_empty());
+    assert_eq!(output.diagnostics.len(), 1);
+    assert_eq!(output.diagnostics[0].severity, baproto::Severity::Warning);
+
+    Ok(())
+}
+
+#[test]
+fn test_codegen_requires_out_dir() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A configuration without an output directory (and no 'OUT_DIR').
+    if std::env::var_os("OUT_DIR").is_some() {
+        return Ok(());
+    }
+
+    // When: Generating bindings.
+    let result = baproto::CodegenBuilder::default()
+        .input(Path::new(TESTDATA).join("simple_types.baproto"))
+        .build()?
+        .run();
+
+    // Then: The configuration is rejected.
+    assert!(matches!(result, Err(baproto::CodegenError::Config(_))));
+
+    Ok(())
+}
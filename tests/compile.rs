@@ -1,4 +1,4 @@
-use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::cargo::{cargo_bin, cargo_bin_cmd};
 
 /* ------------------------------- Mod: Common ------------------------------ */
 
@@ -185,6 +185,44 @@ fn test_compile_cross_file_imports() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_compile_deprecations() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema referencing a deprecated type from another file
+    let base = ctx.copy_testdata("deprecated_base.baproto");
+    let dependent = ctx.copy_testdata("deprecated_dependent.baproto");
+
+    // When: Compiling both files via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&base)
+        .arg(&dependent)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: A warning is reported for the cross-file deprecated reference.
+    golden::assert_golden(&output, "tests/testdata/golden/deprecations.log");
+
+    // Then: Generated code matches golden file
+    let content = ctx.read_generated("test/deprecated.rs");
+    golden::assert_golden(&content, "tests/testdata/golden/deprecations.rs");
+
+    // Then: The generated file has valid Rust syntax.
+    golden::assert_valid_rust_syntax("tests/testdata/golden/deprecations.rs");
+
+    Ok(())
+}
+
 #[test]
 fn test_compile_encodings() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = common::TestContext::new();
@@ -329,16 +367,17 @@ fn test_error_duplicate_field_indices() -> Result<(), Box<dyn std::error::Error>
 }
 
 #[test]
-fn test_error_invalid_type_reference() -> Result<(), Box<dyn std::error::Error>> {
+fn test_diagnostics_format_json() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = common::TestContext::new();
 
-    // Given: A schema referencing a nonexistent type
-    let schema = ctx.copy_testdata("invalid_type_ref.baproto");
+    // Given: A schema with duplicate field indices
+    let schema = ctx.copy_testdata("duplicate_indices.baproto");
 
-    // When: Compiling via CLI (expecting failure)
+    // When: Compiling via CLI with JSON diagnostics (expecting failure)
     let assert = cargo_bin_cmd!("baproto")
         .arg("compile")
         .arg("--rust")
+        .arg("--diagnostics-format=json")
         .arg("-o")
         .arg(ctx.output_path())
         .arg("-I")
@@ -348,53 +387,143 @@ fn test_error_invalid_type_reference() -> Result<(), Box<dyn std::error::Error>>
         .failure();
 
     let output = assert.get_output();
-    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
     let output = golden::normalize_paths(&output, ctx.input_path());
 
-    // Then: Error output matches golden file
-    golden::assert_golden(&output, "tests/testdata/golden/invalid_type_reference.log");
+    // Then: JSON output on stdout matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/duplicate_indices.json");
 
     Ok(())
 }
 
 #[test]
-fn test_error_file_not_found() -> Result<(), Box<dyn std::error::Error>> {
+fn test_diagnostics_format_sarif() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = common::TestContext::new();
 
-    // Given: A nonexistent file path
-    let bad_path = ctx.input_path().join("nonexistent.baproto");
+    // Given: A schema with duplicate field indices
+    let schema = ctx.copy_testdata("duplicate_indices.baproto");
 
-    // When: Compiling via CLI (expecting failure)
+    // When: Compiling via CLI with SARIF diagnostics (expecting failure)
     let assert = cargo_bin_cmd!("baproto")
         .arg("compile")
         .arg("--rust")
+        .arg("--diagnostics-format=sarif")
         .arg("-o")
         .arg(ctx.output_path())
         .arg("-I")
         .arg(ctx.input_path())
-        .arg(&bad_path)
+        .arg(&schema)
         .assert()
         .failure();
 
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: SARIF output on stdout matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/duplicate_indices.sarif");
+
+    Ok(())
+}
+
+#[test]
+fn test_diagnostics_format_json_reports_once() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with an unused include (i.e. a compiler warning)
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("unused_include.baproto");
+
+    // When: Compiling via CLI with JSON diagnostics
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("--diagnostics-format=json")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
+
+    // Then: stdout contains a single JSON document with the warning
+    let document: serde_json::Value = serde_json::from_str(&output)?;
+    assert_eq!(document["diagnostics"].as_array().map(Vec::len), Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_diagnostics_format_json_check_prints_diff_to_stderr()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Bindings which were modified after being generated
+    let schema = ctx.create_schema("check.baproto", "package check.pkg;\n\nmessage A {}\n");
+    check_command(&ctx, &schema, false).assert().success();
+
+    let path = ctx.output_path().join("check/pkg.rs");
+    let generated = std::fs::read_to_string(&path)?;
+    std::fs::write(&path, format!("// edited\n{}", generated))?;
+
+    // When: Checking the bindings with JSON diagnostics
+    let assert = check_command(&ctx, &schema, true)
+        .arg("--diagnostics-format=json")
+        .assert();
+
+    // Then: stdout only contains the JSON document; the diff is on stderr
+    let output = assert.failure().get_output().clone();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    serde_json::from_str::<serde_json::Value>(&stdout)?;
+    assert!(stderr.contains("-// edited"));
+
+    Ok(())
+}
+
+#[test]
+fn test_warning_naming_conventions() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with names violating the default naming conventions
+    let schema = ctx.copy_testdata("naming_conventions.baproto");
+
+    // When: Compiling via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
     let output = assert.get_output();
     let output = String::from_utf8_lossy(&output.stderr).to_string();
     let output = golden::normalize_paths(&output, ctx.input_path());
 
-    // Then: Error output matches golden file
-    golden::assert_golden(&output, "tests/testdata/golden/file_not_found.log");
+    // Then: Warnings with suggested fixes are reported.
+    golden::assert_golden(&output, "tests/testdata/golden/naming_conventions.log");
 
     Ok(())
 }
 
 #[test]
-fn test_error_invalid_extension() -> Result<(), Box<dyn std::error::Error>> {
+fn test_warning_unused_include() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = common::TestContext::new();
 
-    // Given: A file without .baproto extension
-    let bad_file = ctx.input_path().join("schema.txt");
-    std::fs::write(&bad_file, "package test;").unwrap();
+    // Given: A schema which includes a file without using any of its types
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("unused_include.baproto");
 
-    // When: Compiling via CLI (expecting failure)
+    // When: Compiling via CLI
     let assert = cargo_bin_cmd!("baproto")
         .arg("compile")
         .arg("--rust")
@@ -402,7 +531,129 @@ fn test_error_invalid_extension() -> Result<(), Box<dyn std::error::Error>> {
         .arg(ctx.output_path())
         .arg("-I")
         .arg(ctx.input_path())
-        .arg(&bad_file)
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: The unused include is reported.
+    golden::assert_golden(&output, "tests/testdata/golden/unused_include.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_warning_unused_types() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with unreferenced types and a '@root' entry point
+    let schema = ctx.copy_testdata("unused_types.baproto");
+
+    // When: Compiling via CLI with unused type warnings enabled
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("--warn-unused-types")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Only the unreferenced, non-root types are reported.
+    golden::assert_golden(&output, "tests/testdata/golden/unused_types.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_warning_inline_suppressions() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema whose warnings are partially suppressed by comments
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("suppressions.baproto");
+
+    // When: Compiling via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Only unsuppressed warnings and the malformed suppression are reported.
+    golden::assert_golden(&output, "tests/testdata/golden/suppressions.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_warning_levels() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with naming convention warnings
+    let schema = ctx.copy_testdata("naming_conventions.baproto");
+
+    // When: Compiling via CLI while allowing keyword collisions
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-A")
+        .arg("keyword-collision")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Then: Only the naming convention warnings are reported.
+    assert!(output.contains("[W0002] Warning"));
+    assert!(!output.contains("[W0003] Warning"));
+
+    Ok(())
+}
+
+#[test]
+fn test_error_deny_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema which includes a file without using any of its types
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("unused_include.baproto");
+
+    // When: Compiling via CLI with warnings denied (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("--deny-warnings")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
         .assert()
         .failure();
 
@@ -410,29 +661,48 @@ fn test_error_invalid_extension() -> Result<(), Box<dyn std::error::Error>> {
     let output = String::from_utf8_lossy(&output.stderr).to_string();
     let output = golden::normalize_paths(&output, ctx.input_path());
 
-    // Then: Error output matches golden file
-    golden::assert_golden(&output, "tests/testdata/golden/invalid_extension.log");
+    // Then: The unused include is reported as an error.
+    golden::assert_golden(&output, "tests/testdata/golden/deny_warnings.log");
 
     Ok(())
 }
 
 #[test]
-fn test_error_missing_import_file() -> Result<(), Box<dyn std::error::Error>> {
+fn test_error_multiple_syntax_errors() -> Result<(), Box<dyn std::error::Error>> {
     let ctx = common::TestContext::new();
 
-    // Given: A schema that includes a missing file
-    let schema = ctx.create_schema(
-        "test.baproto",
-        r#"
-package test;
+    // Given: A schema with several syntax errors and an invalid type reference
+    let schema = ctx.copy_testdata("syntax_errors.baproto");
 
-include "missing.baproto";
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
 
-message Foo {
-    0: u32 id;
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Every syntax error is reported, along with errors in the
+    // recovered declarations
+    golden::assert_golden(&output, "tests/testdata/golden/syntax_errors.log");
+
+    Ok(())
 }
-"#,
-    );
+
+#[test]
+fn test_error_reserved_usage() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with fields and variants using reserved indices and names
+    let schema = ctx.copy_testdata("reserved_usage.baproto");
 
     // When: Compiling via CLI (expecting failure)
     let assert = cargo_bin_cmd!("baproto")
@@ -450,8 +720,1642 @@ message Foo {
     let output = String::from_utf8_lossy(&output.stderr).to_string();
     let output = golden::normalize_paths(&output, ctx.input_path());
 
-    // Then: Error output matches golden file
-    golden::assert_golden(&output, "tests/testdata/golden/missing_import_file.log");
+    // Then: The generated file matches expectations.
+    golden::assert_golden(&output, "tests/testdata/golden/reserved_usage.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_error_invalid_type_reference() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema referencing a nonexistent type
+    let schema = ctx.copy_testdata("invalid_type_ref.baproto");
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Error output matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/invalid_type_reference.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_error_misspelled_type_reference() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema referencing misspelled names of existing types
+    let schema = ctx.copy_testdata("misspelled_type_ref.baproto");
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Errors suggest the similarly-named types
+    golden::assert_golden(
+        &output,
+        "tests/testdata/golden/misspelled_type_reference.log",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_error_misspelled_imported_type_reference() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema referencing a misspelled name of an imported type
+    ctx.create_schema("base.baproto", "package dep.base;\n\nmessage User {}\n");
+    let schema = ctx.create_schema(
+        "main.baproto",
+        "package dep.main;\n\ninclude \"base.baproto\";\n\nmessage Post {\n    0: Usr author;\n}\n",
+    );
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Then: The error suggests the imported type as an absolute reference
+    assert!(output.contains("could not resolve reference: Usr; did you mean '.dep.base.User'?"));
+
+    Ok(())
+}
+
+#[test]
+fn test_error_file_not_found() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A nonexistent file path
+    let bad_path = ctx.input_path().join("nonexistent.baproto");
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&bad_path)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Error output matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/file_not_found.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_error_invalid_extension() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A file without .baproto extension
+    let bad_file = ctx.input_path().join("schema.txt");
+    std::fs::write(&bad_file, "package test;").unwrap();
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&bad_file)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Error output matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/invalid_extension.log");
+
+    Ok(())
+}
+
+#[test]
+fn test_error_missing_import_file() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema that includes a missing file
+    let schema = ctx.create_schema(
+        "test.baproto",
+        r#"
+package test;
+
+include "missing.baproto";
+
+message Foo {
+    0: u32 id;
+}
+"#,
+    );
+
+    // When: Compiling via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: Error output matches golden file
+    golden::assert_golden(&output, "tests/testdata/golden/missing_import_file.log");
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                            Tests: generators                               */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(unix)]
+#[test]
+fn test_compile_multiple_generators_share_compilation() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin which writes a single file
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(&ctx, "echo", r#"{"files":{"plugin.txt":"generated"}}"#);
+
+    let rust_out = ctx.output_path().join("rust");
+    let plugin_out = ctx.output_path().join("plugin");
+    std::fs::create_dir_all(&rust_out)?;
+    std::fs::create_dir_all(&plugin_out)?;
+
+    // When: Compiling with both generators via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg(format!("--rust={}", rust_out.display()))
+        .arg("--plugin")
+        .arg(format!("{}={}", plugin.display(), plugin_out.display()))
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: Each generator writes to its own output directory
+    let content = ctx.read_generated("rust/test/types.rs");
+    golden::assert_golden(&content, "tests/testdata/golden/simple_types.rs");
+    assert_eq!(ctx.read_generated("plugin/plugin.txt"), "generated");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_generator_failures_are_reported_separately()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin which always fails
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(&ctx, "broken", "not json");
+
+    // When: Compiling with a working and a failing generator via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Then: Only the failing generator is reported
+    assert!(stderr.contains("generator 'broken' failed"));
+    assert!(!stderr.contains("generator 'rust' failed"));
+    assert!(stderr.contains("Generation failed for 1 of 2 generator(s)."));
+
+    // Then: The working generator's output is still written
+    let content = ctx.read_generated("test/types.rs");
+    golden::assert_golden(&content, "tests/testdata/golden/simple_types.rs");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_receives_request_envelope() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema including another and a plugin which records its input
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("imports_dependent.baproto");
+    let request = ctx.output_path().join("request.json");
+    let plugin = create_recording_plugin(&ctx, "record", &request);
+
+    // When: Compiling with plugin options via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("--plugin-opt")
+        .arg("prefix=Foo")
+        .arg("--plugin-opt")
+        .arg("runtime=")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The plugin receives the versioned request envelope
+    let request: baproto::PluginRequest =
+        serde_json::from_str(&std::fs::read_to_string(&request)?)?;
+
+    assert_eq!(request.protocol_version, baproto::PROTOCOL_VERSION);
+    assert_eq!(request.compiler_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(request.parameters["prefix"], "Foo");
+    assert_eq!(request.parameters["runtime"], "");
+    assert_eq!(
+        request.files,
+        vec![std::path::PathBuf::from("imports_dependent.baproto")]
+    );
+    assert_eq!(request.packages, vec!["test.multi".parse()?]);
+    assert_eq!(request.schema.packages[0].messages.len(), 2);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_legacy_protocol_receives_schema() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin which records its input
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let request = ctx.output_path().join("request.json");
+    let plugin = create_recording_plugin(&ctx, "record", &request);
+
+    // When: Compiling with the legacy plugin protocol via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("--legacy-plugin-protocol")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The plugin receives the bare schema
+    let content = std::fs::read_to_string(&request)?;
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+    assert!(value.get("protocol_version").is_none());
+
+    let schema: baproto::Schema = serde_json::from_value(value)?;
+    assert_eq!(schema.packages.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_invalid_plugin_opt_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Compiling with a plugin option missing its value via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("--plugin-opt")
+        .arg("prefix")
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // Then: The malformed option is reported
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("expected 'KEY=VALUE', found 'prefix'"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_warnings_are_reported_at_declarations()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin which warns about a field
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(
+        &ctx,
+        "warn",
+        r#"{"files":{"plugin.txt":"generated"},"diagnostics":[{"severity":"warning","message":"unsupported bit field","descriptor":{"package":["test","types"],"path":["Scalars"]},"field":"flag_bit"}]}"#,
+    );
+
+    // When: Compiling with the plugin via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The warning is reported at the field's declaration
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("Warning: unsupported bit field"));
+    assert!(stderr.contains("simple_types.baproto:17:13"));
+    assert!(stderr.contains("reported by generator 'warn'"));
+
+    // Then: The generated files are still written
+    assert_eq!(ctx.read_generated("plugin.txt"), "generated");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_errors_fail_generation() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin reporting an error about an unknown type
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(
+        &ctx,
+        "strict",
+        r#"{"files":{"plugin.txt":"generated"},"diagnostics":[{"severity":"error","message":"unsupported type","descriptor":{"package":["test","types"],"path":["Missing"]}}]}"#,
+    );
+
+    // When: Compiling with the plugin via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // Then: The error is reported along with the declaration it references
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("Error: unsupported type"));
+    assert!(stderr.contains("referenced declaration: 'test.types.Missing'"));
+    assert!(stderr.contains("generator 'strict' failed: reported 1 error(s)"));
+
+    // Then: No files are written
+    assert!(!ctx.output_path().join("plugin.txt").exists());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_warnings_can_be_denied() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin which warns about a message
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(
+        &ctx,
+        "warn",
+        r#"{"files":{},"diagnostics":[{"severity":"warning","message":"slow encoding","descriptor":{"package":["test","types"],"path":["Scalars"]}}]}"#,
+    );
+
+    // When: Compiling with the plugin and denying warnings via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(&plugin)
+        .arg("--deny-warnings")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // Then: The warning is reported as an error
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("Error: slow encoding"));
+    assert!(stderr.contains("simple_types.baproto:3:9"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_gen_finds_plugin_in_plugins_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin following the naming convention
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    create_plugin(
+        &ctx,
+        "baproto-gen-echo",
+        r#"{"files":{"plugin.txt":"generated"}}"#,
+    );
+
+    // When: Compiling with the plugin selected by name via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--gen")
+        .arg(format!("echo={}", ctx.output_path().display()))
+        .arg("--plugins-dir")
+        .arg(ctx.input_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The plugin's output is written
+    assert_eq!(ctx.read_generated("plugin.txt"), "generated");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_gen_finds_plugin_on_path() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin in a directory on 'PATH'
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    create_plugin(
+        &ctx,
+        "baproto-gen-echo",
+        r#"{"files":{"plugin.txt":"generated"}}"#,
+    );
+
+    let path = std::env::join_paths(std::iter::once(ctx.input_path().to_owned()).chain(
+        std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()),
+    ))?;
+
+    // When: Compiling with the plugin selected by name via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--gen")
+        .arg("echo")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .env("PATH", path)
+        .assert()
+        .success();
+
+    // Then: The plugin's output is written
+    assert_eq!(ctx.read_generated("plugin.txt"), "generated");
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_gen_unknown_plugin_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and an empty plugins directory
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Compiling with an unknown plugin selected by name via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--gen")
+        .arg("missing")
+        .arg("--plugins-dir")
+        .arg(ctx.output_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // Then: The plugin and the searched directories are reported
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("plugin not found: 'baproto-gen-missing'"));
+    assert!(stderr.contains(&format!("'{}'", ctx.output_path().display())));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_compile_plugin_paths_escaping_out_dir_are_rejected()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema and a plugin writing outside of its output directory
+    let schema = ctx.copy_testdata("simple_types.baproto");
+    let plugin = create_plugin(
+        &ctx,
+        "escape",
+        r#"{"files":{"ok.txt":"generated","nested/../../escaped.txt":"generated"}}"#,
+    );
+
+    let out = ctx.output_path().join("plugin");
+    std::fs::create_dir_all(&out)?;
+
+    // When: Compiling with the plugin via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--plugin")
+        .arg(format!("{}={}", plugin.display(), out.display()))
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // Then: The invalid path is reported
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(
+        stderr.contains(
+            "invalid output path 'nested/../../escaped.txt': escapes the output directory"
+        )
+    );
+
+    // Then: No files are written
+    assert!(!ctx.output_path().join("escaped.txt").exists());
+    assert!(!out.join("ok.txt").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_unchanged_files_are_not_rewritten() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema whose bindings were already generated
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    let compile = || {
+        cargo_bin_cmd!("baproto")
+            .arg("compile")
+            .arg("--rust")
+            .arg("-o")
+            .arg(ctx.output_path())
+            .arg("-I")
+            .arg(ctx.input_path())
+            .arg(&schema)
+            .assert()
+            .success();
+    };
+
+    compile();
+
+    let path = ctx.output_path().join("test/types.rs");
+    let modified = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1);
+    std::fs::File::options()
+        .write(true)
+        .open(&path)?
+        .set_modified(modified)?;
+
+    // When: Compiling the unchanged schema again
+    compile();
+
+    // Then: The generated file isn't modified
+    assert_eq!(std::fs::metadata(&path)?.modified()?, modified);
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_removes_stale_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Bindings generated for two packages alongside a user-created file
+    let first = ctx.create_schema("first.baproto", "package first.pkg;\n\nmessage A {}\n");
+    let second = ctx.create_schema("second.baproto", "package second.pkg;\n\nmessage B {}\n");
+
+    let compile = |files: &[&std::path::PathBuf]| {
+        cargo_bin_cmd!("baproto")
+            .arg("compile")
+            .arg("--rust")
+            .arg("-o")
+            .arg(ctx.output_path())
+            .arg("-I")
+            .arg(ctx.input_path())
+            .args(files)
+            .assert()
+            .success();
+    };
+
+    compile(&[&first, &second]);
+    std::fs::write(ctx.output_path().join("second/user.rs"), "// user")?;
+
+    assert!(ctx.output_path().join("first/pkg.rs").exists());
+    assert!(ctx.output_path().join("second/pkg.rs").exists());
+    assert!(ctx.output_path().join(".baproto-manifest.json").exists());
+
+    // When: Compiling only one of the packages
+    compile(&[&first]);
+
+    // Then: The stale bindings are removed, but the user-created file is kept
+    assert!(ctx.output_path().join("first/pkg.rs").exists());
+    assert!(!ctx.output_path().join("second/pkg.rs").exists());
+    assert!(ctx.output_path().join("second/user.rs").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_check_passes_when_up_to_date() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Bindings which were generated from the current schema
+    let schema = ctx.create_schema("check.baproto", "package check.pkg;\n\nmessage A {}\n");
+    check_command(&ctx, &schema, false).assert().success();
+
+    // When: Checking the bindings
+    let assert = check_command(&ctx, &schema, true).assert();
+
+    // Then: The check succeeds without printing any differences
+    let output = assert.success().get_output().clone();
+    assert!(output.stdout.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_check_reports_modified_files() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Bindings which were modified after being generated
+    let schema = ctx.create_schema("check.baproto", "package check.pkg;\n\nmessage A {}\n");
+    check_command(&ctx, &schema, false).assert().success();
+
+    let path = ctx.output_path().join("check/pkg.rs");
+    let generated = std::fs::read_to_string(&path)?;
+    std::fs::write(&path, format!("// edited\n{}", generated))?;
+
+    // When: Checking the bindings
+    let assert = check_command(&ctx, &schema, true).assert();
+
+    // Then: A diff is printed and the check fails without writing anything
+    let output = assert.failure().get_output().clone();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stdout.contains("--- a/check/pkg.rs\n+++ b/check/pkg.rs\n"));
+    assert!(stdout.contains("-// edited"));
+    assert!(stderr.contains("1 generated file(s) are out of date"));
+
+    assert!(std::fs::read_to_string(&path)?.starts_with("// edited"));
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_check_reports_missing_files() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema whose bindings were never generated
+    let schema = ctx.create_schema("check.baproto", "package check.pkg;\n\nmessage A {}\n");
+
+    // When: Checking the bindings
+    let assert = check_command(&ctx, &schema, true).assert();
+
+    // Then: The missing file is reported and nothing is written
+    let output = assert.failure().get_output().clone();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("Missing generated file:"));
+    assert!(stdout.contains("pkg.rs"));
+
+    assert!(!ctx.output_path().join("check").exists());
+    assert!(!ctx.output_path().join(".baproto-manifest.json").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_check_reports_extra_files() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Bindings generated for a package which is no longer compiled
+    let first = ctx.create_schema("first.baproto", "package first.pkg;\n\nmessage A {}\n");
+    let second = ctx.create_schema("second.baproto", "package second.pkg;\n\nmessage B {}\n");
+
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&first)
+        .arg(&second)
+        .assert()
+        .success();
+
+    // When: Checking the bindings of only one package
+    let assert = check_command(&ctx, &first, true).assert();
+
+    // Then: The previously generated file is reported as extra, and kept
+    let output = assert.failure().get_output().clone();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("Extra generated file:"));
+    assert!(stdout.contains("pkg.rs"));
+
+    assert!(ctx.output_path().join("second/pkg.rs").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_depfile_lists_outputs_and_included_schemas()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema which includes another schema
+    ctx.create_schema("base.baproto", "package dep.pkg;\n\nmessage A {}\n");
+    let schema = ctx.create_schema(
+        "dependent.baproto",
+        "package dep.pkg;\n\ninclude \"base.baproto\";\n\nmessage B {\n    0: A a;\n}\n",
+    );
+    let depfile = ctx.output_path().join("deps/out.d");
+
+    // When: Compiling the schema with a depfile
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg("--depfile")
+        .arg(&depfile)
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: Every output depends on both schemas
+    let contents = std::fs::read_to_string(&depfile)?;
+    let (outputs, inputs) = contents.split_once(':').unwrap();
+
+    assert!(outputs.contains("dep/pkg.rs"));
+    assert!(!outputs.contains(".baproto-manifest.json"));
+    assert!(inputs.contains("base.baproto"));
+    assert!(inputs.contains("dependent.baproto"));
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_depfile_refreshes_unchanged_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Previously generated bindings which are older than their schema
+    let contents = "package dep.pkg;\n\nmessage A {}\n";
+    let schema = ctx.create_schema("dep.baproto", contents);
+    let depfile = ctx.output_path().join("out.d");
+
+    let compile = || {
+        cargo_bin_cmd!("baproto")
+            .arg("compile")
+            .arg("--rust")
+            .arg("-o")
+            .arg(ctx.output_path())
+            .arg("-I")
+            .arg(ctx.input_path())
+            .arg("--depfile")
+            .arg(&depfile)
+            .arg(&schema)
+            .assert()
+            .success();
+    };
+
+    compile();
+
+    let output = ctx.output_path().join("dep/pkg.rs");
+    let generated = std::fs::read_to_string(&output)?;
+
+    std::fs::File::options()
+        .write(true)
+        .open(&output)?
+        .set_modified(std::time::SystemTime::UNIX_EPOCH)?;
+
+    // When: Recompiling after a change which doesn't affect the bindings
+    std::fs::write(&schema, format!("// comment\n{}", contents))?;
+    compile();
+
+    // Then: The bindings are unchanged, but newer than the schema
+    let modified = |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified());
+
+    assert_eq!(std::fs::read_to_string(&output)?, generated);
+    assert!(modified(&output)? >= modified(&schema)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_reads_schema_from_stdin() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema on stdin which includes a schema within an import root
+    ctx.create_schema("base.baproto", "package stdin.pkg;\n\nmessage A {}\n");
+    let schema = "package stdin.pkg;\n\ninclude \"base.baproto\";\n\nmessage B {\n    0: A a;\n}\n";
+
+    // When: Compiling the schema read from stdin
+    cargo_bin_cmd!("baproto")
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg("-")
+        .write_stdin(schema)
+        .assert()
+        .success();
+
+    // Then: Bindings are generated for both schemas
+    let content = ctx.read_generated("stdin/pkg.rs");
+    assert!(content.contains("pub struct A"));
+    assert!(content.contains("pub struct B"));
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_project_targets_share_schema_from_stdin() -> Result<(), Box<dyn std::error::Error>>
+{
+    let ctx = common::TestContext::new();
+
+    // Given: A project whose targets have different options
+    ctx.create_schema(
+        "baproto.toml",
+        "[[targets]]\nrust = true\nout = \"a\"\n\n[[targets]]\nrust = true\nout = \"b\"\noptions = { naming = { field_case = \"any\" } }\n",
+    );
+
+    // When: Compiling a schema read from stdin
+    cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .arg("-")
+        .write_stdin("package test.stdin;\n\nmessage A {}\n")
+        .assert()
+        .success();
+
+    // Then: Bindings are generated for both targets
+    for out in ["a", "b"] {
+        let content = std::fs::read_to_string(ctx.input_path().join(out).join("test/stdin.rs"))?;
+        assert!(content.contains("pub struct A"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_check_reports_stdin_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: An invalid schema on stdin
+    let schema = "package stdin.pkg;\n\nmessage A {\n    0: u32 a;\n    0: u32 b;\n}\n";
+
+    // When: Checking the schema read from stdin
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("check")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg("-")
+        .write_stdin(schema)
+        .assert()
+        .failure();
+
+    // Then: Diagnostics are reported against stdin
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert!(stderr.contains("<stdin>:5:"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lower_in_memory_resolves_includes() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: In-memory schemas, one of which includes the other
+    let fs = baproto::MemoryFileSystem::default()
+        .with_file("schemas/base.baproto", "package mem.pkg;\n\nmessage A {}\n")
+        .with_file(
+            "schemas/main.baproto",
+            "package mem.pkg;\n\ninclude \"base.baproto\";\n\nmessage B {\n    0: A a;\n}\n",
+        );
+
+    // When: Lowering the including schema
+    let schema = baproto::lower_in(
+        fs,
+        vec!["schemas/main.baproto"],
+        vec!["schemas"],
+        baproto::CompileOptions::default(),
+    )?;
+
+    // Then: Both schemas are lowered without reading the disk
+    let names = schema.packages[0]
+        .messages
+        .iter()
+        .map(|msg| msg.descriptor.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["mem.pkg.A", "mem.pkg.B"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_lower_in_memory_reports_unresolved_includes() {
+    // Given: An in-memory schema including a file which only exists on disk
+    let fs = baproto::MemoryFileSystem::default().with_file(
+        "main.baproto",
+        "package mem.main;\n\ninclude \"simple_types.baproto\";\n",
+    );
+
+    // When: Lowering the schema
+    let result = baproto::lower_in(
+        fs,
+        vec!["main.baproto"],
+        vec![],
+        baproto::CompileOptions::default(),
+    );
+
+    // Then: The include isn't resolved
+    assert!(result.is_err());
+}
+
+/* ---------------------------- Fn: check_command --------------------------- */
+
+/// `check_command` creates a command which compiles `schema` into the output
+/// directory, only checking the generated bindings if `check` is set.
+fn check_command(
+    ctx: &common::TestContext,
+    schema: &std::path::Path,
+    check: bool,
+) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("baproto");
+
+    cmd.arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(schema);
+
+    if check {
+        cmd.arg("--check");
+    }
+
+    cmd
+}
+
+/* ---------------------------- Fn: create_plugin --------------------------- */
+
+/// `create_plugin` creates an executable plugin named `name` in the input
+/// directory which ignores its input and prints `output`.
+#[cfg(unix)]
+fn create_plugin(ctx: &common::TestContext, name: &str, output: &str) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = ctx.input_path().join(name);
+    let script = format!("#!/bin/sh\ncat > /dev/null\necho '{}'\n", output);
+
+    std::fs::write(&path, script).expect("failed to write plugin");
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+        .expect("failed to make plugin executable");
+
+    path
+}
+
+/* ----------------------- Fn: create_recording_plugin ---------------------- */
+
+/// `create_recording_plugin` creates an executable plugin named `name` in the
+/// input directory which writes its input to `record` and generates nothing.
+#[cfg(unix)]
+fn create_recording_plugin(
+    ctx: &common::TestContext,
+    name: &str,
+    record: &std::path::Path,
+) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = ctx.input_path().join(name);
+    let script = format!(
+        "#!/bin/sh\ncat > '{}'\necho '{{\"files\":{{}}}}'\n",
+        record.display()
+    );
+
+    std::fs::write(&path, script).expect("failed to write plugin");
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+        .expect("failed to make plugin executable");
+
+    path
+}
+
+/* -------------------------------------------------------------------------- */
+/*                              Tests: project                                */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_compile_project_builds_all_targets() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A project file declaring inputs by glob and a Rust target
+    ctx.copy_testdata("imports_base.baproto");
+    ctx.copy_testdata("imports_dependent.baproto");
+    ctx.create_schema(
+        "baproto.toml",
+        &format!(
+            "inputs = [\"imports_*.baproto\"]\nimport_roots = [\".\"]\n\n[[targets]]\nrust = true\nout = {:?}\n",
+            ctx.output_path().join("rust")
+        ),
+    );
+
+    // When: Compiling via CLI without any arguments
+    cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .assert()
+        .success();
+
+    // Then: The target's bindings are generated
+    let content = ctx.read_generated("rust/test/multi.rs");
+    golden::assert_golden(&content, "tests/testdata/golden/cross_file_imports.rs");
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_project_arguments_take_precedence() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A project which denies warnings and a schema with warnings
+    let schema = ctx.copy_testdata("naming_conventions.baproto");
+    ctx.create_schema(
+        "baproto.toml",
+        "[options]\ndeny_warnings = true\n\n[[targets]]\nrust = true\nout = \"unused\"\n",
+    );
+
+    // When: Compiling via CLI with the project's options
+    cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // When: Compiling via CLI with overriding arguments
+    cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .arg("--rust")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-W")
+        .arg("warnings")
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: Only the arguments' output directory is written to
+    assert!(!ctx.input_path().join("unused").join("test").exists());
+    assert_ne!(std::fs::read_dir(ctx.output_path())?.count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_project_targets_with_different_options_report_once()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with an unused include and a misnamed field
+    ctx.copy_testdata("imports_base.baproto");
+    ctx.create_schema(
+        "main.baproto",
+        "package test.multi;\n\ninclude \"imports_base.baproto\";\n\nmessage Comment {\n    0: u32 teamId;\n}\n",
+    );
+
+    // Given: A project whose second target allows any field names
+    ctx.create_schema(
+        "baproto.toml",
+        "inputs = [\"main.baproto\"]\n\n[[targets]]\nrust = true\nout = \"a\"\n\n[[targets]]\nrust = true\nout = \"b\"\noptions = { naming = { field_case = \"any\" } }\n",
+    );
+
+    // When: Compiling via CLI with JSON diagnostics
+    let assert = cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .arg("--diagnostics-format=json")
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
+
+    // Then: stdout contains a single JSON document with each warning once
+    let document: serde_json::Value = serde_json::from_str(&output)?;
+    let codes = document["diagnostics"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|d| d["code"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, ["W0004", "W0002"]);
+
+    // Then: Both targets' bindings are generated
+    assert!(ctx.input_path().join("a/test/multi.rs").is_file());
+    assert!(ctx.input_path().join("b/test/multi.rs").is_file());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_project_invalid_targets_create_no_directories()
+-> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A project whose second target names an unknown plugin
+    ctx.copy_testdata("simple_types.baproto");
+    ctx.create_schema(
+        "baproto.toml",
+        "inputs = [\"simple_types.baproto\"]\n\n[[targets]]\nrust = true\nout = \"rust\"\n\n[[targets]]\ngenerator = \"missing\"\nout = \"missing\"\n",
+    );
+
+    // When: Compiling via CLI (expecting failure)
+    cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .env("PATH", "")
+        .assert()
+        .failure();
+
+    // Then: No output directories are created
+    assert!(!ctx.input_path().join("rust").exists());
+    assert!(!ctx.input_path().join("missing").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_compile_without_inputs_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // When: Compiling via CLI without arguments or a project file
+    let assert = cargo_bin_cmd!("baproto")
+        .current_dir(ctx.input_path())
+        .arg("compile")
+        .arg("--rust")
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+
+    // Then: The missing inputs are reported
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no input files specified"));
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                               Tests: watch                                 */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_compile_watch_recompiles_on_change() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.create_schema("watch.baproto", "package test.watch;\n\nmessage A {}\n");
+    let generated = ctx.output_path().join("test/watch.rs");
+
+    // When: Compiling in watch mode via CLI
+    let mut child = std::process::Command::new(cargo_bin!("baproto"))
+        .arg("compile")
+        .arg("--rust")
+        .arg("--watch")
+        .arg("-o")
+        .arg(ctx.output_path())
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+
+    let is_initial_compile = wait_for(|| generated.exists());
+
+    // When: The schema is changed, first with an error and then fixed
+    std::fs::write(&schema, "package test.watch;\n\nmessage B {\n")?;
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let is_kept_on_error = std::fs::read_to_string(&generated)?.contains("pub struct A");
+
+    std::fs::write(&schema, "package test.watch;\n\nmessage B {}\n")?;
+    let is_recompiled =
+        wait_for(|| std::fs::read_to_string(&generated).is_ok_and(|s| s.contains("pub struct B")));
+
+    child.kill()?;
+    child.wait()?;
+
+    // Then: The bindings are generated, kept on error, and then regenerated
+    assert!(is_initial_compile);
+    assert!(is_kept_on_error);
+    assert!(is_recompiled);
+
+    Ok(())
+}
+
+/* ------------------------------ Fn: wait_for ------------------------------ */
+
+/// `wait_for` polls `condition` until it holds, giving up after a timeout.
+fn wait_for(condition: impl Fn() -> bool) -> bool {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+
+    while std::time::Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    false
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                Tests: check                                */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_check_valid_schema_writes_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Checking via CLI from within the output directory
+    let assert = cargo_bin_cmd!("baproto")
+        .current_dir(ctx.output_path())
+        .arg("check")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+
+    // Then: Nothing is reported or written
+    assert!(output.stderr.is_empty());
+    assert_eq!(std::fs::read_dir(ctx.output_path())?.count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_check_invalid_schema_reports_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with duplicate field indices
+    let schema = ctx.copy_testdata("duplicate_indices.baproto");
+
+    // When: Checking via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("check")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+    let output = golden::normalize_paths(&output, ctx.input_path());
+
+    // Then: The same diagnostics as compilation are reported
+    golden::assert_golden(&output, "tests/testdata/golden/duplicate_field_indices.log");
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                 Tests: fmt                                 */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_fmt_rewrites_schema_in_place() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema which isn't formatted
+    let schema = ctx.copy_testdata("unformatted.baproto");
+
+    // When: Formatting via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("fmt")
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The schema is rewritten in the canonical style
+    let output = std::fs::read_to_string(&schema)?;
+    golden::assert_golden(&output, "tests/testdata/golden/unformatted.baproto");
+
+    Ok(())
+}
+
+#[test]
+fn test_fmt_check_lists_unformatted_schemas() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A formatted and an unformatted schema
+    let formatted = ctx.copy_testdata("enums.baproto");
+    let unformatted = ctx.copy_testdata("unformatted.baproto");
+    let contents = std::fs::read_to_string(&unformatted)?;
+
+    // When: Checking formatting via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("fmt")
+        .arg("--check")
+        .arg(&formatted)
+        .arg(&unformatted)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+
+    // Then: Only the unformatted schema is listed
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        format!("{}\n", unformatted.display())
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 file(s) would be reformatted"));
+
+    // Then: No schema is modified
+    assert_eq!(std::fs::read_to_string(&unformatted)?, contents);
+
+    Ok(())
+}
+
+#[test]
+fn test_fmt_leaves_schema_with_syntax_errors_untouched() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with syntax errors
+    let schema = ctx.copy_testdata("syntax_errors.baproto");
+    let contents = std::fs::read_to_string(&schema)?;
+
+    // When: Formatting via CLI (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("fmt")
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Then: The errors are reported and the schema is unchanged
+    assert!(output.contains("Formatting failed with 3 error(s)."));
+    assert_eq!(std::fs::read_to_string(&schema)?, contents);
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+/*                                  Tests: ir                                 */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_ir_prints_schema_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Printing the IR via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+
+    // Then: The IR matches the golden file
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
+    golden::assert_golden(&output, "tests/testdata/golden/simple_types.json");
+
+    Ok(())
+}
+
+#[test]
+fn test_ir_filters_packages_and_writes_file() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: Schemas from two different packages
+    let types = ctx.copy_testdata("simple_types.baproto");
+    let enums = ctx.copy_testdata("enums.baproto");
+    let out = ctx.output_path().join("ir.json");
+
+    // When: Printing the compact IR of one package to a file via CLI
+    cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("--compact")
+        .arg("--package")
+        .arg("test.types")
+        .arg("-o")
+        .arg(&out)
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&types)
+        .arg(&enums)
+        .assert()
+        .success();
+
+    // Then: The file contains only the selected package
+    let content = std::fs::read_to_string(&out)?;
+    assert_eq!(content.lines().count(), 1);
+
+    let schema: baproto::Schema = serde_json::from_str(&content)?;
+    let names = schema
+        .packages
+        .iter()
+        .map(|pkg| pkg.name.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["test.types"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_ir_envelope_matches_plugin_request() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Printing the IR wrapped in the plugin request envelope
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("--envelope")
+        .arg("--compact")
+        .arg("--plugin-opt")
+        .arg("prefix=Foo")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: The output is the request written to plugins
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    let request: baproto::PluginRequest = serde_json::from_str(&stdout)?;
+    assert_eq!(request.protocol_version, baproto::PROTOCOL_VERSION);
+    assert_eq!(
+        request.parameters.get("prefix").map(String::as_str),
+        Some("Foo")
+    );
+    assert_eq!(
+        request.files,
+        [std::path::PathBuf::from("simple_types.baproto")]
+    );
+    assert_eq!(request.schema.packages.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_ir_structured_diagnostics_require_out() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A schema with an unused include (i.e. a compiler warning)
+    ctx.copy_testdata("imports_base.baproto");
+    let schema = ctx.copy_testdata("unused_include.baproto");
+
+    // When: Printing the IR to stdout with JSON diagnostics (expecting failure)
+    cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("--diagnostics-format=json")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    // When: Writing the IR to a file with JSON diagnostics
+    let path = ctx.output_path().join("schema.json");
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("--diagnostics-format=json")
+        .arg("-o")
+        .arg(&path)
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .success();
+
+    // Then: stdout only contains the diagnostics document
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    let document: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(document["diagnostics"].as_array().map(Vec::len), Some(1));
+
+    // Then: The file only contains the IR
+    let schema: baproto::Schema = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    assert_eq!(schema.packages.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_ir_unknown_package_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::TestContext::new();
+
+    // Given: A valid schema
+    let schema = ctx.copy_testdata("simple_types.baproto");
+
+    // When: Filtering by a package which isn't defined (expecting failure)
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("ir")
+        .arg("--package")
+        .arg("test.missing")
+        .arg("-I")
+        .arg(ctx.input_path())
+        .arg(&schema)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+
+    // Then: The missing package is reported
+    assert!(String::from_utf8_lossy(&output.stderr).contains("package not found: test.missing"));
 
     Ok(())
 }
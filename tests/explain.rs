@@ -0,0 +1,48 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+
+/* -------------------------------------------------------------------------- */
+/*                               Tests: explain                               */
+/* -------------------------------------------------------------------------- */
+
+#[test]
+fn test_explain_known_code() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A known diagnostic code (in lowercase).
+    let code = "e0003";
+
+    // When: Explaining the code via CLI.
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("explain")
+        .arg(code)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stdout).to_string();
+
+    // Then: The code's summary is printed, followed by an example.
+    assert!(output.starts_with("E0003: duplicate field index\n"));
+    assert!(output.contains("Erroneous example:"));
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_unknown_code() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: An unknown diagnostic code.
+    let code = "E9999";
+
+    // When: Explaining the code via CLI (expecting failure).
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("explain")
+        .arg(code)
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let output = String::from_utf8_lossy(&output.stderr).to_string();
+
+    // Then: The error names the unknown code.
+    assert!(output.contains("unknown diagnostic code: E9999"));
+
+    Ok(())
+}
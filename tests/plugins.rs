@@ -0,0 +1,85 @@
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/* -------------------------------------------------------------------------- */
+/*                            Tests: plugins list                             */
+/* -------------------------------------------------------------------------- */
+
+#[cfg(unix)]
+#[test]
+fn test_plugins_list_describes_plugins() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: A plugin supporting the describe handshake and one which doesn't
+    let plugins = TempDir::new()?;
+    let empty = TempDir::new()?;
+    let described = create_plugin(
+        plugins.path(),
+        "baproto-gen-cpp",
+        "if [ \"$1\" = \"--describe\" ]; then echo '{\"name\":\"cpp\",\"version\":\"1.2.3\"}'; fi",
+    );
+    let broken = create_plugin(plugins.path(), "baproto-gen-old", "exit 1");
+    create_plugin(plugins.path(), "unrelated", "exit 0");
+
+    // When: Listing plugins via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("plugins")
+        .arg("list")
+        .arg("--plugins-dir")
+        .arg(plugins.path())
+        .env("PATH", empty.path())
+        .assert()
+        .success();
+
+    // Then: Each plugin is listed by name along with its description
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    let lines = stdout.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("cpp  cpp 1.2.3"));
+    assert!(lines[0].ends_with(&described.display().to_string()));
+    assert!(lines[1].starts_with("old  (unknown: "));
+    assert!(lines[1].ends_with(&broken.display().to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn test_plugins_list_without_plugins() -> Result<(), Box<dyn std::error::Error>> {
+    // Given: An empty plugins directory and 'PATH'
+    let plugins = TempDir::new()?;
+    let empty = TempDir::new()?;
+
+    // When: Listing plugins via CLI
+    let assert = cargo_bin_cmd!("baproto")
+        .arg("plugins")
+        .arg("list")
+        .arg("--plugins-dir")
+        .arg(plugins.path())
+        .env("PATH", empty.path())
+        .assert()
+        .success();
+
+    // Then: The searched directories are reported
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(stderr.contains("No plugins found"));
+    assert!(stderr.contains(&format!("'{}'", plugins.path().display())));
+
+    Ok(())
+}
+
+/* ---------------------------- Fn: create_plugin --------------------------- */
+
+/// `create_plugin` creates an executable shell script named `name` in `dir`
+/// which runs `body`.
+#[cfg(unix)]
+fn create_plugin(dir: &Path, name: &str, body: &str) -> PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join(name);
+
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).expect("failed to write plugin");
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+        .expect("failed to make plugin executable");
+
+    path
+}
@@ -1,7 +1,7 @@
-//! Generated code for package `test.collections`.
-//!
-//! This file was automatically generated by baproto.
-//! Do not edit manually.
+// Generated code for package `test.collections`.
+//
+// This file was automatically generated by baproto.
+// Do not edit manually.
 
 use std::collections::HashMap;
 use std::io::{Read, Write};
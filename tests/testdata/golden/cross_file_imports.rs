@@ -1,12 +1,12 @@
-//! Generated code for package `test.multi`.
-//!
-//! This file was automatically generated by baproto.
-//! Do not edit manually.
+// Generated code for package `test.multi`.
+//
+// This file was automatically generated by baproto.
+// Do not edit manually.
 
 use std::collections::HashMap;
 use std::io::{Read, Write};
 
-use crate::test::multi;
+use super::super::test::multi;
 #[derive(Debug, Clone, PartialEq)]
 pub struct User {
     pub id: u32,
@@ -0,0 +1,124 @@
+// Generated code for package `test.deprecated`.
+//
+// This file was automatically generated by baproto.
+// Do not edit manually.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+use super::super::test::deprecated;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Circle,
+    #[deprecated]
+    Square,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    /// Creates a new instance with default values.
+    pub fn new() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+
+    /// Encodes this message to a writer.
+    pub fn encode(&self, _writer: &mut [u8]) -> std::io::Result<()> {
+        todo!("serialization not yet implemented")
+    }
+
+    /// Decodes a message from a reader.
+    pub fn decode(_reader: &[u8]) -> std::io::Result<Self> {
+        todo!("deserialization not yet implemented")
+    }
+}
+
+impl Default for Vec3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A position in 2D space.
+#[deprecated(note = "use Vec3")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[allow(deprecated)]
+impl Vec2 {
+    /// Creates a new instance with default values.
+    pub fn new() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+        }
+    }
+
+    /// Encodes this message to a writer.
+    pub fn encode(&self, _writer: &mut [u8]) -> std::io::Result<()> {
+        todo!("serialization not yet implemented")
+    }
+
+    /// Decodes a message from a reader.
+    pub fn decode(_reader: &[u8]) -> std::io::Result<Self> {
+        todo!("deserialization not yet implemented")
+    }
+}
+
+#[allow(deprecated)]
+impl Default for Vec2 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    #[deprecated(note = "use pos_v2")]
+    pub pos: Vec2,
+    pub pos_v2: Vec3,
+    pub shape: Shape,
+}
+
+#[allow(deprecated)]
+impl Entity {
+    /// Creates a new instance with default values.
+    pub fn new() -> Self {
+        Self {
+            pos: Vec2::new(),
+            pos_v2: Vec3::new(),
+            shape: Shape::default(),
+        }
+    }
+
+    /// Encodes this message to a writer.
+    pub fn encode(&self, _writer: &mut [u8]) -> std::io::Result<()> {
+        todo!("serialization not yet implemented")
+    }
+
+    /// Decodes a message from a reader.
+    pub fn decode(_reader: &[u8]) -> std::io::Result<Self> {
+        todo!("deserialization not yet implemented")
+    }
+}
+
+#[allow(deprecated)]
+impl Default for Entity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
@@ -1,12 +1,12 @@
-//! Generated code for package `test.status`.
-//!
-//! This file was automatically generated by baproto.
-//! Do not edit manually.
+// Generated code for package `test.status`.
+//
+// This file was automatically generated by baproto.
+// Do not edit manually.
 
 use std::collections::HashMap;
 use std::io::{Read, Write};
 
-use crate::test::status;
+use super::super::test::status;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     Unknown,
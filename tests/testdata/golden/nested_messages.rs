@@ -1,12 +1,12 @@
-//! Generated code for package `test.nesting`.
-//!
-//! This file was automatically generated by baproto.
-//! Do not edit manually.
+// Generated code for package `test.nesting`.
+//
+// This file was automatically generated by baproto.
+// Do not edit manually.
 
 use std::collections::HashMap;
 use std::io::{Read, Write};
 
-use crate::test::nesting;
+use super::super::test::nesting;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Level3 {
     pub active: bool,